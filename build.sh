@@ -4,5 +4,6 @@ set -e
 export RUST_BACKTRACE=1
 cargo build --release
 cbindgen --config cbindgen.toml --crate sui_rust_sdk --output ./header/sui_lib.h
-gcc test.c -L target/release/ -lsui_rust_sdk -o Test
-./test
+rustc --edition 2021 -o target/sui_lib_hpp tools/sui_lib_hpp.rs
+./target/sui_lib_hpp
+cargo test
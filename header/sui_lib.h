@@ -1,21 +1,109 @@
+/*
+ * Ownership: everything returned by the library is owned by the caller and released exactly
+ * once with the sui_free_* function of its type (all of them accept null and error results).
+ * Arguments are borrowed and stay with the caller, except the builder passed to
+ * execute_transaction, execute_transaction_allow_sponser and build_transaction_data and the
+ * filters passed to object_filter_match_*, which are consumed. Nested data is released with
+ * its parent. The older free_* and destroy_* functions are kept and behave the same.
+ * Opaque handles (Sui* types, *_v1 functions) are released with their sui_free_*_v1 function.
+ */
+
+#ifndef SUI_LIB_H
+#define SUI_LIB_H
+
 #include <stdarg.h>
 #include <stdbool.h>
 #include <stdint.h>
 #include <stdlib.h>
 
+/**
+ * `start_sequence_number` of `follow_checkpoints` starting at the latest checkpoint.
+ */
+#define FOLLOW_FROM_LATEST UINT64_MAX
+
+#define MAX_SIGNATURE_LENGTH 256
+
+/**
+ * Version of the handle ABI. Raised whenever a `_vN` function is added, existing functions are
+ * never changed or removed.
+ */
+#define SUI_SDK_ABI_VERSION 1
+
+#define RPC_INVALID_REQUEST -32600
+
+#define RPC_METHOD_NOT_FOUND -32601
+
+#define RPC_INVALID_PARAMS -32602
+
+/**
+ * The request did not get an answer, or a query function failed.
+ */
+#define RPC_SERVER_ERROR -32000
+
+/**
+ * Outcome of an object lookup, `Exists` is the only state carrying object data.
+ */
+typedef enum ObjectStatus {
+  Exists = 0,
+  NotExists = 1,
+  Deleted = 2,
+  VersionNotFound = 3,
+  VersionTooHigh = 4,
+  Error = 5,
+} ObjectStatus;
+
 typedef struct CArguments CArguments;
 
+typedef struct CMultiSigSession CMultiSigSession;
+
+typedef struct CObjectFilter CObjectFilter;
+
 typedef struct CProgrammableTransactionBuilder CProgrammableTransactionBuilder;
 
 typedef struct CPure CPure;
 
 typedef struct CTypeTags CTypeTags;
 
+/**
+ * A coin owned by an address.
+ */
+typedef struct SuiCoin SuiCoin;
+
+typedef struct SuiCoinList SuiCoinList;
+
+/**
+ * An object read from the network.
+ */
+typedef struct SuiObject SuiObject;
+
+typedef struct SuiObjectList SuiObjectList;
+
+/**
+ * A wallet from the keystore or a freshly generated one. Secrets are wiped when it is freed.
+ */
+typedef struct SuiWallet SuiWallet;
+
+/**
+ * Null on success, otherwise the error message, released with `sui_free_string`.
+ */
+typedef const char *SuiStatus;
+
+typedef int (*SignCallback)(const char *address,
+                            const uint8_t *intent_message,
+                            uintptr_t intent_message_len,
+                            uint8_t *signature,
+                            uintptr_t *signature_len,
+                            void *user_data);
+
 typedef struct CStringArray {
   const char *const *data;
   int len;
 } CStringArray;
 
+/**
+ * Every string is set: parts that were not requested or are absent read `"None"`, or the JSON
+ * literal `null` for `display`, `content` and `bcs`.
+ */
 typedef struct CSuiObjectData {
   char *object_id;
   uint64_t version;
@@ -71,17 +159,279 @@ typedef struct CMultiSig {
   const char *error;
 } CMultiSig;
 
+typedef struct CMultiSigSessionResult {
+  struct CMultiSigSession *session;
+  const char *error;
+} CMultiSigSessionResult;
+
+typedef struct CMultiSigMember {
+  const char *public_key;
+  uint8_t weight;
+  bool has_signed;
+  const char *signature;
+} CMultiSigMember;
+
+typedef struct CMultiSigInfo {
+  const char *address;
+  struct CMultiSigMember *members;
+  uintptr_t members_len;
+  uint16_t bitmap;
+  uint16_t threshold;
+  uint16_t signed_weight;
+  bool threshold_met;
+  const char *error;
+} CMultiSigInfo;
+
+typedef struct CZkLoginEphemeral {
+  const char *private_key;
+  const char *public_key;
+  const char *randomness;
+  const char *nonce;
+  uint64_t max_epoch;
+  const char *error;
+} CZkLoginEphemeral;
+
+typedef struct CZkLoginAddress {
+  const char *address;
+  const char *address_seed;
+  const char *iss;
+  const char *sub;
+  const char *aud;
+  const char *error;
+} CZkLoginAddress;
+
+typedef struct CSuiObjectDataOptions {
+  bool show_type;
+  bool show_owner;
+  bool show_previous_transaction;
+  bool show_display;
+  bool show_content;
+  bool show_bcs;
+  bool show_storage_rebate;
+} CSuiObjectDataOptions;
+
+typedef struct CSuiObjectPage {
+  struct CSuiObjectDataArray objects;
+  char *next_cursor;
+  bool has_next_page;
+  char *error;
+} CSuiObjectPage;
+
+typedef struct CSuiObjectResult {
+  int status;
+  struct CSuiObjectData *object;
+  char *error;
+} CSuiObjectResult;
+
+typedef struct CSuiObjectResultArray {
+  struct CSuiObjectResult *results;
+  uintptr_t len;
+  char *error;
+} CSuiObjectResultArray;
+
+typedef struct CDynamicFieldInfo {
+  char *name;
+  char *name_type;
+  char *field_type;
+  char *object_type;
+  char *object_id;
+  uint64_t version;
+  char *digest;
+} CDynamicFieldInfo;
+
+typedef struct CDynamicFieldPage {
+  struct CDynamicFieldInfo *fields;
+  uintptr_t len;
+  char *next_cursor;
+  bool has_next_page;
+  char *error;
+} CDynamicFieldPage;
+
+typedef struct CDynamicField {
+  char *name;
+  char *name_type;
+  char *field_type;
+  char *field_id;
+  char *value_object_id;
+  char *value_type;
+  char *content;
+  char *error;
+} CDynamicField;
+
+typedef struct CKioskItem {
+  char *object_id;
+  char *object_type;
+  bool is_listed;
+  uint64_t price;
+  bool is_locked;
+} CKioskItem;
+
+typedef struct CKioskContents {
+  char *kiosk_id;
+  char *owner;
+  uint64_t profits;
+  struct CKioskItem *items;
+  uintptr_t len;
+  char *error;
+} CKioskContents;
+
+typedef struct CKioskOwnerCap {
+  char *kiosk_id;
+  char *cap_id;
+} CKioskOwnerCap;
+
+typedef struct CKioskOwnerCapArray {
+  struct CKioskOwnerCap *caps;
+  uintptr_t len;
+  char *error;
+} CKioskOwnerCapArray;
+
+typedef struct CNftDisplay {
+  char *object_id;
+  char *object_type;
+  char *name;
+  char *description;
+  char *image_url;
+  char *link;
+  char *project_url;
+  char *creator;
+  char *fields;
+} CNftDisplay;
+
+typedef struct CNftPage {
+  struct CNftDisplay *nfts;
+  uintptr_t len;
+  char *next_cursor;
+  bool has_next_page;
+  char *error;
+} CNftPage;
+
+typedef struct CNftCollection {
+  char *object_type;
+  char *display_object_id;
+  uint64_t version;
+  char *name;
+  char *description;
+  char *image_url;
+  char *link;
+  char *project_url;
+  char *creator;
+  char *templates;
+  char *error;
+} CNftCollection;
+
+typedef struct CValidator {
+  char *address;
+  char *name;
+  char *description;
+  char *image_url;
+  char *project_url;
+  uint64_t commission_rate;
+  uint64_t next_epoch_commission_rate;
+  double apy;
+  char *staking_pool_id;
+  uint64_t total_stake;
+  uint64_t voting_power;
+  uint64_t gas_price;
+} CValidator;
+
+typedef struct CValidatorArray {
+  struct CValidator *validators;
+  uintptr_t len;
+  uint64_t epoch;
+  char *error;
+} CValidatorArray;
+
+typedef struct CStakedSui {
+  char *staked_sui_id;
+  char *validator_address;
+  char *staking_pool_id;
+  uint64_t principal;
+  uint64_t stake_request_epoch;
+  uint64_t stake_active_epoch;
+  char *status;
+  uint64_t estimated_reward;
+} CStakedSui;
+
+typedef struct CStakedSuiArray {
+  struct CStakedSui *stakes;
+  uintptr_t len;
+  char *error;
+} CStakedSuiArray;
+
+typedef struct CChainInfo {
+  char *chain_identifier;
+  char *network;
+  char *configured_network;
+  bool network_mismatch;
+  char *api_version;
+  uint64_t latest_checkpoint;
+  uint64_t reference_gas_price;
+  uint64_t epoch;
+  uint64_t epoch_start_timestamp_ms;
+  uint64_t epoch_duration_ms;
+  uint64_t protocol_version;
+  uint64_t total_stake;
+  char *error;
+} CChainInfo;
+
+typedef struct CCheckpoint {
+  uint64_t sequence_number;
+  char *digest;
+  char *previous_digest;
+  uint64_t epoch;
+  uint64_t timestamp_ms;
+  uint64_t network_total_transactions;
+  uint64_t computation_cost;
+  uint64_t storage_cost;
+  uint64_t storage_rebate;
+  char **transactions;
+  uintptr_t transactions_len;
+  char *error;
+} CCheckpoint;
+
+typedef struct CCheckpointPage {
+  struct CCheckpoint *checkpoints;
+  uintptr_t len;
+  char *next_cursor;
+  bool has_next_page;
+  char *error;
+} CCheckpointPage;
+
+typedef struct CCheckpointTransaction {
+  char *digest;
+  char *sender;
+  bool success;
+  char *status_error;
+  uint64_t gas_used;
+  uint64_t timestamp_ms;
+} CCheckpointTransaction;
+
+typedef struct CCheckpointTransactionArray {
+  struct CCheckpointTransaction *transactions;
+  uintptr_t len;
+  char *error;
+} CCheckpointTransactionArray;
+
+typedef int (*CheckpointCallback)(const struct CCheckpoint *checkpoint, void *user_data);
+
 typedef struct ResultCStringArray {
   struct CStringArray strings;
   const char *error;
 } ResultCStringArray;
 
+typedef struct CAddressResult {
+  const char *address;
+  const char *error;
+} CAddressResult;
+
 typedef struct Wallet {
   char *address;
   char *mnemonic;
   char *public_base64_key;
   char *private_key;
   char *key_scheme;
+  char *alias;
 } Wallet;
 
 typedef struct WalletList {
@@ -89,6 +439,25 @@ typedef struct WalletList {
   uintptr_t length;
 } WalletList;
 
+typedef struct KeyResult {
+  int status;
+  char *key;
+  char *error;
+} KeyResult;
+
+typedef struct DerivedAccount {
+  char *address;
+  char *public_base64_key;
+  char *derivation_path;
+  char *key_scheme;
+} DerivedAccount;
+
+typedef struct DerivedAccountList {
+  struct DerivedAccount *accounts;
+  uintptr_t length;
+  char *error;
+} DerivedAccountList;
+
 typedef struct ImportResult {
   int status;
   char *address;
@@ -97,12 +466,55 @@ typedef struct ImportResult {
 
 void free_strings(struct CStringArray array);
 
+void free_result_string_array(struct ResultCStringArray result);
+
+void free_address_result(struct CAddressResult result);
+
 void free_sui_object_data_list(struct CSuiObjectDataArray array);
 
+void free_u8_array(struct CU8Array array);
+
 void free_error_string(const char *error);
 
 struct CPure *bsc_basic(const char *type_, const char *data);
 
+void free_pure(struct CPure *value);
+
+struct CChainInfo get_chain_info(void);
+
+void free_chain_info(struct CChainInfo info);
+
+const char *get_chain_identifier(void);
+
+uint64_t get_latest_checkpoint_sequence_number(void);
+
+uint64_t get_reference_gas_price(void);
+
+uint64_t get_current_epoch(void);
+
+const char *get_protocol_config(void);
+
+const char *get_sui_system_state(void);
+
+struct CCheckpoint get_checkpoint_by_sequence_number(uint64_t sequence_number);
+
+struct CCheckpoint get_checkpoint_by_digest(const char *digest);
+
+void free_checkpoint(struct CCheckpoint checkpoint);
+
+struct CCheckpointPage get_checkpoints(const char *cursor, uintptr_t limit, bool descending_order);
+
+void free_checkpoint_page(struct CCheckpointPage page);
+
+struct CCheckpointTransactionArray get_checkpoint_transactions(uint64_t sequence_number);
+
+void free_checkpoint_transaction_array(struct CCheckpointTransactionArray array);
+
+SuiStatus follow_checkpoints(uint64_t start_sequence_number,
+                             uint64_t poll_interval_ms,
+                             CheckpointCallback callback,
+                             void *user_data);
+
 int32_t coin_read_api(void);
 
 uint64_t get_total_supply_sync(void);
@@ -121,16 +533,127 @@ void free_coin_array(struct CCoinArray coin_array);
 
 struct CCoinArray get_coins_sync(const char *address);
 
+struct CDynamicFieldPage get_dynamic_fields(const char *parent_object_id,
+                                           const char *cursor,
+                                           uintptr_t limit);
+
+void free_dynamic_field_page(struct CDynamicFieldPage page);
+
+struct CDynamicField get_dynamic_field(const char *parent_object_id,
+                                       const char *name_type,
+                                       struct CU8Array name_bcs);
+
+void free_dynamic_field(struct CDynamicField field);
+
+const char *create_kiosk(const char *sender, uint64_t gas_budget);
+
+const char *kiosk_place(const char *sender,
+                        const char *kiosk_id,
+                        const char *cap_id,
+                        const char *item_id,
+                        bool lock,
+                        uint64_t gas_budget);
+
+const char *kiosk_list(const char *sender,
+                       const char *kiosk_id,
+                       const char *cap_id,
+                       const char *item_id,
+                       uint64_t price,
+                       uint64_t gas_budget);
+
+const char *kiosk_delist(const char *sender,
+                         const char *kiosk_id,
+                         const char *cap_id,
+                         const char *item_id,
+                         uint64_t gas_budget);
+
+const char *kiosk_purchase(const char *sender,
+                           const char *kiosk_id,
+                           const char *item_id,
+                           const char *buyer_kiosk_id,
+                           const char *buyer_cap_id,
+                           uint64_t gas_budget);
+
+const char *kiosk_withdraw_profits(const char *sender,
+                                   const char *kiosk_id,
+                                   const char *cap_id,
+                                   uint64_t amount,
+                                   uint64_t gas_budget);
+
+struct CKioskContents get_kiosk_contents(const char *kiosk_id);
+
+void free_kiosk_contents(struct CKioskContents contents);
+
+struct CKioskOwnerCapArray get_owned_kiosks(const char *address);
+
+void free_kiosk_owner_cap_array(struct CKioskOwnerCapArray array);
+
 void free_multisig(struct CMultiSig multisig);
 
 struct CMultiSig get_or_create_multisig(struct CStringArray addresses,
                                         struct CU8Array weights,
                                         uint16_t threshold);
 
+struct CMultiSig create_multisig_from_public_keys(struct CStringArray public_keys,
+                                                 struct CStringArray schemes,
+                                                 struct CU8Array weights,
+                                                 uint16_t threshold);
+
+const char *zklogin_multisig_member(const char *iss, const char *address_seed);
+
+const char *multisig_address_from_bytes(struct CU8Array multisig);
+
 const char *sign_and_execute_transaction_miltisig(struct CU8Array multisig,
                                                   struct CU8Array tx,
                                                   struct CStringArray addresses);
 
+struct CMultiSigSessionResult create_multisig_session(struct CU8Array multisig, struct CU8Array tx);
+
+struct CMultiSigSessionResult create_multisig_session_from_public_keys(struct CStringArray public_keys,
+                                                                       struct CU8Array weights,
+                                                                       uint16_t threshold,
+                                                                       struct CU8Array tx);
+
+void destroy_multisig_session(struct CMultiSigSession *session);
+
+SuiStatus multisig_session_add_signature(struct CMultiSigSession *session,
+                                         const char *signature);
+
+SuiStatus multisig_session_sign_with_keystore(struct CMultiSigSession *session,
+                                              const char *address);
+
+SuiStatus multisig_session_sign_with_signer(struct CMultiSigSession *session,
+                                            const char *address);
+
+struct CU8Array multisig_session_signing_message(const struct CMultiSigSession *session);
+
+uint16_t multisig_session_bitmap(const struct CMultiSigSession *session);
+
+uint16_t multisig_session_weight(const struct CMultiSigSession *session);
+
+uint16_t multisig_session_threshold(const struct CMultiSigSession *session);
+
+bool multisig_session_is_threshold_met(const struct CMultiSigSession *session);
+
+struct CU8Array multisig_session_combine(const struct CMultiSigSession *session);
+
+const char *multisig_session_execute(const struct CMultiSigSession *session);
+
+void free_multisig_info(struct CMultiSigInfo info);
+
+struct CU8Array combine_partial_signatures_multisig(struct CU8Array multisig,
+                                                    struct CStringArray signatures);
+
+struct CMultiSigInfo decode_multisig_signature(struct CU8Array multisig);
+
+struct CMultiSigInfo decode_multisig_signature_base64(const char *multisig);
+
+SuiStatus verify_multisig_signature(struct CU8Array multisig, struct CU8Array tx);
+
+const char *resolve_sui_name(const char *name);
+
+struct ResultCStringArray lookup_sui_names(const char *address);
+
 const char *mint_nft(const char *package_id,
                      const char *sender_address,
                      const char *name,
@@ -142,6 +665,93 @@ const char *transfer_nft(const char *package_id,
                          const char *nft_id,
                          const char *recipient_address);
 
+struct CNftPage get_wallet_nfts(const char *address, const char *cursor, uintptr_t limit);
+
+void free_nft_page(struct CNftPage page);
+
+struct CNftCollection get_nft_collection(const char *object_type);
+
+void free_nft_collection(struct CNftCollection collection);
+
+const char *resolve_nft_image_url(const char *url, const char *ipfs_gateway);
+
+const char *transfer_object(const char *sender_address,
+                            const char *object_id,
+                            const char *recipient_address,
+                            uint64_t gas_budget);
+
+const char *transfer_objects(const char *sender_address,
+                             struct CStringArray object_ids,
+                             const char *recipient_address,
+                             uint64_t gas_budget);
+
+const char *mint_nft_with_call(const char *sender_address,
+                               const char *package_id,
+                               const char *module,
+                               const char *function,
+                               struct CStringArray type_arguments,
+                               struct CStringArray argument_types,
+                               struct CStringArray argument_values,
+                               uint64_t gas_budget);
+
+struct CValidatorArray get_validators(void);
+
+void free_validator_array(struct CValidatorArray array);
+
+struct CStakedSuiArray get_stakes(const char *address);
+
+void free_staked_sui_array(struct CStakedSuiArray array);
+
+const char *stake_sui(const char *sender,
+                      const char *validator_address,
+                      uint64_t amount,
+                      uint64_t gas_budget);
+
+const char *withdraw_stake(const char *sender, const char *staked_sui_id, uint64_t gas_budget);
+
+struct CObjectFilter *object_filter_struct_type(const char *struct_type);
+
+struct CObjectFilter *object_filter_package(const char *package_id);
+
+struct CObjectFilter *object_filter_move_module(const char *package_id, const char *module);
+
+struct CObjectFilter *object_filter_match_all(struct CObjectFilter *const *filters, uintptr_t len);
+
+struct CObjectFilter *object_filter_match_any(struct CObjectFilter *const *filters, uintptr_t len);
+
+struct CObjectFilter *object_filter_match_none(struct CObjectFilter *const *filters, uintptr_t len);
+
+void destroy_object_filter(struct CObjectFilter *filter);
+
+struct CSuiObjectPage get_owned_objects(const char *address,
+                                        const struct CObjectFilter *filter,
+                                        struct CSuiObjectDataOptions options,
+                                        const char *cursor,
+                                        uintptr_t limit);
+
+void free_sui_object_page(struct CSuiObjectPage page);
+
+struct CSuiObjectDataOptions sui_object_data_options_full(void);
+
+struct CSuiObjectResult get_object(const char *object_id, struct CSuiObjectDataOptions options);
+
+struct CSuiObjectResultArray multi_get_objects(struct CStringArray object_ids,
+                                               struct CSuiObjectDataOptions options);
+
+struct CSuiObjectResult get_past_object(const char *object_id,
+                                        uint64_t version,
+                                        struct CSuiObjectDataOptions options);
+
+void free_sui_object_result(struct CSuiObjectResult result);
+
+void free_sui_object_result_array(struct CSuiObjectResultArray array);
+
+void register_signer_callback(SignCallback callback, void *user_data);
+
+SuiStatus register_local_signer(struct CStringArray private_keys);
+
+void reset_signer(void);
+
 int32_t test(void);
 
 int32_t build_mainnet(void);
@@ -150,6 +760,8 @@ int32_t build_testnet(void);
 
 int32_t build_devnet(void);
 
+int32_t build_custom(const char *rpc_url);
+
 struct ResultCStringArray available_rpc_methods(void);
 
 struct ResultCStringArray available_subscriptions(void);
@@ -195,8 +807,54 @@ struct ImportResult *import_from_mnemonic(const char *mnemonic,
 
 struct ImportResult *import_from_private_key(const char *key_base64);
 
+struct Wallet *get_wallet_with_private_key(const char *address);
+
+struct ImportResult *import_from_private_key_with_scheme(const char *private_key,
+                                                         const char *key_scheme);
+
+struct KeyResult *export_private_key(const char *address, const char *format);
+
+struct KeyResult *convert_private_key(const char *private_key,
+                                      const char *key_scheme,
+                                      const char *format);
+
+void free_import_result(struct ImportResult *result);
+
+void free_key_result(struct KeyResult *result);
+
 struct Wallet *get_wallet_from_address(const char *address);
 
+struct DerivedAccountList derive_accounts_from_mnemonic(const char *mnemonic,
+                                                        const char *key_scheme,
+                                                        uint32_t start_index,
+                                                        uint32_t count);
+
+struct DerivedAccountList derive_accounts_from_paths(const char *mnemonic,
+                                                     const char *key_scheme,
+                                                     struct CStringArray derivation_paths);
+
+void free_derived_account_list(struct DerivedAccountList account_list);
+
+struct ImportResult *import_from_mnemonic_with_path(const char *mnemonic,
+                                                    const char *key_scheme,
+                                                    const char *derivation_path,
+                                                    const char *alias);
+
+struct DerivedAccountList import_accounts_from_mnemonic(const char *mnemonic,
+                                                        const char *key_scheme,
+                                                        const uint32_t *account_indexes,
+                                                        uintptr_t account_indexes_len);
+
+struct CAddressResult get_address_by_alias(const char *alias);
+
+struct CAddressResult rename_alias(const char *old_alias, const char *new_alias);
+
+struct CAddressResult remove_key(const char *address_or_alias);
+
+struct CAddressResult get_active_address(void);
+
+struct CAddressResult set_active_address(const char *address_or_alias);
+
 struct CSuiObjectDataArray get_wallet_objects(const char *address, const char *object_type);
 
 struct CTypeTags *create_type_tags(void);
@@ -221,6 +879,11 @@ void make_pure(struct CProgrammableTransactionBuilder *builder,
                struct CArguments *arguments,
                struct CPure *value);
 
+SuiStatus make_object(struct CProgrammableTransactionBuilder *builder,
+                      struct CArguments *arguments,
+                      const char *object_id,
+                      bool is_mutable);
+
 struct CProgrammableTransactionBuilder *create_builder(void);
 
 void destroy_builder(struct CProgrammableTransactionBuilder *builder);
@@ -248,7 +911,277 @@ char *execute_transaction(struct CProgrammableTransactionBuilder *builder,
                           const char *sender,
                           unsigned long long gas_budget);
 
+struct CU8Array build_transaction_data(struct CProgrammableTransactionBuilder *builder,
+                                       const char *sender,
+                                       unsigned long long gas_budget);
+
 char *execute_transaction_allow_sponser(struct CProgrammableTransactionBuilder *builder,
                                         const char *sender,
                                         unsigned long long gas_budget,
                                         const char *sponser);
+
+struct CZkLoginEphemeral zklogin_generate_ephemeral(uint64_t max_epoch, const char *randomness);
+
+void free_zklogin_ephemeral(struct CZkLoginEphemeral ephemeral);
+
+struct CZkLoginAddress zklogin_address_from_jwt(const char *jwt, const char *salt);
+
+void free_zklogin_address(struct CZkLoginAddress address);
+
+const char *zklogin_sign_with_ephemeral(const char *ephemeral_private_key, struct CU8Array tx);
+
+struct CU8Array zklogin_assemble_signature(const char *proof_json,
+                                           const char *address_seed,
+                                           uint64_t max_epoch,
+                                           const char *ephemeral_signature);
+
+const char *zklogin_execute_transaction(struct CU8Array tx, struct CU8Array signature);
+
+/**
+ * Any `const char *` / `char *` returned by the library, including error messages.
+ */
+void sui_free_string(const char *s);
+
+/**
+ * A `CStringArray` returned by the library, the strings and the array.
+ */
+void sui_free_string_array(struct CStringArray array);
+
+void sui_free_result_string_array(struct ResultCStringArray result);
+
+void sui_free_address_result(struct CAddressResult result);
+
+/**
+ * Bytes and error of a `CU8Array` returned by the library.
+ */
+void sui_free_u8_array(struct CU8Array array);
+
+/**
+ * A value from `bsc_basic`, `make_pure` copies it so it can be freed right after.
+ */
+void sui_free_pure(struct CPure *value);
+
+void sui_free_object_data_array(struct CSuiObjectDataArray array);
+
+void sui_free_object_page(struct CSuiObjectPage page);
+
+void sui_free_object_result(struct CSuiObjectResult result);
+
+void sui_free_object_result_array(struct CSuiObjectResultArray array);
+
+/**
+ * A filter that was not passed to an `object_filter_match_*` combinator.
+ */
+void sui_free_object_filter(struct CObjectFilter *filter);
+
+void sui_free_chain_info(struct CChainInfo info);
+
+void sui_free_checkpoint(struct CCheckpoint checkpoint);
+
+void sui_free_checkpoint_page(struct CCheckpointPage page);
+
+void sui_free_checkpoint_transaction_array(struct CCheckpointTransactionArray array);
+
+void sui_free_balance(struct CBalance balance);
+
+void sui_free_balance_array(struct CBalanceArray array);
+
+void sui_free_coin_array(struct CCoinArray array);
+
+void sui_free_dynamic_field_page(struct CDynamicFieldPage page);
+
+void sui_free_dynamic_field(struct CDynamicField field);
+
+void sui_free_kiosk_contents(struct CKioskContents contents);
+
+void sui_free_kiosk_owner_cap_array(struct CKioskOwnerCapArray array);
+
+void sui_free_multisig(struct CMultiSig multisig);
+
+/**
+ * The session of a `CMultiSigSessionResult`, its `error` goes to `sui_free_string`.
+ */
+void sui_free_multisig_session(struct CMultiSigSession *session);
+
+void sui_free_multisig_info(struct CMultiSigInfo info);
+
+void sui_free_nft_page(struct CNftPage page);
+
+void sui_free_nft_collection(struct CNftCollection collection);
+
+void sui_free_validator_array(struct CValidatorArray array);
+
+void sui_free_staked_sui_array(struct CStakedSuiArray array);
+
+void sui_free_type_tags(struct CTypeTags *type_tags);
+
+void sui_free_arguments(struct CArguments *arguments);
+
+/**
+ * A builder that was not consumed by executing or building its transaction.
+ */
+void sui_free_builder(struct CProgrammableTransactionBuilder *builder);
+
+void sui_free_wallet(struct Wallet *wallet);
+
+void sui_free_wallet_list(struct WalletList wallet_list);
+
+void sui_free_import_result(struct ImportResult *result);
+
+void sui_free_key_result(struct KeyResult *result);
+
+void sui_free_derived_account_list(struct DerivedAccountList account_list);
+
+void sui_free_zklogin_ephemeral(struct CZkLoginEphemeral ephemeral);
+
+void sui_free_zklogin_address(struct CZkLoginAddress address);
+
+uint32_t sui_sdk_abi_version(void);
+
+/**
+ * The object with its type, owner, display and content; missing or deleted objects are errors.
+ */
+struct SuiObject *sui_object_fetch_v1(const char *object_id, char **error);
+
+/**
+ * Every object owned by `address`, only those of `object_type` unless it is null.
+ */
+struct SuiObjectList *sui_owned_objects_fetch_v1(const char *address,
+                                                 const char *object_type,
+                                                 char **error);
+
+uintptr_t sui_object_list_len_v1(const struct SuiObjectList *list);
+
+/**
+ * Borrowed from the list, null when `index` is out of range.
+ */
+const struct SuiObject *sui_object_list_at_v1(const struct SuiObjectList *list, uintptr_t index);
+
+void sui_free_object_list_v1(struct SuiObjectList *list);
+
+/**
+ * An object from `sui_object_fetch_v1`, never one borrowed from a list.
+ */
+void sui_free_object_v1(struct SuiObject *object);
+
+const char *sui_object_get_id_v1(const struct SuiObject *object);
+
+uint64_t sui_object_get_version_v1(const struct SuiObject *object);
+
+const char *sui_object_get_digest_v1(const struct SuiObject *object);
+
+const char *sui_object_get_type_v1(const struct SuiObject *object);
+
+const char *sui_object_get_owner_v1(const struct SuiObject *object);
+
+const char *sui_object_get_previous_transaction_v1(const struct SuiObject *object);
+
+uint64_t sui_object_get_storage_rebate_v1(const struct SuiObject *object);
+
+/**
+ * The `Display` of the object as JSON.
+ */
+const char *sui_object_get_display_json_v1(const struct SuiObject *object);
+
+/**
+ * The fields of a Move object, or the modules of a package, as JSON.
+ */
+const char *sui_object_get_content_json_v1(const struct SuiObject *object);
+
+const char *sui_object_get_bcs_json_v1(const struct SuiObject *object);
+
+/**
+ * The SUI coins owned by `address`, every page of them.
+ */
+struct SuiCoinList *sui_coins_fetch_v1(const char *address, char **error);
+
+uintptr_t sui_coin_list_len_v1(const struct SuiCoinList *list);
+
+/**
+ * Borrowed from the list, null when `index` is out of range.
+ */
+const struct SuiCoin *sui_coin_list_at_v1(const struct SuiCoinList *list, uintptr_t index);
+
+void sui_free_coin_list_v1(struct SuiCoinList *list);
+
+const char *sui_coin_get_type_v1(const struct SuiCoin *coin);
+
+const char *sui_coin_get_id_v1(const struct SuiCoin *coin);
+
+uint64_t sui_coin_get_version_v1(const struct SuiCoin *coin);
+
+const char *sui_coin_get_digest_v1(const struct SuiCoin *coin);
+
+uint64_t sui_coin_get_balance_v1(const struct SuiCoin *coin);
+
+const char *sui_coin_get_previous_transaction_v1(const struct SuiCoin *coin);
+
+/**
+ * A new key pair with its mnemonic, not added to the keystore. `key_scheme` and `word_length`
+ * take the values of `generate_wallet`.
+ */
+struct SuiWallet *sui_wallet_generate_v1(const char *key_scheme,
+                                         const char *word_length,
+                                         char **error);
+
+/**
+ * The keystore entry of `address`, without its private key.
+ */
+struct SuiWallet *sui_wallet_from_address_v1(const char *address, char **error);
+
+void sui_free_wallet_v1(struct SuiWallet *wallet);
+
+const char *sui_wallet_get_address_v1(const struct SuiWallet *wallet);
+
+const char *sui_wallet_get_public_key_v1(const struct SuiWallet *wallet);
+
+const char *sui_wallet_get_key_scheme_v1(const struct SuiWallet *wallet);
+
+const char *sui_wallet_get_alias_v1(const struct SuiWallet *wallet);
+
+/**
+ * Only set for generated wallets.
+ */
+const char *sui_wallet_get_mnemonic_v1(const struct SuiWallet *wallet);
+
+/**
+ * Base64 private key with its scheme flag, only set for generated wallets.
+ */
+const char *sui_wallet_get_private_key_v1(const struct SuiWallet *wallet);
+
+/**
+ * Forward a JSON-RPC call, `params_json` is a JSON array or object (null for no params).
+ */
+const char *sui_rpc_call(const char *method, const char *params_json);
+
+/**
+ * The object with every field, a missing object is an error carrying its ObjectStatus as data.
+ */
+const char *get_object_json(const char *object_id);
+
+/**
+ * One page of the objects owned by `address`, pass the previous `nextCursor` (or null for the
+ * first page) and a limit of 0 for the node default.
+ */
+const char *get_owned_objects_json(const char *address, const char *cursor, uintptr_t limit);
+
+const char *get_dynamic_fields_json(const char *parent_object_id,
+                                    const char *cursor,
+                                    uintptr_t limit);
+
+const char *get_coins_json(const char *address);
+
+const char *get_balance_json(const char *address);
+
+const char *get_all_balances_json(const char *address);
+
+const char *get_checkpoint_json(uint64_t sequence_number);
+
+/**
+ * One page of checkpoints, `cursor` is the last sequence number of the previous page (or null).
+ */
+const char *get_checkpoints_json(const char *cursor, uintptr_t limit, bool descending_order);
+
+const char *get_checkpoint_transactions_json(uint64_t sequence_number);
+
+#endif /* SUI_LIB_H */
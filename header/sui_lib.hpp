@@ -0,0 +1,1851 @@
+// Generated by tools/sui_lib_hpp.rs from sui_lib.h, do not edit.
+//
+// Header-only C++17 wrapper of the C API: results are RAII values released with their sui_free_*
+// function, errors are thrown as sui::Error, strings and arrays are std::string and std::vector,
+// and calls taking only values have a _async variant returning a std::future.
+
+#pragma once
+
+#include <cstdint>
+#include <cstring>
+#include <future>
+#include <memory>
+#include <optional>
+#include <stdexcept>
+#include <string>
+#include <utility>
+#include <vector>
+
+extern "C"
+{
+#include "sui_lib.h"
+}
+
+namespace sui
+{
+
+// An error reported by the SDK, `code` is the status of the result (ObjectStatus, the status of
+// ImportResult and KeyResult) or -1 when the result has none.
+class Error : public std::runtime_error
+{
+public:
+    Error(const std::string &message, int code) : std::runtime_error(message), code_(code) {}
+
+    int code() const noexcept { return code_; }
+
+private:
+    int code_;
+};
+
+// A string argument, converts from std::string, string literals and nullptr (absent).
+class Str
+{
+public:
+    Str(std::nullptr_t) {}
+    Str(const char *value)
+    {
+        if (value != nullptr)
+        {
+            value_ = value;
+        }
+    }
+    Str(std::string value) : value_(std::move(value)) {}
+
+    const char *c_str() const { return value_ ? value_->c_str() : nullptr; }
+
+private:
+    std::optional<std::string> value_;
+};
+
+// A struct returned by value, released with `Free` when it goes out of scope.
+template <typename T, void (*Free)(T)>
+class Owned
+{
+public:
+    explicit Owned(T value) : value_(value), owned_(true) {}
+    Owned(Owned &&other) noexcept : value_(other.value_), owned_(std::exchange(other.owned_, false)) {}
+    Owned &operator=(Owned &&other) noexcept
+    {
+        if (this != &other)
+        {
+            reset();
+            value_ = other.value_;
+            owned_ = std::exchange(other.owned_, false);
+        }
+        return *this;
+    }
+    Owned(const Owned &) = delete;
+    Owned &operator=(const Owned &) = delete;
+    ~Owned() { reset(); }
+
+    const T &get() const { return value_; }
+    const T *operator->() const { return &value_; }
+    const T &operator*() const { return value_; }
+
+private:
+    void reset()
+    {
+        if (owned_)
+        {
+            Free(value_);
+            owned_ = false;
+        }
+    }
+
+    T value_;
+    bool owned_;
+};
+
+template <typename T, void (*Free)(T *)>
+struct Deleter
+{
+    void operator()(T *value) const { Free(value); }
+};
+
+// A pointer returned by the library, released with `Free`. Call release() before passing it to
+// a function that consumes it (execute_transaction, object_filter_match_*).
+template <typename T, void (*Free)(T *)>
+using Handle = std::unique_ptr<T, Deleter<T, Free>>;
+
+// Iterate over an array of a result without copying it, valid while the result lives.
+template <typename T>
+struct Span
+{
+    const T *data;
+    size_t len;
+
+    const T *begin() const { return data; }
+    const T *end() const { return data + len; }
+    size_t size() const { return len; }
+    const T &operator[](size_t index) const { return data[index]; }
+};
+
+template <typename T>
+Span<T> span(const T *data, size_t len)
+{
+    return Span<T>{data, data == nullptr ? 0 : len};
+}
+
+namespace detail
+{
+
+// The error field of a result, released together with the result
+inline void check(const char *error, int code)
+{
+    if (error != nullptr)
+    {
+        throw Error(error, code);
+    }
+}
+
+// An error message owned by the caller, released before throwing
+inline void check_status(const char *error)
+{
+    if (error != nullptr)
+    {
+        std::string message(error);
+        sui_free_string(error);
+        throw Error(message, -1);
+    }
+}
+
+inline std::string take_string(const char *value)
+{
+    if (value == nullptr)
+    {
+        return std::string();
+    }
+    std::string result(value);
+    sui_free_string(value);
+    return result;
+}
+
+inline std::optional<std::string> borrowed(const char *value)
+{
+    if (value == nullptr)
+    {
+        return std::nullopt;
+    }
+    return std::string(value);
+}
+
+inline std::vector<std::string> take_strings(ResultCStringArray result)
+{
+    Owned<ResultCStringArray, sui_free_result_string_array> owned(result);
+    check(owned->error, -1);
+    std::vector<std::string> strings;
+    for (int i = 0; i < owned->strings.len; i++)
+    {
+        strings.emplace_back(owned->strings.data[i]);
+    }
+    return strings;
+}
+
+inline std::vector<uint8_t> take_bytes(CU8Array array)
+{
+    Owned<CU8Array, sui_free_u8_array> owned(array);
+    check(owned->error, -1);
+    if (owned->data == nullptr)
+    {
+        return std::vector<uint8_t>();
+    }
+    return std::vector<uint8_t>(owned->data, owned->data + owned->len);
+}
+
+// A borrowed CStringArray over strings owned by the caller.
+class StringArray
+{
+public:
+    explicit StringArray(const std::vector<std::string> &strings)
+    {
+        for (const std::string &string : strings)
+        {
+            pointers_.push_back(string.c_str());
+        }
+    }
+
+    CStringArray get() const { return CStringArray{pointers_.data(), static_cast<int>(pointers_.size())}; }
+
+private:
+    std::vector<const char *> pointers_;
+};
+
+inline CU8Array bytes(const std::vector<uint8_t> &bytes)
+{
+    return CU8Array{bytes.data(), static_cast<unsigned int>(bytes.size()), nullptr};
+}
+
+} // namespace detail
+
+inline Handle<CPure, sui_free_pure> bsc_basic(Str type_, Str data)
+{
+    Handle<CPure, sui_free_pure> result(::bsc_basic(type_.c_str(), data.c_str()));
+    return result;
+}
+
+inline std::future<Handle<CPure, sui_free_pure>> bsc_basic_async(Str type_, Str data)
+{
+    return std::async(std::launch::async, bsc_basic, std::move(type_), std::move(data));
+}
+
+inline Owned<CChainInfo, sui_free_chain_info> get_chain_info()
+{
+    Owned<CChainInfo, sui_free_chain_info> result(::get_chain_info());
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CChainInfo, sui_free_chain_info>> get_chain_info_async()
+{
+    return std::async(std::launch::async, get_chain_info);
+}
+
+inline std::string get_chain_identifier()
+{
+    return detail::take_string(::get_chain_identifier());
+}
+
+inline std::future<std::string> get_chain_identifier_async()
+{
+    return std::async(std::launch::async, get_chain_identifier);
+}
+
+inline uint64_t get_latest_checkpoint_sequence_number()
+{
+    return ::get_latest_checkpoint_sequence_number();
+}
+
+inline std::future<uint64_t> get_latest_checkpoint_sequence_number_async()
+{
+    return std::async(std::launch::async, get_latest_checkpoint_sequence_number);
+}
+
+inline uint64_t get_reference_gas_price()
+{
+    return ::get_reference_gas_price();
+}
+
+inline std::future<uint64_t> get_reference_gas_price_async()
+{
+    return std::async(std::launch::async, get_reference_gas_price);
+}
+
+inline uint64_t get_current_epoch()
+{
+    return ::get_current_epoch();
+}
+
+inline std::future<uint64_t> get_current_epoch_async()
+{
+    return std::async(std::launch::async, get_current_epoch);
+}
+
+inline std::string get_protocol_config()
+{
+    return detail::take_string(::get_protocol_config());
+}
+
+inline std::future<std::string> get_protocol_config_async()
+{
+    return std::async(std::launch::async, get_protocol_config);
+}
+
+inline std::string get_sui_system_state()
+{
+    return detail::take_string(::get_sui_system_state());
+}
+
+inline std::future<std::string> get_sui_system_state_async()
+{
+    return std::async(std::launch::async, get_sui_system_state);
+}
+
+inline Owned<CCheckpoint, sui_free_checkpoint> get_checkpoint_by_sequence_number(uint64_t sequence_number)
+{
+    Owned<CCheckpoint, sui_free_checkpoint> result(::get_checkpoint_by_sequence_number(sequence_number));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CCheckpoint, sui_free_checkpoint>> get_checkpoint_by_sequence_number_async(uint64_t sequence_number)
+{
+    return std::async(std::launch::async, get_checkpoint_by_sequence_number, std::move(sequence_number));
+}
+
+inline Owned<CCheckpoint, sui_free_checkpoint> get_checkpoint_by_digest(Str digest)
+{
+    Owned<CCheckpoint, sui_free_checkpoint> result(::get_checkpoint_by_digest(digest.c_str()));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CCheckpoint, sui_free_checkpoint>> get_checkpoint_by_digest_async(Str digest)
+{
+    return std::async(std::launch::async, get_checkpoint_by_digest, std::move(digest));
+}
+
+inline Owned<CCheckpointPage, sui_free_checkpoint_page> get_checkpoints(Str cursor, uintptr_t limit, bool descending_order)
+{
+    Owned<CCheckpointPage, sui_free_checkpoint_page> result(::get_checkpoints(cursor.c_str(), limit, descending_order));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CCheckpointPage, sui_free_checkpoint_page>> get_checkpoints_async(Str cursor, uintptr_t limit, bool descending_order)
+{
+    return std::async(std::launch::async, get_checkpoints, std::move(cursor), std::move(limit), std::move(descending_order));
+}
+
+inline Owned<CCheckpointTransactionArray, sui_free_checkpoint_transaction_array> get_checkpoint_transactions(uint64_t sequence_number)
+{
+    Owned<CCheckpointTransactionArray, sui_free_checkpoint_transaction_array> result(::get_checkpoint_transactions(sequence_number));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CCheckpointTransactionArray, sui_free_checkpoint_transaction_array>> get_checkpoint_transactions_async(uint64_t sequence_number)
+{
+    return std::async(std::launch::async, get_checkpoint_transactions, std::move(sequence_number));
+}
+
+inline void follow_checkpoints(uint64_t start_sequence_number, uint64_t poll_interval_ms, CheckpointCallback callback, void *user_data)
+{
+    detail::check_status(::follow_checkpoints(start_sequence_number, poll_interval_ms, callback, user_data));
+}
+
+inline int32_t coin_read_api()
+{
+    return ::coin_read_api();
+}
+
+inline std::future<int32_t> coin_read_api_async()
+{
+    return std::async(std::launch::async, coin_read_api);
+}
+
+inline uint64_t get_total_supply_sync()
+{
+    return ::get_total_supply_sync();
+}
+
+inline std::future<uint64_t> get_total_supply_sync_async()
+{
+    return std::async(std::launch::async, get_total_supply_sync);
+}
+
+inline Owned<CBalance, sui_free_balance> get_balance_sync(Str address)
+{
+    Owned<CBalance, sui_free_balance> result(::get_balance_sync(address.c_str()));
+    return result;
+}
+
+inline std::future<Owned<CBalance, sui_free_balance>> get_balance_sync_async(Str address)
+{
+    return std::async(std::launch::async, get_balance_sync, std::move(address));
+}
+
+inline Owned<CBalanceArray, sui_free_balance_array> get_all_balances_sync(Str address)
+{
+    Owned<CBalanceArray, sui_free_balance_array> result(::get_all_balances_sync(address.c_str()));
+    return result;
+}
+
+inline std::future<Owned<CBalanceArray, sui_free_balance_array>> get_all_balances_sync_async(Str address)
+{
+    return std::async(std::launch::async, get_all_balances_sync, std::move(address));
+}
+
+inline Owned<CBalanceArray, sui_free_balance_array> get_balances(Str address)
+{
+    Owned<CBalanceArray, sui_free_balance_array> result(::get_balances(address.c_str()));
+    return result;
+}
+
+inline std::future<Owned<CBalanceArray, sui_free_balance_array>> get_balances_async(Str address)
+{
+    return std::async(std::launch::async, get_balances, std::move(address));
+}
+
+inline Owned<CCoinArray, sui_free_coin_array> get_coins_sync(Str address)
+{
+    Owned<CCoinArray, sui_free_coin_array> result(::get_coins_sync(address.c_str()));
+    return result;
+}
+
+inline std::future<Owned<CCoinArray, sui_free_coin_array>> get_coins_sync_async(Str address)
+{
+    return std::async(std::launch::async, get_coins_sync, std::move(address));
+}
+
+inline Owned<CDynamicFieldPage, sui_free_dynamic_field_page> get_dynamic_fields(Str parent_object_id, Str cursor, uintptr_t limit)
+{
+    Owned<CDynamicFieldPage, sui_free_dynamic_field_page> result(::get_dynamic_fields(parent_object_id.c_str(), cursor.c_str(), limit));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CDynamicFieldPage, sui_free_dynamic_field_page>> get_dynamic_fields_async(Str parent_object_id, Str cursor, uintptr_t limit)
+{
+    return std::async(std::launch::async, get_dynamic_fields, std::move(parent_object_id), std::move(cursor), std::move(limit));
+}
+
+inline Owned<CDynamicField, sui_free_dynamic_field> get_dynamic_field(Str parent_object_id, Str name_type, std::vector<uint8_t> name_bcs)
+{
+    Owned<CDynamicField, sui_free_dynamic_field> result(::get_dynamic_field(parent_object_id.c_str(), name_type.c_str(), detail::bytes(name_bcs)));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CDynamicField, sui_free_dynamic_field>> get_dynamic_field_async(Str parent_object_id, Str name_type, std::vector<uint8_t> name_bcs)
+{
+    return std::async(std::launch::async, get_dynamic_field, std::move(parent_object_id), std::move(name_type), std::move(name_bcs));
+}
+
+inline std::string create_kiosk(Str sender, uint64_t gas_budget)
+{
+    return detail::take_string(::create_kiosk(sender.c_str(), gas_budget));
+}
+
+inline std::future<std::string> create_kiosk_async(Str sender, uint64_t gas_budget)
+{
+    return std::async(std::launch::async, create_kiosk, std::move(sender), std::move(gas_budget));
+}
+
+inline std::string kiosk_place(Str sender, Str kiosk_id, Str cap_id, Str item_id, bool lock, uint64_t gas_budget)
+{
+    return detail::take_string(::kiosk_place(sender.c_str(), kiosk_id.c_str(), cap_id.c_str(), item_id.c_str(), lock, gas_budget));
+}
+
+inline std::future<std::string> kiosk_place_async(Str sender, Str kiosk_id, Str cap_id, Str item_id, bool lock, uint64_t gas_budget)
+{
+    return std::async(std::launch::async, kiosk_place, std::move(sender), std::move(kiosk_id), std::move(cap_id), std::move(item_id), std::move(lock), std::move(gas_budget));
+}
+
+inline std::string kiosk_list(Str sender, Str kiosk_id, Str cap_id, Str item_id, uint64_t price, uint64_t gas_budget)
+{
+    return detail::take_string(::kiosk_list(sender.c_str(), kiosk_id.c_str(), cap_id.c_str(), item_id.c_str(), price, gas_budget));
+}
+
+inline std::future<std::string> kiosk_list_async(Str sender, Str kiosk_id, Str cap_id, Str item_id, uint64_t price, uint64_t gas_budget)
+{
+    return std::async(std::launch::async, kiosk_list, std::move(sender), std::move(kiosk_id), std::move(cap_id), std::move(item_id), std::move(price), std::move(gas_budget));
+}
+
+inline std::string kiosk_delist(Str sender, Str kiosk_id, Str cap_id, Str item_id, uint64_t gas_budget)
+{
+    return detail::take_string(::kiosk_delist(sender.c_str(), kiosk_id.c_str(), cap_id.c_str(), item_id.c_str(), gas_budget));
+}
+
+inline std::future<std::string> kiosk_delist_async(Str sender, Str kiosk_id, Str cap_id, Str item_id, uint64_t gas_budget)
+{
+    return std::async(std::launch::async, kiosk_delist, std::move(sender), std::move(kiosk_id), std::move(cap_id), std::move(item_id), std::move(gas_budget));
+}
+
+inline std::string kiosk_purchase(Str sender, Str kiosk_id, Str item_id, Str buyer_kiosk_id, Str buyer_cap_id, uint64_t gas_budget)
+{
+    return detail::take_string(::kiosk_purchase(sender.c_str(), kiosk_id.c_str(), item_id.c_str(), buyer_kiosk_id.c_str(), buyer_cap_id.c_str(), gas_budget));
+}
+
+inline std::future<std::string> kiosk_purchase_async(Str sender, Str kiosk_id, Str item_id, Str buyer_kiosk_id, Str buyer_cap_id, uint64_t gas_budget)
+{
+    return std::async(std::launch::async, kiosk_purchase, std::move(sender), std::move(kiosk_id), std::move(item_id), std::move(buyer_kiosk_id), std::move(buyer_cap_id), std::move(gas_budget));
+}
+
+inline std::string kiosk_withdraw_profits(Str sender, Str kiosk_id, Str cap_id, uint64_t amount, uint64_t gas_budget)
+{
+    return detail::take_string(::kiosk_withdraw_profits(sender.c_str(), kiosk_id.c_str(), cap_id.c_str(), amount, gas_budget));
+}
+
+inline std::future<std::string> kiosk_withdraw_profits_async(Str sender, Str kiosk_id, Str cap_id, uint64_t amount, uint64_t gas_budget)
+{
+    return std::async(std::launch::async, kiosk_withdraw_profits, std::move(sender), std::move(kiosk_id), std::move(cap_id), std::move(amount), std::move(gas_budget));
+}
+
+inline Owned<CKioskContents, sui_free_kiosk_contents> get_kiosk_contents(Str kiosk_id)
+{
+    Owned<CKioskContents, sui_free_kiosk_contents> result(::get_kiosk_contents(kiosk_id.c_str()));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CKioskContents, sui_free_kiosk_contents>> get_kiosk_contents_async(Str kiosk_id)
+{
+    return std::async(std::launch::async, get_kiosk_contents, std::move(kiosk_id));
+}
+
+inline Owned<CKioskOwnerCapArray, sui_free_kiosk_owner_cap_array> get_owned_kiosks(Str address)
+{
+    Owned<CKioskOwnerCapArray, sui_free_kiosk_owner_cap_array> result(::get_owned_kiosks(address.c_str()));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CKioskOwnerCapArray, sui_free_kiosk_owner_cap_array>> get_owned_kiosks_async(Str address)
+{
+    return std::async(std::launch::async, get_owned_kiosks, std::move(address));
+}
+
+inline Owned<CMultiSig, sui_free_multisig> get_or_create_multisig(std::vector<std::string> addresses, std::vector<uint8_t> weights, uint16_t threshold)
+{
+    detail::StringArray addresses_array(addresses);
+    Owned<CMultiSig, sui_free_multisig> result(::get_or_create_multisig(addresses_array.get(), detail::bytes(weights), threshold));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CMultiSig, sui_free_multisig>> get_or_create_multisig_async(std::vector<std::string> addresses, std::vector<uint8_t> weights, uint16_t threshold)
+{
+    return std::async(std::launch::async, get_or_create_multisig, std::move(addresses), std::move(weights), std::move(threshold));
+}
+
+inline Owned<CMultiSig, sui_free_multisig> create_multisig_from_public_keys(std::vector<std::string> public_keys, std::vector<std::string> schemes, std::vector<uint8_t> weights, uint16_t threshold)
+{
+    detail::StringArray public_keys_array(public_keys);
+    detail::StringArray schemes_array(schemes);
+    Owned<CMultiSig, sui_free_multisig> result(::create_multisig_from_public_keys(public_keys_array.get(), schemes_array.get(), detail::bytes(weights), threshold));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CMultiSig, sui_free_multisig>> create_multisig_from_public_keys_async(std::vector<std::string> public_keys, std::vector<std::string> schemes, std::vector<uint8_t> weights, uint16_t threshold)
+{
+    return std::async(std::launch::async, create_multisig_from_public_keys, std::move(public_keys), std::move(schemes), std::move(weights), std::move(threshold));
+}
+
+inline std::string zklogin_multisig_member(Str iss, Str address_seed)
+{
+    return detail::take_string(::zklogin_multisig_member(iss.c_str(), address_seed.c_str()));
+}
+
+inline std::future<std::string> zklogin_multisig_member_async(Str iss, Str address_seed)
+{
+    return std::async(std::launch::async, zklogin_multisig_member, std::move(iss), std::move(address_seed));
+}
+
+inline std::string multisig_address_from_bytes(std::vector<uint8_t> multisig)
+{
+    return detail::take_string(::multisig_address_from_bytes(detail::bytes(multisig)));
+}
+
+inline std::future<std::string> multisig_address_from_bytes_async(std::vector<uint8_t> multisig)
+{
+    return std::async(std::launch::async, multisig_address_from_bytes, std::move(multisig));
+}
+
+inline std::string sign_and_execute_transaction_miltisig(std::vector<uint8_t> multisig, std::vector<uint8_t> tx, std::vector<std::string> addresses)
+{
+    detail::StringArray addresses_array(addresses);
+    return detail::take_string(::sign_and_execute_transaction_miltisig(detail::bytes(multisig), detail::bytes(tx), addresses_array.get()));
+}
+
+inline std::future<std::string> sign_and_execute_transaction_miltisig_async(std::vector<uint8_t> multisig, std::vector<uint8_t> tx, std::vector<std::string> addresses)
+{
+    return std::async(std::launch::async, sign_and_execute_transaction_miltisig, std::move(multisig), std::move(tx), std::move(addresses));
+}
+
+inline Handle<CMultiSigSession, sui_free_multisig_session> create_multisig_session(std::vector<uint8_t> multisig, std::vector<uint8_t> tx)
+{
+    auto raw = ::create_multisig_session(detail::bytes(multisig), detail::bytes(tx));
+    Handle<CMultiSigSession, sui_free_multisig_session> result(raw.session);
+    detail::check_status(raw.error);
+    return result;
+}
+
+inline std::future<Handle<CMultiSigSession, sui_free_multisig_session>> create_multisig_session_async(std::vector<uint8_t> multisig, std::vector<uint8_t> tx)
+{
+    return std::async(std::launch::async, create_multisig_session, std::move(multisig), std::move(tx));
+}
+
+inline Handle<CMultiSigSession, sui_free_multisig_session> create_multisig_session_from_public_keys(std::vector<std::string> public_keys, std::vector<uint8_t> weights, uint16_t threshold, std::vector<uint8_t> tx)
+{
+    detail::StringArray public_keys_array(public_keys);
+    auto raw = ::create_multisig_session_from_public_keys(public_keys_array.get(), detail::bytes(weights), threshold, detail::bytes(tx));
+    Handle<CMultiSigSession, sui_free_multisig_session> result(raw.session);
+    detail::check_status(raw.error);
+    return result;
+}
+
+inline std::future<Handle<CMultiSigSession, sui_free_multisig_session>> create_multisig_session_from_public_keys_async(std::vector<std::string> public_keys, std::vector<uint8_t> weights, uint16_t threshold, std::vector<uint8_t> tx)
+{
+    return std::async(std::launch::async, create_multisig_session_from_public_keys, std::move(public_keys), std::move(weights), std::move(threshold), std::move(tx));
+}
+
+inline void multisig_session_add_signature(CMultiSigSession *session, Str signature)
+{
+    detail::check_status(::multisig_session_add_signature(session, signature.c_str()));
+}
+
+inline void multisig_session_sign_with_keystore(CMultiSigSession *session, Str address)
+{
+    detail::check_status(::multisig_session_sign_with_keystore(session, address.c_str()));
+}
+
+inline void multisig_session_sign_with_signer(CMultiSigSession *session, Str address)
+{
+    detail::check_status(::multisig_session_sign_with_signer(session, address.c_str()));
+}
+
+inline std::vector<uint8_t> multisig_session_signing_message(const CMultiSigSession *session)
+{
+    return detail::take_bytes(::multisig_session_signing_message(session));
+}
+
+inline uint16_t multisig_session_bitmap(const CMultiSigSession *session)
+{
+    return ::multisig_session_bitmap(session);
+}
+
+inline uint16_t multisig_session_weight(const CMultiSigSession *session)
+{
+    return ::multisig_session_weight(session);
+}
+
+inline uint16_t multisig_session_threshold(const CMultiSigSession *session)
+{
+    return ::multisig_session_threshold(session);
+}
+
+inline bool multisig_session_is_threshold_met(const CMultiSigSession *session)
+{
+    return ::multisig_session_is_threshold_met(session);
+}
+
+inline std::vector<uint8_t> multisig_session_combine(const CMultiSigSession *session)
+{
+    return detail::take_bytes(::multisig_session_combine(session));
+}
+
+inline std::string multisig_session_execute(const CMultiSigSession *session)
+{
+    return detail::take_string(::multisig_session_execute(session));
+}
+
+inline std::vector<uint8_t> combine_partial_signatures_multisig(std::vector<uint8_t> multisig, std::vector<std::string> signatures)
+{
+    detail::StringArray signatures_array(signatures);
+    return detail::take_bytes(::combine_partial_signatures_multisig(detail::bytes(multisig), signatures_array.get()));
+}
+
+inline std::future<std::vector<uint8_t>> combine_partial_signatures_multisig_async(std::vector<uint8_t> multisig, std::vector<std::string> signatures)
+{
+    return std::async(std::launch::async, combine_partial_signatures_multisig, std::move(multisig), std::move(signatures));
+}
+
+inline Owned<CMultiSigInfo, sui_free_multisig_info> decode_multisig_signature(std::vector<uint8_t> multisig)
+{
+    Owned<CMultiSigInfo, sui_free_multisig_info> result(::decode_multisig_signature(detail::bytes(multisig)));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CMultiSigInfo, sui_free_multisig_info>> decode_multisig_signature_async(std::vector<uint8_t> multisig)
+{
+    return std::async(std::launch::async, decode_multisig_signature, std::move(multisig));
+}
+
+inline Owned<CMultiSigInfo, sui_free_multisig_info> decode_multisig_signature_base64(Str multisig)
+{
+    Owned<CMultiSigInfo, sui_free_multisig_info> result(::decode_multisig_signature_base64(multisig.c_str()));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CMultiSigInfo, sui_free_multisig_info>> decode_multisig_signature_base64_async(Str multisig)
+{
+    return std::async(std::launch::async, decode_multisig_signature_base64, std::move(multisig));
+}
+
+inline void verify_multisig_signature(std::vector<uint8_t> multisig, std::vector<uint8_t> tx)
+{
+    detail::check_status(::verify_multisig_signature(detail::bytes(multisig), detail::bytes(tx)));
+}
+
+inline std::string resolve_sui_name(Str name)
+{
+    return detail::take_string(::resolve_sui_name(name.c_str()));
+}
+
+inline std::future<std::string> resolve_sui_name_async(Str name)
+{
+    return std::async(std::launch::async, resolve_sui_name, std::move(name));
+}
+
+inline std::vector<std::string> lookup_sui_names(Str address)
+{
+    return detail::take_strings(::lookup_sui_names(address.c_str()));
+}
+
+inline std::future<std::vector<std::string>> lookup_sui_names_async(Str address)
+{
+    return std::async(std::launch::async, lookup_sui_names, std::move(address));
+}
+
+inline std::string mint_nft(Str package_id, Str sender_address, Str name, Str description, Str uri)
+{
+    return detail::take_string(::mint_nft(package_id.c_str(), sender_address.c_str(), name.c_str(), description.c_str(), uri.c_str()));
+}
+
+inline std::future<std::string> mint_nft_async(Str package_id, Str sender_address, Str name, Str description, Str uri)
+{
+    return std::async(std::launch::async, mint_nft, std::move(package_id), std::move(sender_address), std::move(name), std::move(description), std::move(uri));
+}
+
+inline std::string transfer_nft(Str package_id, Str sender_address, Str nft_id, Str recipient_address)
+{
+    return detail::take_string(::transfer_nft(package_id.c_str(), sender_address.c_str(), nft_id.c_str(), recipient_address.c_str()));
+}
+
+inline std::future<std::string> transfer_nft_async(Str package_id, Str sender_address, Str nft_id, Str recipient_address)
+{
+    return std::async(std::launch::async, transfer_nft, std::move(package_id), std::move(sender_address), std::move(nft_id), std::move(recipient_address));
+}
+
+inline Owned<CNftPage, sui_free_nft_page> get_wallet_nfts(Str address, Str cursor, uintptr_t limit)
+{
+    Owned<CNftPage, sui_free_nft_page> result(::get_wallet_nfts(address.c_str(), cursor.c_str(), limit));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CNftPage, sui_free_nft_page>> get_wallet_nfts_async(Str address, Str cursor, uintptr_t limit)
+{
+    return std::async(std::launch::async, get_wallet_nfts, std::move(address), std::move(cursor), std::move(limit));
+}
+
+inline Owned<CNftCollection, sui_free_nft_collection> get_nft_collection(Str object_type)
+{
+    Owned<CNftCollection, sui_free_nft_collection> result(::get_nft_collection(object_type.c_str()));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CNftCollection, sui_free_nft_collection>> get_nft_collection_async(Str object_type)
+{
+    return std::async(std::launch::async, get_nft_collection, std::move(object_type));
+}
+
+inline std::string resolve_nft_image_url(Str url, Str ipfs_gateway)
+{
+    return detail::take_string(::resolve_nft_image_url(url.c_str(), ipfs_gateway.c_str()));
+}
+
+inline std::future<std::string> resolve_nft_image_url_async(Str url, Str ipfs_gateway)
+{
+    return std::async(std::launch::async, resolve_nft_image_url, std::move(url), std::move(ipfs_gateway));
+}
+
+inline std::string transfer_object(Str sender_address, Str object_id, Str recipient_address, uint64_t gas_budget)
+{
+    return detail::take_string(::transfer_object(sender_address.c_str(), object_id.c_str(), recipient_address.c_str(), gas_budget));
+}
+
+inline std::future<std::string> transfer_object_async(Str sender_address, Str object_id, Str recipient_address, uint64_t gas_budget)
+{
+    return std::async(std::launch::async, transfer_object, std::move(sender_address), std::move(object_id), std::move(recipient_address), std::move(gas_budget));
+}
+
+inline std::string transfer_objects(Str sender_address, std::vector<std::string> object_ids, Str recipient_address, uint64_t gas_budget)
+{
+    detail::StringArray object_ids_array(object_ids);
+    return detail::take_string(::transfer_objects(sender_address.c_str(), object_ids_array.get(), recipient_address.c_str(), gas_budget));
+}
+
+inline std::future<std::string> transfer_objects_async(Str sender_address, std::vector<std::string> object_ids, Str recipient_address, uint64_t gas_budget)
+{
+    return std::async(std::launch::async, transfer_objects, std::move(sender_address), std::move(object_ids), std::move(recipient_address), std::move(gas_budget));
+}
+
+inline std::string mint_nft_with_call(Str sender_address, Str package_id, Str module, Str function, std::vector<std::string> type_arguments, std::vector<std::string> argument_types, std::vector<std::string> argument_values, uint64_t gas_budget)
+{
+    detail::StringArray type_arguments_array(type_arguments);
+    detail::StringArray argument_types_array(argument_types);
+    detail::StringArray argument_values_array(argument_values);
+    return detail::take_string(::mint_nft_with_call(sender_address.c_str(), package_id.c_str(), module.c_str(), function.c_str(), type_arguments_array.get(), argument_types_array.get(), argument_values_array.get(), gas_budget));
+}
+
+inline std::future<std::string> mint_nft_with_call_async(Str sender_address, Str package_id, Str module, Str function, std::vector<std::string> type_arguments, std::vector<std::string> argument_types, std::vector<std::string> argument_values, uint64_t gas_budget)
+{
+    return std::async(std::launch::async, mint_nft_with_call, std::move(sender_address), std::move(package_id), std::move(module), std::move(function), std::move(type_arguments), std::move(argument_types), std::move(argument_values), std::move(gas_budget));
+}
+
+inline Owned<CValidatorArray, sui_free_validator_array> get_validators()
+{
+    Owned<CValidatorArray, sui_free_validator_array> result(::get_validators());
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CValidatorArray, sui_free_validator_array>> get_validators_async()
+{
+    return std::async(std::launch::async, get_validators);
+}
+
+inline Owned<CStakedSuiArray, sui_free_staked_sui_array> get_stakes(Str address)
+{
+    Owned<CStakedSuiArray, sui_free_staked_sui_array> result(::get_stakes(address.c_str()));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CStakedSuiArray, sui_free_staked_sui_array>> get_stakes_async(Str address)
+{
+    return std::async(std::launch::async, get_stakes, std::move(address));
+}
+
+inline std::string stake_sui(Str sender, Str validator_address, uint64_t amount, uint64_t gas_budget)
+{
+    return detail::take_string(::stake_sui(sender.c_str(), validator_address.c_str(), amount, gas_budget));
+}
+
+inline std::future<std::string> stake_sui_async(Str sender, Str validator_address, uint64_t amount, uint64_t gas_budget)
+{
+    return std::async(std::launch::async, stake_sui, std::move(sender), std::move(validator_address), std::move(amount), std::move(gas_budget));
+}
+
+inline std::string withdraw_stake(Str sender, Str staked_sui_id, uint64_t gas_budget)
+{
+    return detail::take_string(::withdraw_stake(sender.c_str(), staked_sui_id.c_str(), gas_budget));
+}
+
+inline std::future<std::string> withdraw_stake_async(Str sender, Str staked_sui_id, uint64_t gas_budget)
+{
+    return std::async(std::launch::async, withdraw_stake, std::move(sender), std::move(staked_sui_id), std::move(gas_budget));
+}
+
+inline Handle<CObjectFilter, sui_free_object_filter> object_filter_struct_type(Str struct_type)
+{
+    Handle<CObjectFilter, sui_free_object_filter> result(::object_filter_struct_type(struct_type.c_str()));
+    return result;
+}
+
+inline std::future<Handle<CObjectFilter, sui_free_object_filter>> object_filter_struct_type_async(Str struct_type)
+{
+    return std::async(std::launch::async, object_filter_struct_type, std::move(struct_type));
+}
+
+inline Handle<CObjectFilter, sui_free_object_filter> object_filter_package(Str package_id)
+{
+    Handle<CObjectFilter, sui_free_object_filter> result(::object_filter_package(package_id.c_str()));
+    return result;
+}
+
+inline std::future<Handle<CObjectFilter, sui_free_object_filter>> object_filter_package_async(Str package_id)
+{
+    return std::async(std::launch::async, object_filter_package, std::move(package_id));
+}
+
+inline Handle<CObjectFilter, sui_free_object_filter> object_filter_move_module(Str package_id, Str module)
+{
+    Handle<CObjectFilter, sui_free_object_filter> result(::object_filter_move_module(package_id.c_str(), module.c_str()));
+    return result;
+}
+
+inline std::future<Handle<CObjectFilter, sui_free_object_filter>> object_filter_move_module_async(Str package_id, Str module)
+{
+    return std::async(std::launch::async, object_filter_move_module, std::move(package_id), std::move(module));
+}
+
+inline Handle<CObjectFilter, sui_free_object_filter> object_filter_match_all(CObjectFilter * const *filters, uintptr_t len)
+{
+    Handle<CObjectFilter, sui_free_object_filter> result(::object_filter_match_all(filters, len));
+    return result;
+}
+
+inline Handle<CObjectFilter, sui_free_object_filter> object_filter_match_any(CObjectFilter * const *filters, uintptr_t len)
+{
+    Handle<CObjectFilter, sui_free_object_filter> result(::object_filter_match_any(filters, len));
+    return result;
+}
+
+inline Handle<CObjectFilter, sui_free_object_filter> object_filter_match_none(CObjectFilter * const *filters, uintptr_t len)
+{
+    Handle<CObjectFilter, sui_free_object_filter> result(::object_filter_match_none(filters, len));
+    return result;
+}
+
+inline Owned<CSuiObjectPage, sui_free_object_page> get_owned_objects(Str address, const CObjectFilter *filter, CSuiObjectDataOptions options, Str cursor, uintptr_t limit)
+{
+    Owned<CSuiObjectPage, sui_free_object_page> result(::get_owned_objects(address.c_str(), filter, options, cursor.c_str(), limit));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline CSuiObjectDataOptions sui_object_data_options_full()
+{
+    return ::sui_object_data_options_full();
+}
+
+inline std::future<CSuiObjectDataOptions> sui_object_data_options_full_async()
+{
+    return std::async(std::launch::async, sui_object_data_options_full);
+}
+
+inline Owned<CSuiObjectResult, sui_free_object_result> get_object(Str object_id, CSuiObjectDataOptions options)
+{
+    Owned<CSuiObjectResult, sui_free_object_result> result(::get_object(object_id.c_str(), options));
+    detail::check(result->error, result->status);
+    return result;
+}
+
+inline std::future<Owned<CSuiObjectResult, sui_free_object_result>> get_object_async(Str object_id, CSuiObjectDataOptions options)
+{
+    return std::async(std::launch::async, get_object, std::move(object_id), std::move(options));
+}
+
+inline Owned<CSuiObjectResultArray, sui_free_object_result_array> multi_get_objects(std::vector<std::string> object_ids, CSuiObjectDataOptions options)
+{
+    detail::StringArray object_ids_array(object_ids);
+    Owned<CSuiObjectResultArray, sui_free_object_result_array> result(::multi_get_objects(object_ids_array.get(), options));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CSuiObjectResultArray, sui_free_object_result_array>> multi_get_objects_async(std::vector<std::string> object_ids, CSuiObjectDataOptions options)
+{
+    return std::async(std::launch::async, multi_get_objects, std::move(object_ids), std::move(options));
+}
+
+inline Owned<CSuiObjectResult, sui_free_object_result> get_past_object(Str object_id, uint64_t version, CSuiObjectDataOptions options)
+{
+    Owned<CSuiObjectResult, sui_free_object_result> result(::get_past_object(object_id.c_str(), version, options));
+    detail::check(result->error, result->status);
+    return result;
+}
+
+inline std::future<Owned<CSuiObjectResult, sui_free_object_result>> get_past_object_async(Str object_id, uint64_t version, CSuiObjectDataOptions options)
+{
+    return std::async(std::launch::async, get_past_object, std::move(object_id), std::move(version), std::move(options));
+}
+
+inline void register_signer_callback(SignCallback callback, void *user_data)
+{
+    ::register_signer_callback(callback, user_data);
+}
+
+inline void register_local_signer(std::vector<std::string> private_keys)
+{
+    detail::StringArray private_keys_array(private_keys);
+    detail::check_status(::register_local_signer(private_keys_array.get()));
+}
+
+inline void reset_signer()
+{
+    ::reset_signer();
+}
+
+inline int32_t test()
+{
+    return ::test();
+}
+
+inline std::future<int32_t> test_async()
+{
+    return std::async(std::launch::async, test);
+}
+
+inline int32_t build_mainnet()
+{
+    return ::build_mainnet();
+}
+
+inline std::future<int32_t> build_mainnet_async()
+{
+    return std::async(std::launch::async, build_mainnet);
+}
+
+inline int32_t build_testnet()
+{
+    return ::build_testnet();
+}
+
+inline std::future<int32_t> build_testnet_async()
+{
+    return std::async(std::launch::async, build_testnet);
+}
+
+inline int32_t build_devnet()
+{
+    return ::build_devnet();
+}
+
+inline std::future<int32_t> build_devnet_async()
+{
+    return std::async(std::launch::async, build_devnet);
+}
+
+inline int32_t build_custom(Str rpc_url)
+{
+    return ::build_custom(rpc_url.c_str());
+}
+
+inline std::future<int32_t> build_custom_async(Str rpc_url)
+{
+    return std::async(std::launch::async, build_custom, std::move(rpc_url));
+}
+
+inline std::vector<std::string> available_rpc_methods()
+{
+    return detail::take_strings(::available_rpc_methods());
+}
+
+inline std::future<std::vector<std::string>> available_rpc_methods_async()
+{
+    return std::async(std::launch::async, available_rpc_methods);
+}
+
+inline std::vector<std::string> available_subscriptions()
+{
+    return detail::take_strings(::available_subscriptions());
+}
+
+inline std::future<std::vector<std::string>> available_subscriptions_async()
+{
+    return std::async(std::launch::async, available_subscriptions);
+}
+
+inline int32_t check_api_version()
+{
+    return ::check_api_version();
+}
+
+inline std::future<int32_t> check_api_version_async()
+{
+    return std::async(std::launch::async, check_api_version);
+}
+
+inline std::string api_version()
+{
+    return detail::take_string(::api_version());
+}
+
+inline std::future<std::string> api_version_async()
+{
+    return std::async(std::launch::async, api_version);
+}
+
+inline int32_t connect_localnet_c()
+{
+    return ::connect_localnet_c();
+}
+
+inline std::future<int32_t> connect_localnet_c_async()
+{
+    return std::async(std::launch::async, connect_localnet_c);
+}
+
+inline int32_t connect_devnet_c()
+{
+    return ::connect_devnet_c();
+}
+
+inline std::future<int32_t> connect_devnet_c_async()
+{
+    return std::async(std::launch::async, connect_devnet_c);
+}
+
+inline int32_t connect_testnet_c()
+{
+    return ::connect_testnet_c();
+}
+
+inline std::future<int32_t> connect_testnet_c_async()
+{
+    return std::async(std::launch::async, connect_testnet_c);
+}
+
+inline std::vector<uint8_t> create_transaction(Str from_address, Str to_address, uint64_t amount)
+{
+    return detail::take_bytes(::create_transaction(from_address.c_str(), to_address.c_str(), amount));
+}
+
+inline std::future<std::vector<uint8_t>> create_transaction_async(Str from_address, Str to_address, uint64_t amount)
+{
+    return std::async(std::launch::async, create_transaction, std::move(from_address), std::move(to_address), std::move(amount));
+}
+
+inline std::string programmable_transaction(Str sender_address, Str recipient_address, uint64_t amount)
+{
+    return detail::take_string(::programmable_transaction(sender_address.c_str(), recipient_address.c_str(), amount));
+}
+
+inline std::future<std::string> programmable_transaction_async(Str sender_address, Str recipient_address, uint64_t amount)
+{
+    return std::async(std::launch::async, programmable_transaction, std::move(sender_address), std::move(recipient_address), std::move(amount));
+}
+
+inline std::string programmable_transaction_allow_sponser(Str sender_address, Str recipient_address, uint64_t amount, Str sponser_address)
+{
+    return detail::take_string(::programmable_transaction_allow_sponser(sender_address.c_str(), recipient_address.c_str(), amount, sponser_address.c_str()));
+}
+
+inline std::future<std::string> programmable_transaction_allow_sponser_async(Str sender_address, Str recipient_address, uint64_t amount, Str sponser_address)
+{
+    return std::async(std::launch::async, programmable_transaction_allow_sponser, std::move(sender_address), std::move(recipient_address), std::move(amount), std::move(sponser_address));
+}
+
+inline std::string request_tokens_from_faucet(Str address_str)
+{
+    return detail::take_string(::request_tokens_from_faucet(address_str.c_str()));
+}
+
+inline std::future<std::string> request_tokens_from_faucet_async(Str address_str)
+{
+    return std::async(std::launch::async, request_tokens_from_faucet, std::move(address_str));
+}
+
+inline Owned<WalletList, sui_free_wallet_list> get_wallets()
+{
+    Owned<WalletList, sui_free_wallet_list> result(::get_wallets());
+    return result;
+}
+
+inline std::future<Owned<WalletList, sui_free_wallet_list>> get_wallets_async()
+{
+    return std::async(std::launch::async, get_wallets);
+}
+
+inline Handle<Wallet, sui_free_wallet> generate_wallet(Str key_scheme, Str word_length)
+{
+    Handle<Wallet, sui_free_wallet> result(::generate_wallet(key_scheme.c_str(), word_length.c_str()));
+    return result;
+}
+
+inline std::future<Handle<Wallet, sui_free_wallet>> generate_wallet_async(Str key_scheme, Str word_length)
+{
+    return std::async(std::launch::async, generate_wallet, std::move(key_scheme), std::move(word_length));
+}
+
+inline Handle<Wallet, sui_free_wallet> generate_and_add_key()
+{
+    Handle<Wallet, sui_free_wallet> result(::generate_and_add_key());
+    return result;
+}
+
+inline std::future<Handle<Wallet, sui_free_wallet>> generate_and_add_key_async()
+{
+    return std::async(std::launch::async, generate_and_add_key);
+}
+
+inline Handle<ImportResult, sui_free_import_result> import_from_mnemonic(Str mnemonic, Str sig_scheme, Str alias)
+{
+    Handle<ImportResult, sui_free_import_result> result(::import_from_mnemonic(mnemonic.c_str(), sig_scheme.c_str(), alias.c_str()));
+    if (result)
+    {
+        detail::check(result->error, result->status);
+    }
+    return result;
+}
+
+inline std::future<Handle<ImportResult, sui_free_import_result>> import_from_mnemonic_async(Str mnemonic, Str sig_scheme, Str alias)
+{
+    return std::async(std::launch::async, import_from_mnemonic, std::move(mnemonic), std::move(sig_scheme), std::move(alias));
+}
+
+inline Handle<ImportResult, sui_free_import_result> import_from_private_key(Str key_base64)
+{
+    Handle<ImportResult, sui_free_import_result> result(::import_from_private_key(key_base64.c_str()));
+    if (result)
+    {
+        detail::check(result->error, result->status);
+    }
+    return result;
+}
+
+inline std::future<Handle<ImportResult, sui_free_import_result>> import_from_private_key_async(Str key_base64)
+{
+    return std::async(std::launch::async, import_from_private_key, std::move(key_base64));
+}
+
+inline Handle<Wallet, sui_free_wallet> get_wallet_with_private_key(Str address)
+{
+    Handle<Wallet, sui_free_wallet> result(::get_wallet_with_private_key(address.c_str()));
+    return result;
+}
+
+inline std::future<Handle<Wallet, sui_free_wallet>> get_wallet_with_private_key_async(Str address)
+{
+    return std::async(std::launch::async, get_wallet_with_private_key, std::move(address));
+}
+
+inline Handle<ImportResult, sui_free_import_result> import_from_private_key_with_scheme(Str private_key, Str key_scheme)
+{
+    Handle<ImportResult, sui_free_import_result> result(::import_from_private_key_with_scheme(private_key.c_str(), key_scheme.c_str()));
+    if (result)
+    {
+        detail::check(result->error, result->status);
+    }
+    return result;
+}
+
+inline std::future<Handle<ImportResult, sui_free_import_result>> import_from_private_key_with_scheme_async(Str private_key, Str key_scheme)
+{
+    return std::async(std::launch::async, import_from_private_key_with_scheme, std::move(private_key), std::move(key_scheme));
+}
+
+inline Handle<KeyResult, sui_free_key_result> export_private_key(Str address, Str format)
+{
+    Handle<KeyResult, sui_free_key_result> result(::export_private_key(address.c_str(), format.c_str()));
+    if (result)
+    {
+        detail::check(result->error, result->status);
+    }
+    return result;
+}
+
+inline std::future<Handle<KeyResult, sui_free_key_result>> export_private_key_async(Str address, Str format)
+{
+    return std::async(std::launch::async, export_private_key, std::move(address), std::move(format));
+}
+
+inline Handle<KeyResult, sui_free_key_result> convert_private_key(Str private_key, Str key_scheme, Str format)
+{
+    Handle<KeyResult, sui_free_key_result> result(::convert_private_key(private_key.c_str(), key_scheme.c_str(), format.c_str()));
+    if (result)
+    {
+        detail::check(result->error, result->status);
+    }
+    return result;
+}
+
+inline std::future<Handle<KeyResult, sui_free_key_result>> convert_private_key_async(Str private_key, Str key_scheme, Str format)
+{
+    return std::async(std::launch::async, convert_private_key, std::move(private_key), std::move(key_scheme), std::move(format));
+}
+
+inline Handle<Wallet, sui_free_wallet> get_wallet_from_address(Str address)
+{
+    Handle<Wallet, sui_free_wallet> result(::get_wallet_from_address(address.c_str()));
+    return result;
+}
+
+inline std::future<Handle<Wallet, sui_free_wallet>> get_wallet_from_address_async(Str address)
+{
+    return std::async(std::launch::async, get_wallet_from_address, std::move(address));
+}
+
+inline Owned<DerivedAccountList, sui_free_derived_account_list> derive_accounts_from_mnemonic(Str mnemonic, Str key_scheme, uint32_t start_index, uint32_t count)
+{
+    Owned<DerivedAccountList, sui_free_derived_account_list> result(::derive_accounts_from_mnemonic(mnemonic.c_str(), key_scheme.c_str(), start_index, count));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<DerivedAccountList, sui_free_derived_account_list>> derive_accounts_from_mnemonic_async(Str mnemonic, Str key_scheme, uint32_t start_index, uint32_t count)
+{
+    return std::async(std::launch::async, derive_accounts_from_mnemonic, std::move(mnemonic), std::move(key_scheme), std::move(start_index), std::move(count));
+}
+
+inline Owned<DerivedAccountList, sui_free_derived_account_list> derive_accounts_from_paths(Str mnemonic, Str key_scheme, std::vector<std::string> derivation_paths)
+{
+    detail::StringArray derivation_paths_array(derivation_paths);
+    Owned<DerivedAccountList, sui_free_derived_account_list> result(::derive_accounts_from_paths(mnemonic.c_str(), key_scheme.c_str(), derivation_paths_array.get()));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<DerivedAccountList, sui_free_derived_account_list>> derive_accounts_from_paths_async(Str mnemonic, Str key_scheme, std::vector<std::string> derivation_paths)
+{
+    return std::async(std::launch::async, derive_accounts_from_paths, std::move(mnemonic), std::move(key_scheme), std::move(derivation_paths));
+}
+
+inline Handle<ImportResult, sui_free_import_result> import_from_mnemonic_with_path(Str mnemonic, Str key_scheme, Str derivation_path, Str alias)
+{
+    Handle<ImportResult, sui_free_import_result> result(::import_from_mnemonic_with_path(mnemonic.c_str(), key_scheme.c_str(), derivation_path.c_str(), alias.c_str()));
+    if (result)
+    {
+        detail::check(result->error, result->status);
+    }
+    return result;
+}
+
+inline std::future<Handle<ImportResult, sui_free_import_result>> import_from_mnemonic_with_path_async(Str mnemonic, Str key_scheme, Str derivation_path, Str alias)
+{
+    return std::async(std::launch::async, import_from_mnemonic_with_path, std::move(mnemonic), std::move(key_scheme), std::move(derivation_path), std::move(alias));
+}
+
+inline Owned<DerivedAccountList, sui_free_derived_account_list> import_accounts_from_mnemonic(Str mnemonic, Str key_scheme, const uint32_t *account_indexes, uintptr_t account_indexes_len)
+{
+    Owned<DerivedAccountList, sui_free_derived_account_list> result(::import_accounts_from_mnemonic(mnemonic.c_str(), key_scheme.c_str(), account_indexes, account_indexes_len));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline Owned<CAddressResult, sui_free_address_result> get_address_by_alias(Str alias)
+{
+    Owned<CAddressResult, sui_free_address_result> result(::get_address_by_alias(alias.c_str()));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CAddressResult, sui_free_address_result>> get_address_by_alias_async(Str alias)
+{
+    return std::async(std::launch::async, get_address_by_alias, std::move(alias));
+}
+
+inline Owned<CAddressResult, sui_free_address_result> rename_alias(Str old_alias, Str new_alias)
+{
+    Owned<CAddressResult, sui_free_address_result> result(::rename_alias(old_alias.c_str(), new_alias.c_str()));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CAddressResult, sui_free_address_result>> rename_alias_async(Str old_alias, Str new_alias)
+{
+    return std::async(std::launch::async, rename_alias, std::move(old_alias), std::move(new_alias));
+}
+
+inline Owned<CAddressResult, sui_free_address_result> remove_key(Str address_or_alias)
+{
+    Owned<CAddressResult, sui_free_address_result> result(::remove_key(address_or_alias.c_str()));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CAddressResult, sui_free_address_result>> remove_key_async(Str address_or_alias)
+{
+    return std::async(std::launch::async, remove_key, std::move(address_or_alias));
+}
+
+inline Owned<CAddressResult, sui_free_address_result> get_active_address()
+{
+    Owned<CAddressResult, sui_free_address_result> result(::get_active_address());
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CAddressResult, sui_free_address_result>> get_active_address_async()
+{
+    return std::async(std::launch::async, get_active_address);
+}
+
+inline Owned<CAddressResult, sui_free_address_result> set_active_address(Str address_or_alias)
+{
+    Owned<CAddressResult, sui_free_address_result> result(::set_active_address(address_or_alias.c_str()));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CAddressResult, sui_free_address_result>> set_active_address_async(Str address_or_alias)
+{
+    return std::async(std::launch::async, set_active_address, std::move(address_or_alias));
+}
+
+inline Owned<CSuiObjectDataArray, sui_free_object_data_array> get_wallet_objects(Str address, Str object_type)
+{
+    Owned<CSuiObjectDataArray, sui_free_object_data_array> result(::get_wallet_objects(address.c_str(), object_type.c_str()));
+    return result;
+}
+
+inline std::future<Owned<CSuiObjectDataArray, sui_free_object_data_array>> get_wallet_objects_async(Str address, Str object_type)
+{
+    return std::async(std::launch::async, get_wallet_objects, std::move(address), std::move(object_type));
+}
+
+inline Handle<CTypeTags, sui_free_type_tags> create_type_tags()
+{
+    Handle<CTypeTags, sui_free_type_tags> result(::create_type_tags());
+    return result;
+}
+
+inline std::future<Handle<CTypeTags, sui_free_type_tags>> create_type_tags_async()
+{
+    return std::async(std::launch::async, create_type_tags);
+}
+
+inline void add_type_tag(CTypeTags *type_tags, Str tag)
+{
+    ::add_type_tag(type_tags, tag.c_str());
+}
+
+inline Handle<CArguments, sui_free_arguments> create_arguments()
+{
+    Handle<CArguments, sui_free_arguments> result(::create_arguments());
+    return result;
+}
+
+inline std::future<Handle<CArguments, sui_free_arguments>> create_arguments_async()
+{
+    return std::async(std::launch::async, create_arguments);
+}
+
+inline void add_argument_gas_coin(CArguments *arguments)
+{
+    ::add_argument_gas_coin(arguments);
+}
+
+inline void add_argument_result(CArguments *arguments, uint16_t value)
+{
+    ::add_argument_result(arguments, value);
+}
+
+inline void add_argument_input(CArguments *arguments, uint16_t value)
+{
+    ::add_argument_input(arguments, value);
+}
+
+inline void add_argument_nested_result(CArguments *arguments, uint16_t value1, uint16_t value2)
+{
+    ::add_argument_nested_result(arguments, value1, value2);
+}
+
+inline void make_pure(CProgrammableTransactionBuilder *builder, CArguments *arguments, CPure *value)
+{
+    ::make_pure(builder, arguments, value);
+}
+
+inline void make_object(CProgrammableTransactionBuilder *builder, CArguments *arguments, Str object_id, bool is_mutable)
+{
+    detail::check_status(::make_object(builder, arguments, object_id.c_str(), is_mutable));
+}
+
+inline Handle<CProgrammableTransactionBuilder, sui_free_builder> create_builder()
+{
+    Handle<CProgrammableTransactionBuilder, sui_free_builder> result(::create_builder());
+    return result;
+}
+
+inline std::future<Handle<CProgrammableTransactionBuilder, sui_free_builder>> create_builder_async()
+{
+    return std::async(std::launch::async, create_builder);
+}
+
+inline void add_move_call_command(CProgrammableTransactionBuilder *builder, Str package, Str module, Str function, CTypeTags *type_arguments, CArguments *arguments)
+{
+    ::add_move_call_command(builder, package.c_str(), module.c_str(), function.c_str(), type_arguments, arguments);
+}
+
+inline void add_transfer_object_command(CProgrammableTransactionBuilder *builder, CArguments *agreements, CArguments *recipient)
+{
+    ::add_transfer_object_command(builder, agreements, recipient);
+}
+
+inline void add_split_coins_command(CProgrammableTransactionBuilder *builder, CArguments *coin, CArguments *agreements)
+{
+    ::add_split_coins_command(builder, coin, agreements);
+}
+
+inline void add_merge_coins_command(CProgrammableTransactionBuilder *builder, CArguments *coin, CArguments *agreements)
+{
+    ::add_merge_coins_command(builder, coin, agreements);
+}
+
+inline std::string execute_transaction(CProgrammableTransactionBuilder *builder, Str sender, unsigned long long gas_budget)
+{
+    return detail::take_string(::execute_transaction(builder, sender.c_str(), gas_budget));
+}
+
+inline std::vector<uint8_t> build_transaction_data(CProgrammableTransactionBuilder *builder, Str sender, unsigned long long gas_budget)
+{
+    return detail::take_bytes(::build_transaction_data(builder, sender.c_str(), gas_budget));
+}
+
+inline std::string execute_transaction_allow_sponser(CProgrammableTransactionBuilder *builder, Str sender, unsigned long long gas_budget, Str sponser)
+{
+    return detail::take_string(::execute_transaction_allow_sponser(builder, sender.c_str(), gas_budget, sponser.c_str()));
+}
+
+inline Owned<CZkLoginEphemeral, sui_free_zklogin_ephemeral> zklogin_generate_ephemeral(uint64_t max_epoch, Str randomness)
+{
+    Owned<CZkLoginEphemeral, sui_free_zklogin_ephemeral> result(::zklogin_generate_ephemeral(max_epoch, randomness.c_str()));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CZkLoginEphemeral, sui_free_zklogin_ephemeral>> zklogin_generate_ephemeral_async(uint64_t max_epoch, Str randomness)
+{
+    return std::async(std::launch::async, zklogin_generate_ephemeral, std::move(max_epoch), std::move(randomness));
+}
+
+inline Owned<CZkLoginAddress, sui_free_zklogin_address> zklogin_address_from_jwt(Str jwt, Str salt)
+{
+    Owned<CZkLoginAddress, sui_free_zklogin_address> result(::zklogin_address_from_jwt(jwt.c_str(), salt.c_str()));
+    detail::check(result->error, -1);
+    return result;
+}
+
+inline std::future<Owned<CZkLoginAddress, sui_free_zklogin_address>> zklogin_address_from_jwt_async(Str jwt, Str salt)
+{
+    return std::async(std::launch::async, zklogin_address_from_jwt, std::move(jwt), std::move(salt));
+}
+
+inline std::string zklogin_sign_with_ephemeral(Str ephemeral_private_key, std::vector<uint8_t> tx)
+{
+    return detail::take_string(::zklogin_sign_with_ephemeral(ephemeral_private_key.c_str(), detail::bytes(tx)));
+}
+
+inline std::future<std::string> zklogin_sign_with_ephemeral_async(Str ephemeral_private_key, std::vector<uint8_t> tx)
+{
+    return std::async(std::launch::async, zklogin_sign_with_ephemeral, std::move(ephemeral_private_key), std::move(tx));
+}
+
+inline std::vector<uint8_t> zklogin_assemble_signature(Str proof_json, Str address_seed, uint64_t max_epoch, Str ephemeral_signature)
+{
+    return detail::take_bytes(::zklogin_assemble_signature(proof_json.c_str(), address_seed.c_str(), max_epoch, ephemeral_signature.c_str()));
+}
+
+inline std::future<std::vector<uint8_t>> zklogin_assemble_signature_async(Str proof_json, Str address_seed, uint64_t max_epoch, Str ephemeral_signature)
+{
+    return std::async(std::launch::async, zklogin_assemble_signature, std::move(proof_json), std::move(address_seed), std::move(max_epoch), std::move(ephemeral_signature));
+}
+
+inline std::string zklogin_execute_transaction(std::vector<uint8_t> tx, std::vector<uint8_t> signature)
+{
+    return detail::take_string(::zklogin_execute_transaction(detail::bytes(tx), detail::bytes(signature)));
+}
+
+inline std::future<std::string> zklogin_execute_transaction_async(std::vector<uint8_t> tx, std::vector<uint8_t> signature)
+{
+    return std::async(std::launch::async, zklogin_execute_transaction, std::move(tx), std::move(signature));
+}
+
+inline uint32_t sui_sdk_abi_version()
+{
+    return ::sui_sdk_abi_version();
+}
+
+inline std::future<uint32_t> sui_sdk_abi_version_async()
+{
+    return std::async(std::launch::async, sui_sdk_abi_version);
+}
+
+inline Handle<SuiObject, sui_free_object_v1> sui_object_fetch_v1(Str object_id)
+{
+    char *error = nullptr;
+    auto raw = ::sui_object_fetch_v1(object_id.c_str(), &error);
+    detail::check_status(error);
+    Handle<SuiObject, sui_free_object_v1> result(raw);
+    return result;
+}
+
+inline std::future<Handle<SuiObject, sui_free_object_v1>> sui_object_fetch_v1_async(Str object_id)
+{
+    return std::async(std::launch::async, sui_object_fetch_v1, std::move(object_id));
+}
+
+inline Handle<SuiObjectList, sui_free_object_list_v1> sui_owned_objects_fetch_v1(Str address, Str object_type)
+{
+    char *error = nullptr;
+    auto raw = ::sui_owned_objects_fetch_v1(address.c_str(), object_type.c_str(), &error);
+    detail::check_status(error);
+    Handle<SuiObjectList, sui_free_object_list_v1> result(raw);
+    return result;
+}
+
+inline std::future<Handle<SuiObjectList, sui_free_object_list_v1>> sui_owned_objects_fetch_v1_async(Str address, Str object_type)
+{
+    return std::async(std::launch::async, sui_owned_objects_fetch_v1, std::move(address), std::move(object_type));
+}
+
+inline uintptr_t sui_object_list_len_v1(const SuiObjectList *list)
+{
+    return ::sui_object_list_len_v1(list);
+}
+
+inline const SuiObject * sui_object_list_at_v1(const SuiObjectList *list, uintptr_t index)
+{
+    return ::sui_object_list_at_v1(list, index);
+}
+
+inline std::optional<std::string> sui_object_get_id_v1(const SuiObject *object)
+{
+    return detail::borrowed(::sui_object_get_id_v1(object));
+}
+
+inline uint64_t sui_object_get_version_v1(const SuiObject *object)
+{
+    return ::sui_object_get_version_v1(object);
+}
+
+inline std::optional<std::string> sui_object_get_digest_v1(const SuiObject *object)
+{
+    return detail::borrowed(::sui_object_get_digest_v1(object));
+}
+
+inline std::optional<std::string> sui_object_get_type_v1(const SuiObject *object)
+{
+    return detail::borrowed(::sui_object_get_type_v1(object));
+}
+
+inline std::optional<std::string> sui_object_get_owner_v1(const SuiObject *object)
+{
+    return detail::borrowed(::sui_object_get_owner_v1(object));
+}
+
+inline std::optional<std::string> sui_object_get_previous_transaction_v1(const SuiObject *object)
+{
+    return detail::borrowed(::sui_object_get_previous_transaction_v1(object));
+}
+
+inline uint64_t sui_object_get_storage_rebate_v1(const SuiObject *object)
+{
+    return ::sui_object_get_storage_rebate_v1(object);
+}
+
+inline std::optional<std::string> sui_object_get_display_json_v1(const SuiObject *object)
+{
+    return detail::borrowed(::sui_object_get_display_json_v1(object));
+}
+
+inline std::optional<std::string> sui_object_get_content_json_v1(const SuiObject *object)
+{
+    return detail::borrowed(::sui_object_get_content_json_v1(object));
+}
+
+inline std::optional<std::string> sui_object_get_bcs_json_v1(const SuiObject *object)
+{
+    return detail::borrowed(::sui_object_get_bcs_json_v1(object));
+}
+
+inline Handle<SuiCoinList, sui_free_coin_list_v1> sui_coins_fetch_v1(Str address)
+{
+    char *error = nullptr;
+    auto raw = ::sui_coins_fetch_v1(address.c_str(), &error);
+    detail::check_status(error);
+    Handle<SuiCoinList, sui_free_coin_list_v1> result(raw);
+    return result;
+}
+
+inline std::future<Handle<SuiCoinList, sui_free_coin_list_v1>> sui_coins_fetch_v1_async(Str address)
+{
+    return std::async(std::launch::async, sui_coins_fetch_v1, std::move(address));
+}
+
+inline uintptr_t sui_coin_list_len_v1(const SuiCoinList *list)
+{
+    return ::sui_coin_list_len_v1(list);
+}
+
+inline const SuiCoin * sui_coin_list_at_v1(const SuiCoinList *list, uintptr_t index)
+{
+    return ::sui_coin_list_at_v1(list, index);
+}
+
+inline std::optional<std::string> sui_coin_get_type_v1(const SuiCoin *coin)
+{
+    return detail::borrowed(::sui_coin_get_type_v1(coin));
+}
+
+inline std::optional<std::string> sui_coin_get_id_v1(const SuiCoin *coin)
+{
+    return detail::borrowed(::sui_coin_get_id_v1(coin));
+}
+
+inline uint64_t sui_coin_get_version_v1(const SuiCoin *coin)
+{
+    return ::sui_coin_get_version_v1(coin);
+}
+
+inline std::optional<std::string> sui_coin_get_digest_v1(const SuiCoin *coin)
+{
+    return detail::borrowed(::sui_coin_get_digest_v1(coin));
+}
+
+inline uint64_t sui_coin_get_balance_v1(const SuiCoin *coin)
+{
+    return ::sui_coin_get_balance_v1(coin);
+}
+
+inline std::optional<std::string> sui_coin_get_previous_transaction_v1(const SuiCoin *coin)
+{
+    return detail::borrowed(::sui_coin_get_previous_transaction_v1(coin));
+}
+
+inline Handle<SuiWallet, sui_free_wallet_v1> sui_wallet_generate_v1(Str key_scheme, Str word_length)
+{
+    char *error = nullptr;
+    auto raw = ::sui_wallet_generate_v1(key_scheme.c_str(), word_length.c_str(), &error);
+    detail::check_status(error);
+    Handle<SuiWallet, sui_free_wallet_v1> result(raw);
+    return result;
+}
+
+inline std::future<Handle<SuiWallet, sui_free_wallet_v1>> sui_wallet_generate_v1_async(Str key_scheme, Str word_length)
+{
+    return std::async(std::launch::async, sui_wallet_generate_v1, std::move(key_scheme), std::move(word_length));
+}
+
+inline Handle<SuiWallet, sui_free_wallet_v1> sui_wallet_from_address_v1(Str address)
+{
+    char *error = nullptr;
+    auto raw = ::sui_wallet_from_address_v1(address.c_str(), &error);
+    detail::check_status(error);
+    Handle<SuiWallet, sui_free_wallet_v1> result(raw);
+    return result;
+}
+
+inline std::future<Handle<SuiWallet, sui_free_wallet_v1>> sui_wallet_from_address_v1_async(Str address)
+{
+    return std::async(std::launch::async, sui_wallet_from_address_v1, std::move(address));
+}
+
+inline std::optional<std::string> sui_wallet_get_address_v1(const SuiWallet *wallet)
+{
+    return detail::borrowed(::sui_wallet_get_address_v1(wallet));
+}
+
+inline std::optional<std::string> sui_wallet_get_public_key_v1(const SuiWallet *wallet)
+{
+    return detail::borrowed(::sui_wallet_get_public_key_v1(wallet));
+}
+
+inline std::optional<std::string> sui_wallet_get_key_scheme_v1(const SuiWallet *wallet)
+{
+    return detail::borrowed(::sui_wallet_get_key_scheme_v1(wallet));
+}
+
+inline std::optional<std::string> sui_wallet_get_alias_v1(const SuiWallet *wallet)
+{
+    return detail::borrowed(::sui_wallet_get_alias_v1(wallet));
+}
+
+inline std::optional<std::string> sui_wallet_get_mnemonic_v1(const SuiWallet *wallet)
+{
+    return detail::borrowed(::sui_wallet_get_mnemonic_v1(wallet));
+}
+
+inline std::optional<std::string> sui_wallet_get_private_key_v1(const SuiWallet *wallet)
+{
+    return detail::borrowed(::sui_wallet_get_private_key_v1(wallet));
+}
+
+inline std::string sui_rpc_call(Str method, Str params_json)
+{
+    return detail::take_string(::sui_rpc_call(method.c_str(), params_json.c_str()));
+}
+
+inline std::future<std::string> sui_rpc_call_async(Str method, Str params_json)
+{
+    return std::async(std::launch::async, sui_rpc_call, std::move(method), std::move(params_json));
+}
+
+inline std::string get_object_json(Str object_id)
+{
+    return detail::take_string(::get_object_json(object_id.c_str()));
+}
+
+inline std::future<std::string> get_object_json_async(Str object_id)
+{
+    return std::async(std::launch::async, get_object_json, std::move(object_id));
+}
+
+inline std::string get_owned_objects_json(Str address, Str cursor, uintptr_t limit)
+{
+    return detail::take_string(::get_owned_objects_json(address.c_str(), cursor.c_str(), limit));
+}
+
+inline std::future<std::string> get_owned_objects_json_async(Str address, Str cursor, uintptr_t limit)
+{
+    return std::async(std::launch::async, get_owned_objects_json, std::move(address), std::move(cursor), std::move(limit));
+}
+
+inline std::string get_dynamic_fields_json(Str parent_object_id, Str cursor, uintptr_t limit)
+{
+    return detail::take_string(::get_dynamic_fields_json(parent_object_id.c_str(), cursor.c_str(), limit));
+}
+
+inline std::future<std::string> get_dynamic_fields_json_async(Str parent_object_id, Str cursor, uintptr_t limit)
+{
+    return std::async(std::launch::async, get_dynamic_fields_json, std::move(parent_object_id), std::move(cursor), std::move(limit));
+}
+
+inline std::string get_coins_json(Str address)
+{
+    return detail::take_string(::get_coins_json(address.c_str()));
+}
+
+inline std::future<std::string> get_coins_json_async(Str address)
+{
+    return std::async(std::launch::async, get_coins_json, std::move(address));
+}
+
+inline std::string get_balance_json(Str address)
+{
+    return detail::take_string(::get_balance_json(address.c_str()));
+}
+
+inline std::future<std::string> get_balance_json_async(Str address)
+{
+    return std::async(std::launch::async, get_balance_json, std::move(address));
+}
+
+inline std::string get_all_balances_json(Str address)
+{
+    return detail::take_string(::get_all_balances_json(address.c_str()));
+}
+
+inline std::future<std::string> get_all_balances_json_async(Str address)
+{
+    return std::async(std::launch::async, get_all_balances_json, std::move(address));
+}
+
+inline std::string get_checkpoint_json(uint64_t sequence_number)
+{
+    return detail::take_string(::get_checkpoint_json(sequence_number));
+}
+
+inline std::future<std::string> get_checkpoint_json_async(uint64_t sequence_number)
+{
+    return std::async(std::launch::async, get_checkpoint_json, std::move(sequence_number));
+}
+
+inline std::string get_checkpoints_json(Str cursor, uintptr_t limit, bool descending_order)
+{
+    return detail::take_string(::get_checkpoints_json(cursor.c_str(), limit, descending_order));
+}
+
+inline std::future<std::string> get_checkpoints_json_async(Str cursor, uintptr_t limit, bool descending_order)
+{
+    return std::async(std::launch::async, get_checkpoints_json, std::move(cursor), std::move(limit), std::move(descending_order));
+}
+
+inline std::string get_checkpoint_transactions_json(uint64_t sequence_number)
+{
+    return detail::take_string(::get_checkpoint_transactions_json(sequence_number));
+}
+
+inline std::future<std::string> get_checkpoint_transactions_json_async(uint64_t sequence_number)
+{
+    return std::async(std::launch::async, get_checkpoint_transactions_json, std::move(sequence_number));
+}
+
+} // namespace sui
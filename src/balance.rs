@@ -1,3 +1,4 @@
+use crate::name_service::resolve_address;
 use crate::sui_client::SuiClientSingleton;
 use std::str::FromStr;
 
@@ -57,7 +58,7 @@ pub async fn _get_all_balances(address: &str) -> Result<Vec<Balance>> {
     println!(" ***  sui_client done *** ");
 
     // let sui_client = SuiClientBuilder::default().build_testnet().await?;
-    let address = SuiAddress::from_str(address).unwrap();
+    let address = resolve_address(address).await?;
     // Balance
     // Total balance
     // Returns the balance for each coin owned by this address
@@ -69,7 +70,7 @@ pub async fn _get_all_balances(address: &str) -> Result<Vec<Balance>> {
 }
 pub async fn get_coins(address: &str) -> Result<Page<Coin, ObjectID>> {
     let sui = SuiClientSingleton::instance().get_or_init().await?;
-    let active_address: SuiAddress = SuiAddress::from_str(address)?;
+    let active_address: SuiAddress = resolve_address(address).await?;
     println!("Read Coin From address:{}", active_address.to_string());
 
     let coins = sui
@@ -108,7 +109,7 @@ pub async fn get_total_supply() -> Result<Supply> {
 }
 pub async fn get_balance(address: &str) -> Result<Balance> {
     let sui = SuiClientSingleton::instance().get_or_init().await?;
-    let active_address: SuiAddress = SuiAddress::from_str(address)?;
+    let active_address: SuiAddress = resolve_address(address).await?;
       // Balance
     // Returns the balance for the specified coin type for this address,
     // or if None is passed, it will use Coin<SUI> as the coin type
@@ -122,7 +123,7 @@ pub async fn get_balance(address: &str) -> Result<Balance> {
 }
 pub async fn get_all_balances(address: &str) -> Result<Vec<Balance>> {
     let sui = SuiClientSingleton::instance().get_or_init().await?;
-    let active_address: SuiAddress = SuiAddress::from_str(address)?;
+    let active_address: SuiAddress = resolve_address(address).await?;
       // Balance
    // Total balance
     // Returns the balance for each coin owned by this address
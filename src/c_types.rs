@@ -1,8 +1,12 @@
 use std::ffi::{c_char, c_int, c_uchar, c_uint, CStr, CString};
 
 use move_core_types::u256::{self, U256};
-use sui_json_rpc_types::{SuiData, SuiObjectData};
+use sui_json_rpc_types::{SuiObjectData, SuiParsedData};
+use sui_types::base_types::SuiAddress;
+use zeroize::Zeroize;
 
+/// Every string is set: parts that were not requested or are absent read `"None"`, or the JSON
+/// literal `null` for `display`, `content` and `bcs`.
 #[repr(C)]
 pub struct CSuiObjectData {
     pub object_id: *mut c_char,
@@ -17,17 +21,21 @@ pub struct CSuiObjectData {
     pub bcs: *mut c_char,
 }
 
+/// Fields of a Move object as JSON, or the whole package for packages.
+pub(crate) fn content_json(content: Option<&SuiParsedData>) -> Option<String> {
+    match content {
+        Some(SuiParsedData::MoveObject(object)) => {
+            Some(object.fields.clone().to_json_value().to_string())
+        }
+        Some(SuiParsedData::Package(package)) => serde_json::to_string(package).ok(),
+        None => None,
+    }
+}
+
 impl CSuiObjectData {
     pub fn from(data: SuiObjectData) -> Self {
-        let content = data
-            .content
-            .unwrap()
-            .try_as_move()
-            .unwrap()
-            .fields
-            .clone()
-            .to_json_value()
-            .to_string();
+        // Only the parts requested through SuiObjectDataOptions are present
+        let content = content_json(data.content.as_ref());
         CSuiObjectData {
             object_id: CString::new(data.object_id.to_string()).unwrap().into_raw(),
             version: data.version.value(),
@@ -45,13 +53,48 @@ impl CSuiObjectData {
                 None => CString::new("None").unwrap().into_raw(),
             },
             storage_rebate: data.storage_rebate.unwrap_or_default(),
-            display: CString::new(format!("{:?}", data.display))
-                .unwrap()
-                .into_raw(),
-            content: CString::new(content).unwrap().into_raw(),
-            bcs: CString::new(format!("{:?}", data.bcs)).unwrap().into_raw(),
+            display: Self::json(
+                data.display
+                    .as_ref()
+                    .and_then(|display| serde_json::to_string(display).ok()),
+            ),
+            content: Self::json(content),
+            bcs: Self::json(
+                data.bcs
+                    .as_ref()
+                    .and_then(|bcs| serde_json::to_string(bcs).ok()),
+            ),
+        }
+    }
+
+    // Absent parts are the JSON literal null, never a null pointer
+    fn json(value: Option<String>) -> *mut c_char {
+        CString::new(value.unwrap_or_else(|| "null".to_string()))
+            .unwrap()
+            .into_raw()
+    }
+
+    /// Release the strings of the object, the struct itself belongs to the caller.
+    pub fn free(&mut self) {
+        for field in [
+            &mut self.object_id,
+            &mut self.digest,
+            &mut self.type_,
+            &mut self.owner,
+            &mut self.previous_transaction,
+            &mut self.display,
+            &mut self.content,
+            &mut self.bcs,
+        ] {
+            if !field.is_null() {
+                unsafe {
+                    let _ = CString::from_raw(*field);
+                }
+                *field = std::ptr::null_mut();
+            }
         }
     }
+
     pub fn show(&self) {
         unsafe {
             println!("object_id: {}", self.c_str_to_string(self.object_id));
@@ -85,6 +128,18 @@ pub struct CSuiObjectDataArray {
     pub len: usize,
 }
 
+impl From<Vec<CSuiObjectData>> for CSuiObjectDataArray {
+    fn from(objects: Vec<CSuiObjectData>) -> Self {
+        // Boxed so that free_sui_object_data_list can rebuild the allocation from `len` alone
+        let objects = objects.into_boxed_slice();
+        let len = objects.len();
+        CSuiObjectDataArray {
+            data: Box::into_raw(objects) as *mut CSuiObjectData,
+            len,
+        }
+    }
+}
+
 // Struct to hold C-compatible string array
 #[repr(C)]
 pub struct CStringArray {
@@ -92,6 +147,20 @@ pub struct CStringArray {
     pub len: c_int,
 }
 
+impl From<Vec<String>> for CStringArray {
+    fn from(strings: Vec<String>) -> Self {
+        let strings: Box<[*const c_char]> = strings
+            .into_iter()
+            .map(|s| CString::new(s).unwrap().into_raw() as *const c_char)
+            .collect();
+        let len = strings.len() as c_int;
+        CStringArray {
+            data: Box::into_raw(strings) as *const *const c_char,
+            len,
+        }
+    }
+}
+
 #[repr(C)]
 pub struct CU8Array {
     pub data: *const c_uchar,
@@ -99,6 +168,38 @@ pub struct CU8Array {
     pub error: *const c_char,
 }
 
+impl From<Vec<u8>> for CU8Array {
+    fn from(bytes: Vec<u8>) -> Self {
+        let boxed_bytes = bytes.into_boxed_slice();
+        let data = boxed_bytes.as_ptr();
+        let len = boxed_bytes.len() as c_uint;
+        // Leak the boxed slice to keep it alive, it is released by free_u8_array
+        std::mem::forget(boxed_bytes);
+        CU8Array {
+            data,
+            len,
+            error: std::ptr::null(),
+        }
+    }
+}
+
+impl CU8Array {
+    pub fn to_vec(&self) -> Vec<u8> {
+        if self.data.is_null() {
+            return Vec::new();
+        }
+        unsafe { std::slice::from_raw_parts(self.data, self.len as usize).to_vec() }
+    }
+
+    pub fn from_error(error: String) -> Self {
+        CU8Array {
+            data: std::ptr::null(),
+            len: 0,
+            error: CString::new(error).unwrap().into_raw(),
+        }
+    }
+}
+
 pub struct CPure {
     pub data: Vec<u8>
 }
@@ -110,19 +211,77 @@ pub struct ResultCStringArray {
     pub error: *const c_char,
 }
 
-// Function to free the C-compatible string array
+impl ResultCStringArray {
+    pub fn from(result: Result<Vec<String>, anyhow::Error>) -> Self {
+        match result {
+            Ok(strings) => ResultCStringArray {
+                strings: CStringArray::from(strings),
+                error: std::ptr::null(),
+            },
+            Err(e) => ResultCStringArray {
+                strings: CStringArray {
+                    data: std::ptr::null(),
+                    len: 0,
+                },
+                error: CString::new(e.to_string()).unwrap().into_raw(),
+            },
+        }
+    }
+}
+
+// An address or the error that prevented resolving it
+#[repr(C)]
+pub struct CAddressResult {
+    pub address: *const c_char,
+    pub error: *const c_char,
+}
+
+impl CAddressResult {
+    pub fn from(result: Result<SuiAddress, anyhow::Error>) -> Self {
+        match result {
+            Ok(address) => CAddressResult {
+                address: to_c_char(address.to_string()),
+                error: std::ptr::null(),
+            },
+            Err(e) => CAddressResult {
+                address: std::ptr::null(),
+                error: to_c_char(e.to_string()),
+            },
+        }
+    }
+}
+
+// Function to free a string array returned by the library, the strings and the array itself.
+// Arrays built on the C side (function arguments) stay with their owner
 #[no_mangle]
 pub extern "C" fn free_strings(array: CStringArray) {
+    if array.data.is_null() {
+        return;
+    }
     unsafe {
-        for i in 0..array.len {
-            let c_str_ptr = *array.data.add(i as usize);
+        let strings = Box::from_raw(std::slice::from_raw_parts_mut(
+            array.data as *mut *const c_char,
+            array.len as usize,
+        ));
+        for c_str_ptr in strings.iter() {
             if !c_str_ptr.is_null() {
-                drop(CString::from_raw(c_str_ptr as *mut c_char));
+                drop(CString::from_raw(*c_str_ptr as *mut c_char));
             }
         }
     }
 }
 
+#[no_mangle]
+pub extern "C" fn free_result_string_array(result: ResultCStringArray) {
+    free_strings(result.strings);
+    free_error_string(result.error);
+}
+
+#[no_mangle]
+pub extern "C" fn free_address_result(result: CAddressResult) {
+    free_error_string(result.address);
+    free_error_string(result.error);
+}
 
 #[no_mangle]
 pub extern "C" fn free_sui_object_data_list(array: CSuiObjectDataArray) {
@@ -130,32 +289,66 @@ pub extern "C" fn free_sui_object_data_list(array: CSuiObjectDataArray) {
         return;
     }
     unsafe {
-        let boxed_slice = Box::from_raw(std::slice::from_raw_parts_mut(array.data, array.len));
-        for obj in boxed_slice.iter() {
-            if !obj.object_id.is_null() {
-                CString::from_raw(obj.object_id);
-            }
-            if !obj.digest.is_null() {
-                CString::from_raw(obj.digest);
-            }
-            if !obj.type_.is_null() {
-                CString::from_raw(obj.type_);
-            }
-            if !obj.owner.is_null() {
-                CString::from_raw(obj.owner);
-            }
-            if !obj.previous_transaction.is_null() {
-                CString::from_raw(obj.previous_transaction);
-            }
-            if !obj.display.is_null() {
-                CString::from_raw(obj.display);
-            }
-            if !obj.content.is_null() {
-                CString::from_raw(obj.content);
-            }
-            if !obj.bcs.is_null() {
-                CString::from_raw(obj.bcs);
-            }
+        let mut boxed_slice = Box::from_raw(std::slice::from_raw_parts_mut(array.data, array.len));
+        for obj in boxed_slice.iter_mut() {
+            obj.free();
+        }
+    }
+}
+
+/// Copy a secret into a C string, wiping the Rust side copy.
+///
+/// The buffer is allocated with room for the nul terminator so `CString::new`
+/// does not reallocate and leave an unwiped copy behind.
+pub fn secret_to_c_char(mut secret: String) -> *mut c_char {
+    let mut bytes = Vec::with_capacity(secret.len() + 1);
+    bytes.extend_from_slice(secret.as_bytes());
+    secret.zeroize();
+    CString::new(bytes).unwrap().into_raw()
+}
+
+/// Free a C string holding a secret (mnemonic, private key) after wiping its bytes.
+pub fn free_secret_c_char(secret: *mut c_char) {
+    if !secret.is_null() {
+        unsafe {
+            let mut bytes = CString::from_raw(secret).into_bytes_with_nul();
+            bytes.zeroize();
+        }
+    }
+}
+
+/// Null on success, otherwise the error message, released with `sui_free_string`.
+pub type SuiStatus = *const c_char;
+
+/// Move a string to C, the caller releases it with `free_c_char` (`sui_free_string` in C).
+pub(crate) fn to_c_char(s: String) -> *mut c_char {
+    CString::new(s).unwrap().into_raw()
+}
+
+pub(crate) fn free_c_char(s: *const c_char) {
+    free_error_string(s);
+}
+
+/// Borrow a string argument, `None` when it is null or not valid UTF-8.
+pub(crate) fn c_str<'a>(s: *const c_char) -> Option<&'a str> {
+    if s.is_null() {
+        return None;
+    }
+    unsafe { CStr::from_ptr(s) }.to_str().ok()
+}
+
+// Function to free a byte array returned by the library
+#[no_mangle]
+pub extern "C" fn free_u8_array(array: CU8Array) {
+    unsafe {
+        if !array.data.is_null() {
+            let _ = Box::from_raw(std::slice::from_raw_parts_mut(
+                array.data as *mut u8,
+                array.len as usize,
+            ));
+        }
+        if !array.error.is_null() {
+            let _ = CString::from_raw(array.error as *mut c_char);
         }
     }
 }
@@ -165,7 +358,7 @@ pub extern "C" fn free_sui_object_data_list(array: CSuiObjectDataArray) {
 pub extern "C" fn free_error_string(error: *const c_char) {
     if !error.is_null() {
         unsafe {
-            CString::from_raw(error as *mut c_char);
+            drop(CString::from_raw(error as *mut c_char));
         }
     }
 }
@@ -247,6 +440,16 @@ pub extern "C" fn bsc_basic(type_: *const c_char, data: *const c_char) -> *mut C
     Box::into_raw(Box::new(result))
 }
 
+// Free a value from bsc_basic, make_pure copies the bytes so it can go right after
+#[no_mangle]
+pub extern "C" fn free_pure(value: *mut CPure) {
+    if !value.is_null() {
+        unsafe {
+            drop(Box::from_raw(value));
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct CByteVector {
@@ -0,0 +1,230 @@
+use std::ffi::c_char;
+use std::ptr;
+use tokio::runtime;
+
+use crate::c_types::{free_c_char, to_c_char};
+use crate::sui_client::{SuiClientSingleton, SuiEnvironment};
+
+// Chain identifiers (first four bytes of the genesis checkpoint digest) of the
+// long-lived networks, devnet gets a new one on every wipe
+const MAINNET_CHAIN_ID: &str = "35834a8a";
+const TESTNET_CHAIN_ID: &str = "4c78adac";
+
+pub struct ChainInfo {
+    pub chain_identifier: String,
+    pub network: String,
+    pub configured_network: String,
+    pub network_mismatch: bool,
+    pub api_version: String,
+    pub latest_checkpoint: u64,
+    pub reference_gas_price: u64,
+    pub epoch: u64,
+    pub epoch_start_timestamp_ms: u64,
+    pub epoch_duration_ms: u64,
+    pub protocol_version: u64,
+    pub total_stake: u64,
+}
+
+/// Name of the network behind a chain identifier, anything unknown is assumed to be devnet.
+pub fn network_name(chain_identifier: &str) -> &'static str {
+    match chain_identifier {
+        MAINNET_CHAIN_ID => "mainnet",
+        TESTNET_CHAIN_ID => "testnet",
+        _ => "devnet",
+    }
+}
+
+pub async fn _get_chain_identifier() -> Result<String, anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    Ok(sui_client.read_api().get_chain_identifier().await?)
+}
+
+pub async fn _get_latest_checkpoint_sequence_number() -> Result<u64, anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    Ok(sui_client
+        .read_api()
+        .get_latest_checkpoint_sequence_number()
+        .await?)
+}
+
+pub async fn _get_reference_gas_price() -> Result<u64, anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    Ok(sui_client.read_api().get_reference_gas_price().await?)
+}
+
+pub async fn _get_current_epoch() -> Result<u64, anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    Ok(sui_client
+        .governance_api()
+        .get_latest_sui_system_state()
+        .await?
+        .epoch)
+}
+
+/// Protocol config of the current protocol version as JSON.
+pub async fn _get_protocol_config() -> Result<serde_json::Value, anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    let config = sui_client.read_api().get_protocol_config(None).await?;
+    Ok(serde_json::to_value(config)?)
+}
+
+/// Full `SuiSystemStateSummary` as JSON.
+pub async fn _get_system_state() -> Result<serde_json::Value, anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    let system_state = sui_client
+        .governance_api()
+        .get_latest_sui_system_state()
+        .await?;
+    Ok(serde_json::to_value(system_state)?)
+}
+
+/// Snapshot of the network the singleton client is connected to.
+///
+/// `network_mismatch` is set when the chain identifier does not belong to the configured
+/// environment, e.g. a testnet build talking to a mainnet fullnode.
+pub async fn _get_chain_info() -> Result<ChainInfo, anyhow::Error> {
+    let singleton = SuiClientSingleton::instance();
+    let sui_client = singleton.get_or_init().await?;
+    let configured = singleton.environment().await;
+    let chain_identifier = sui_client.read_api().get_chain_identifier().await?;
+    let latest_checkpoint = sui_client
+        .read_api()
+        .get_latest_checkpoint_sequence_number()
+        .await?;
+    let system_state = sui_client
+        .governance_api()
+        .get_latest_sui_system_state()
+        .await?;
+    let network = network_name(&chain_identifier);
+    Ok(ChainInfo {
+        network: network.to_string(),
+        configured_network: configured.name().to_string(),
+        network_mismatch: !matches!(configured, SuiEnvironment::Custom(_))
+            && network != configured.name(),
+        chain_identifier,
+        api_version: sui_client.api_version().to_string(),
+        latest_checkpoint,
+        reference_gas_price: system_state.reference_gas_price,
+        epoch: system_state.epoch,
+        epoch_start_timestamp_ms: system_state.epoch_start_timestamp_ms,
+        epoch_duration_ms: system_state.epoch_duration_ms,
+        protocol_version: system_state.protocol_version,
+        total_stake: system_state.total_stake,
+    })
+}
+
+//public functions for ffi
+
+#[repr(C)]
+pub struct CChainInfo {
+    chain_identifier: *mut c_char,
+    network: *mut c_char,
+    configured_network: *mut c_char,
+    network_mismatch: bool,
+    api_version: *mut c_char,
+    latest_checkpoint: u64,
+    reference_gas_price: u64,
+    epoch: u64,
+    epoch_start_timestamp_ms: u64,
+    epoch_duration_ms: u64,
+    protocol_version: u64,
+    total_stake: u64,
+    error: *mut c_char,
+}
+
+fn string_or_error(result: Result<String, anyhow::Error>) -> *const c_char {
+    let message = match result {
+        Ok(value) => value,
+        Err(e) => format!("Error: {}", e),
+    };
+    to_c_char(message)
+}
+
+#[no_mangle]
+pub extern "C" fn get_chain_info() -> CChainInfo {
+    // Create a new runtime. This step might vary based on the async runtime you are using.
+    let rt = runtime::Runtime::new().unwrap();
+    match rt.block_on(_get_chain_info()) {
+        Ok(info) => CChainInfo {
+            chain_identifier: to_c_char(info.chain_identifier),
+            network: to_c_char(info.network),
+            configured_network: to_c_char(info.configured_network),
+            network_mismatch: info.network_mismatch,
+            api_version: to_c_char(info.api_version),
+            latest_checkpoint: info.latest_checkpoint,
+            reference_gas_price: info.reference_gas_price,
+            epoch: info.epoch,
+            epoch_start_timestamp_ms: info.epoch_start_timestamp_ms,
+            epoch_duration_ms: info.epoch_duration_ms,
+            protocol_version: info.protocol_version,
+            total_stake: info.total_stake,
+            error: ptr::null_mut(),
+        },
+        Err(e) => CChainInfo {
+            chain_identifier: ptr::null_mut(),
+            network: ptr::null_mut(),
+            configured_network: ptr::null_mut(),
+            network_mismatch: false,
+            api_version: ptr::null_mut(),
+            latest_checkpoint: 0,
+            reference_gas_price: 0,
+            epoch: 0,
+            epoch_start_timestamp_ms: 0,
+            epoch_duration_ms: 0,
+            protocol_version: 0,
+            total_stake: 0,
+            error: to_c_char(e.to_string()),
+        },
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn free_chain_info(info: CChainInfo) {
+    free_c_char(info.chain_identifier);
+    free_c_char(info.network);
+    free_c_char(info.configured_network);
+    free_c_char(info.api_version);
+    free_c_char(info.error);
+}
+
+#[no_mangle]
+pub extern "C" fn get_chain_identifier() -> *const c_char {
+    let rt = runtime::Runtime::new().unwrap();
+    string_or_error(rt.block_on(_get_chain_identifier()))
+}
+
+// The numeric getters return 0 when the node cannot be reached, use `get_chain_info` for the error
+
+#[no_mangle]
+pub extern "C" fn get_latest_checkpoint_sequence_number() -> u64 {
+    let rt = runtime::Runtime::new().unwrap();
+    rt.block_on(_get_latest_checkpoint_sequence_number())
+        .unwrap_or(0)
+}
+
+#[no_mangle]
+pub extern "C" fn get_reference_gas_price() -> u64 {
+    let rt = runtime::Runtime::new().unwrap();
+    rt.block_on(_get_reference_gas_price()).unwrap_or(0)
+}
+
+#[no_mangle]
+pub extern "C" fn get_current_epoch() -> u64 {
+    let rt = runtime::Runtime::new().unwrap();
+    rt.block_on(_get_current_epoch()).unwrap_or(0)
+}
+
+#[no_mangle]
+pub extern "C" fn get_protocol_config() -> *const c_char {
+    let rt = runtime::Runtime::new().unwrap();
+    string_or_error(
+        rt.block_on(_get_protocol_config())
+            .map(|config| config.to_string()),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn get_sui_system_state() -> *const c_char {
+    let rt = runtime::Runtime::new().unwrap();
+    string_or_error(rt.block_on(_get_system_state()).map(|state| state.to_string()))
+}
@@ -0,0 +1,403 @@
+use std::ffi::{c_char, c_int, c_void, CStr};
+use std::ptr;
+use std::str::FromStr;
+use std::time::Duration;
+use sui_json_rpc_types::{
+    Checkpoint, CheckpointId, CheckpointPage, SuiExecutionStatus, SuiTransactionBlockDataAPI,
+    SuiTransactionBlockEffectsAPI, SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions,
+};
+use sui_types::digests::CheckpointDigest;
+use tokio::runtime;
+
+use crate::c_types::{free_c_char, to_c_char, SuiStatus};
+use crate::sui_client::SuiClientSingleton;
+
+// Largest batch accepted by sui_multiGetTransactionBlocks
+const MAX_TRANSACTIONS_PER_REQUEST: usize = 50;
+
+pub async fn _get_checkpoint(id: CheckpointId) -> Result<Checkpoint, anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    Ok(sui_client.read_api().get_checkpoint(id).await?)
+}
+
+/// One page of checkpoints, `cursor` is the last sequence number of the previous page.
+pub async fn _get_checkpoints(
+    cursor: Option<u64>,
+    limit: Option<usize>,
+    descending_order: bool,
+) -> Result<CheckpointPage, anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    Ok(sui_client
+        .read_api()
+        .get_checkpoints(cursor.map(Into::into), limit, descending_order)
+        .await?)
+}
+
+/// Transactions of a checkpoint with their effects, fetched in batches.
+pub async fn _get_checkpoint_transactions(
+    sequence_number: u64,
+) -> Result<Vec<SuiTransactionBlockResponse>, anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    let checkpoint = _get_checkpoint(CheckpointId::SequenceNumber(sequence_number)).await?;
+    let options = SuiTransactionBlockResponseOptions::new()
+        .with_input()
+        .with_effects();
+    let mut transactions = Vec::with_capacity(checkpoint.transactions.len());
+    for digests in checkpoint.transactions.chunks(MAX_TRANSACTIONS_PER_REQUEST) {
+        transactions.extend(
+            sui_client
+                .read_api()
+                .multi_get_transactions_with_options(digests.to_vec(), options.clone())
+                .await?,
+        );
+    }
+    Ok(transactions)
+}
+
+/// Poll for new checkpoints starting at `start` (or the latest one) and hand each of them to
+/// `on_checkpoint` in order, until it returns false.
+pub async fn _follow_checkpoints(
+    start: Option<u64>,
+    poll_interval: Duration,
+    mut on_checkpoint: impl FnMut(Checkpoint) -> bool,
+) -> Result<(), anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    let mut next = match start {
+        Some(start) => start,
+        None => {
+            sui_client
+                .read_api()
+                .get_latest_checkpoint_sequence_number()
+                .await?
+        }
+    };
+    loop {
+        let cursor = next.checked_sub(1);
+        let page = _get_checkpoints(cursor, None, false).await?;
+        for checkpoint in page.data {
+            next = checkpoint.sequence_number + 1;
+            if !on_checkpoint(checkpoint) {
+                return Ok(());
+            }
+        }
+        if !page.has_next_page {
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+}
+
+//public functions for ffi
+
+#[repr(C)]
+pub struct CCheckpoint {
+    sequence_number: u64,
+    digest: *mut c_char,
+    previous_digest: *mut c_char,
+    epoch: u64,
+    timestamp_ms: u64,
+    network_total_transactions: u64,
+    computation_cost: u64,
+    storage_cost: u64,
+    storage_rebate: u64,
+    transactions: *mut *mut c_char,
+    transactions_len: usize,
+    error: *mut c_char,
+}
+
+#[repr(C)]
+pub struct CCheckpointPage {
+    checkpoints: *mut CCheckpoint,
+    len: usize,
+    next_cursor: *mut c_char,
+    has_next_page: bool,
+    error: *mut c_char,
+}
+
+#[repr(C)]
+pub struct CCheckpointTransaction {
+    digest: *mut c_char,
+    sender: *mut c_char,
+    success: bool,
+    status_error: *mut c_char,
+    gas_used: u64,
+    timestamp_ms: u64,
+}
+
+#[repr(C)]
+pub struct CCheckpointTransactionArray {
+    transactions: *mut CCheckpointTransaction,
+    len: usize,
+    error: *mut c_char,
+}
+
+/// Called for every new checkpoint, return 0 to keep following and anything else to stop.
+/// The checkpoint is only valid for the duration of the call.
+pub type CheckpointCallback =
+    extern "C" fn(checkpoint: *const CCheckpoint, user_data: *mut c_void) -> c_int;
+
+impl CCheckpoint {
+    fn from(checkpoint: Checkpoint) -> Self {
+        let transactions: Box<[*mut c_char]> = checkpoint
+            .transactions
+            .iter()
+            .map(|digest| to_c_char(digest.to_string()))
+            .collect();
+        let transactions_len = transactions.len();
+        let gas = &checkpoint.epoch_rolling_gas_cost_summary;
+        CCheckpoint {
+            sequence_number: checkpoint.sequence_number,
+            digest: to_c_char(checkpoint.digest.to_string()),
+            previous_digest: checkpoint
+                .previous_digest
+                .map_or(ptr::null_mut(), |digest| to_c_char(digest.to_string())),
+            epoch: checkpoint.epoch,
+            timestamp_ms: checkpoint.timestamp_ms,
+            network_total_transactions: checkpoint.network_total_transactions,
+            computation_cost: gas.computation_cost,
+            storage_cost: gas.storage_cost,
+            storage_rebate: gas.storage_rebate,
+            transactions: Box::into_raw(transactions) as *mut *mut c_char,
+            transactions_len,
+            error: ptr::null_mut(),
+        }
+    }
+
+    fn error(e: anyhow::Error) -> Self {
+        CCheckpoint {
+            sequence_number: 0,
+            digest: ptr::null_mut(),
+            previous_digest: ptr::null_mut(),
+            epoch: 0,
+            timestamp_ms: 0,
+            network_total_transactions: 0,
+            computation_cost: 0,
+            storage_cost: 0,
+            storage_rebate: 0,
+            transactions: ptr::null_mut(),
+            transactions_len: 0,
+            error: to_c_char(e.to_string()),
+        }
+    }
+
+    fn free(&mut self) {
+        if !self.transactions.is_null() {
+            unsafe {
+                let transactions = Box::from_raw(std::slice::from_raw_parts_mut(
+                    self.transactions,
+                    self.transactions_len,
+                ));
+                for digest in transactions.iter() {
+                    free_c_char(*digest);
+                }
+            }
+            self.transactions = ptr::null_mut();
+        }
+        free_c_char(self.digest);
+        free_c_char(self.previous_digest);
+        free_c_char(self.error);
+        self.digest = ptr::null_mut();
+        self.previous_digest = ptr::null_mut();
+        self.error = ptr::null_mut();
+    }
+}
+
+impl CCheckpointTransaction {
+    fn from(response: SuiTransactionBlockResponse) -> Self {
+        let sender = response
+            .transaction
+            .as_ref()
+            .map(|transaction| transaction.data.sender().to_string());
+        let (success, status_error, gas_used) = match &response.effects {
+            Some(effects) => {
+                let gas = effects.gas_cost_summary();
+                let gas_used = (gas.computation_cost + gas.storage_cost)
+                    .saturating_sub(gas.storage_rebate);
+                match effects.status() {
+                    SuiExecutionStatus::Success => (true, None, gas_used),
+                    SuiExecutionStatus::Failure { error } => {
+                        (false, Some(error.clone()), gas_used)
+                    }
+                }
+            }
+            None => (false, None, 0),
+        };
+        CCheckpointTransaction {
+            digest: to_c_char(response.digest.to_string()),
+            sender: sender.map_or(ptr::null_mut(), to_c_char),
+            success,
+            status_error: status_error.map_or(ptr::null_mut(), to_c_char),
+            gas_used,
+            timestamp_ms: response.timestamp_ms.unwrap_or_default(),
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn get_checkpoint_by_sequence_number(sequence_number: u64) -> CCheckpoint {
+    // Create a new runtime. This step might vary based on the async runtime you are using.
+    let rt = runtime::Runtime::new().unwrap();
+    match rt.block_on(_get_checkpoint(CheckpointId::SequenceNumber(sequence_number))) {
+        Ok(checkpoint) => CCheckpoint::from(checkpoint),
+        Err(e) => CCheckpoint::error(e),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn get_checkpoint_by_digest(digest: *const c_char) -> CCheckpoint {
+    let digest = unsafe {
+        assert!(!digest.is_null());
+        CStr::from_ptr(digest).to_str().unwrap_or("Invalid UTF-8")
+    };
+    let digest = match CheckpointDigest::from_str(digest) {
+        Ok(digest) => digest,
+        Err(e) => return CCheckpoint::error(e),
+    };
+    let rt = runtime::Runtime::new().unwrap();
+    match rt.block_on(_get_checkpoint(CheckpointId::Digest(digest))) {
+        Ok(checkpoint) => CCheckpoint::from(checkpoint),
+        Err(e) => CCheckpoint::error(e),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn free_checkpoint(mut checkpoint: CCheckpoint) {
+    checkpoint.free();
+}
+
+// One page of checkpoints, pass the previous `next_cursor` (or null for the first page) and a
+// limit of 0 for the node default
+#[no_mangle]
+pub extern "C" fn get_checkpoints(
+    cursor: *const c_char,
+    limit: usize,
+    descending_order: bool,
+) -> CCheckpointPage {
+    let cursor = if cursor.is_null() {
+        None
+    } else {
+        let cursor = unsafe { CStr::from_ptr(cursor).to_str().unwrap_or("Invalid UTF-8") };
+        match cursor.parse::<u64>() {
+            Ok(cursor) => Some(cursor),
+            Err(e) => {
+                return CCheckpointPage {
+                    checkpoints: ptr::null_mut(),
+                    len: 0,
+                    next_cursor: ptr::null_mut(),
+                    has_next_page: false,
+                    error: to_c_char(format!("Invalid cursor {}: {}", cursor, e)),
+                }
+            }
+        }
+    };
+    let limit = (limit > 0).then_some(limit);
+    let rt = runtime::Runtime::new().unwrap();
+    match rt.block_on(_get_checkpoints(cursor, limit, descending_order)) {
+        Ok(page) => {
+            let checkpoints: Box<[CCheckpoint]> =
+                page.data.into_iter().map(CCheckpoint::from).collect();
+            let len = checkpoints.len();
+            CCheckpointPage {
+                checkpoints: Box::into_raw(checkpoints) as *mut CCheckpoint,
+                len,
+                next_cursor: page
+                    .next_cursor
+                    .map_or(ptr::null_mut(), |cursor| to_c_char((*cursor).to_string())),
+                has_next_page: page.has_next_page,
+                error: ptr::null_mut(),
+            }
+        }
+        Err(e) => CCheckpointPage {
+            checkpoints: ptr::null_mut(),
+            len: 0,
+            next_cursor: ptr::null_mut(),
+            has_next_page: false,
+            error: to_c_char(e.to_string()),
+        },
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn free_checkpoint_page(page: CCheckpointPage) {
+    if !page.checkpoints.is_null() {
+        unsafe {
+            let mut checkpoints =
+                Box::from_raw(std::slice::from_raw_parts_mut(page.checkpoints, page.len));
+            for checkpoint in checkpoints.iter_mut() {
+                checkpoint.free();
+            }
+        }
+    }
+    free_c_char(page.next_cursor);
+    free_c_char(page.error);
+}
+
+#[no_mangle]
+pub extern "C" fn get_checkpoint_transactions(sequence_number: u64) -> CCheckpointTransactionArray {
+    let rt = runtime::Runtime::new().unwrap();
+    match rt.block_on(_get_checkpoint_transactions(sequence_number)) {
+        Ok(transactions) => {
+            let transactions: Box<[CCheckpointTransaction]> = transactions
+                .into_iter()
+                .map(CCheckpointTransaction::from)
+                .collect();
+            let len = transactions.len();
+            CCheckpointTransactionArray {
+                transactions: Box::into_raw(transactions) as *mut CCheckpointTransaction,
+                len,
+                error: ptr::null_mut(),
+            }
+        }
+        Err(e) => CCheckpointTransactionArray {
+            transactions: ptr::null_mut(),
+            len: 0,
+            error: to_c_char(e.to_string()),
+        },
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn free_checkpoint_transaction_array(array: CCheckpointTransactionArray) {
+    if !array.transactions.is_null() {
+        unsafe {
+            let transactions =
+                Box::from_raw(std::slice::from_raw_parts_mut(array.transactions, array.len));
+            for transaction in transactions.iter() {
+                free_c_char(transaction.digest);
+                free_c_char(transaction.sender);
+                free_c_char(transaction.status_error);
+            }
+        }
+    }
+    free_c_char(array.error);
+}
+
+/// `start_sequence_number` of `follow_checkpoints` starting at the latest checkpoint.
+pub const FOLLOW_FROM_LATEST: u64 = u64::MAX;
+
+// Block and call `callback` for every checkpoint from `start_sequence_number` on (0 is genesis,
+// FOLLOW_FROM_LATEST the latest one), polling every `poll_interval_ms` once caught up. Returns
+// null when the callback stopped the stream, or an error message
+#[no_mangle]
+pub extern "C" fn follow_checkpoints(
+    start_sequence_number: u64,
+    poll_interval_ms: u64,
+    callback: CheckpointCallback,
+    user_data: *mut c_void,
+) -> SuiStatus {
+    let start = (start_sequence_number != FOLLOW_FROM_LATEST).then_some(start_sequence_number);
+    let rt = runtime::Runtime::new().unwrap();
+    let result = rt.block_on(_follow_checkpoints(
+        start,
+        Duration::from_millis(poll_interval_ms),
+        |checkpoint| {
+            let mut checkpoint = CCheckpoint::from(checkpoint);
+            let status = callback(&checkpoint, user_data);
+            checkpoint.free();
+            status == 0
+        },
+    ));
+    match result {
+        Ok(()) => ptr::null(),
+        Err(e) => to_c_char(e.to_string()),
+    }
+}
@@ -9,6 +9,7 @@ use std::result::Result::Ok;
 use anyhow::{Result};
 use sui_sdk::{types::base_types::{ObjectID, SuiAddress}, SuiClientBuilder};
 use tokio::runtime;
+use crate::c_types::free_c_char;
 use crate::balance::{self, get_all_balances, get_balance, get_coins, get_total_supply};
 use futures::{future, stream::StreamExt};
 use sui_sdk::types::balance::Supply;
@@ -176,11 +177,7 @@ pub extern "C" fn get_balance_sync(address: *const c_char) -> CBalance {
 
 #[no_mangle]
 pub extern "C" fn free_balance(balance: CBalance) {
-    if !balance.coin_type.is_null() {
-        unsafe {
-            drop(CString::from_raw(balance.coin_type as *mut c_char));
-        }
-    }
+    free_c_char(balance.coin_type as *mut c_char);
 }
 
 /// Wrapper for the Balance struct to implement methods
@@ -201,15 +198,14 @@ impl BalanceWrapper {
 }
 // Function to convert a vector of Balances to a CBalanceArray
 fn to_c_balance_array(balances: Vec<Balance>) -> CBalanceArray {
-    let c_balances: Vec<CBalance> = balances
+    let c_balances: Box<[CBalance]> = balances
         .iter()
         .map(|b| BalanceWrapper(b.clone()).to_c_balance())
         .collect();
     let length = c_balances.len();
-    let balances_ptr = c_balances.as_ptr();
-    std::mem::forget(c_balances); // Prevent Rust from freeing the memory
     CBalanceArray {
-        balances: balances_ptr,
+        // Released by free_balance_array
+        balances: Box::into_raw(c_balances) as *const CBalance,
         length,
     }
 }
@@ -219,20 +215,13 @@ fn to_c_balance_array(balances: Vec<Balance>) -> CBalanceArray {
 pub extern "C" fn free_balance_array(balance_array: CBalanceArray) {
     if !balance_array.balances.is_null() {
         unsafe {
-            let balances_slice = std::slice::from_raw_parts_mut(
-                balance_array.balances as *mut CBalance,
-                balance_array.length,
-            );
-            for balance in balances_slice.iter() {
-                if !balance.coin_type.is_null() {
-                    drop(CString::from_raw(balance.coin_type as *mut c_char));
-                }
-            }
-            drop(Vec::from_raw_parts(
+            let balances = Box::from_raw(std::slice::from_raw_parts_mut(
                 balance_array.balances as *mut CBalance,
                 balance_array.length,
-                balance_array.length,
             ));
+            for balance in balances.iter() {
+                free_c_char(balance.coin_type as *mut c_char);
+            }
         }
     }
 }
@@ -284,26 +273,30 @@ pub struct CCoinArray {
 
 // Function to convert a vector of WrappedCoins to a CCoinArray
 fn to_c_coin_array(coins: Vec<WrappedCoin>) -> CCoinArray {
-    let c_coins: Vec<CCoin> = coins.iter().map(|c| c.to_c_coin()).collect();
+    let c_coins: Box<[CCoin]> = coins.iter().map(|c| c.to_c_coin()).collect();
     let length = c_coins.len();
-    let coins_ptr = c_coins.as_ptr();
-    std::mem::forget(c_coins); // Prevent Rust from freeing the memory
     CCoinArray {
-        coins: coins_ptr,
+        // Released by free_coin_array
+        coins: Box::into_raw(c_coins) as *const CCoin,
         length,
     }
 }
 
-// Function to free a CCoinArray
+// Function to free a CCoinArray and the strings of its coins
 #[no_mangle]
 pub extern "C" fn free_coin_array(coin_array: CCoinArray) {
     if !coin_array.coins.is_null() {
         unsafe {
-            drop(Vec::from_raw_parts(
+            let coins = Box::from_raw(std::slice::from_raw_parts_mut(
                 coin_array.coins as *mut CCoin,
                 coin_array.length,
-                coin_array.length,
             ));
+            for coin in coins.iter() {
+                free_c_char(coin.coin_type);
+                free_c_char(coin.coin_object_id);
+                free_c_char(coin.digest);
+                free_c_char(coin.previous_transaction);
+            }
         }
     }
 }
@@ -0,0 +1,302 @@
+use anyhow::anyhow;
+use fastcrypto::encoding::{Encoding, Hex};
+use move_core_types::language_storage::{StructTag, TypeTag};
+use std::ffi::{c_char, CStr};
+use std::ptr;
+use std::str::FromStr;
+use sui_json_rpc_types::{DynamicFieldPage, SuiObjectData, SuiObjectDataOptions, SuiParsedData};
+use sui_types::base_types::ObjectID;
+use sui_types::dynamic_field::{derive_dynamic_field_id, DynamicFieldInfo, DynamicFieldType};
+use tokio::runtime;
+
+use crate::c_types::{free_c_char, to_c_char, CU8Array};
+use crate::object_read_api::{ObjectLookup, _get_object};
+use crate::sui_client::SuiClientSingleton;
+
+pub struct DynamicField {
+    pub name: serde_json::Value,
+    pub name_type: TypeTag,
+    pub field_type: DynamicFieldType,
+    pub field_id: ObjectID,
+    pub value_object_id: ObjectID,
+    pub value_type: String,
+    pub content: serde_json::Value,
+}
+
+pub async fn _get_dynamic_fields(
+    parent_object_id: &str,
+    cursor: Option<&str>,
+    limit: Option<usize>,
+) -> Result<DynamicFieldPage, anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    let cursor = cursor.map(ObjectID::from_str).transpose()?;
+    Ok(sui_client
+        .read_api()
+        .get_dynamic_fields(ObjectID::from_str(parent_object_id)?, cursor, limit)
+        .await?)
+}
+
+fn move_fields(data: &SuiObjectData) -> Result<serde_json::Value, anyhow::Error> {
+    match &data.content {
+        Some(SuiParsedData::MoveObject(object)) => Ok(object.fields.clone().to_json_value()),
+        _ => Err(anyhow!("Object {} has no Move content", data.object_id)),
+    }
+}
+
+async fn fetch_object(object_id: ObjectID) -> Result<Option<SuiObjectData>, anyhow::Error> {
+    let options = SuiObjectDataOptions::new().with_type().with_content();
+    match _get_object(&object_id.to_string(), options).await? {
+        ObjectLookup::Found(data) => Ok(Some(data)),
+        ObjectLookup::Missing(..) => Ok(None),
+    }
+}
+
+/// `V` of the `0x2::dynamic_field::Field<K, V>` wrapper, the type of the value it holds.
+fn field_value_type(field: &SuiObjectData) -> Result<String, anyhow::Error> {
+    let wrapper = field
+        .type_
+        .as_ref()
+        .ok_or_else(|| anyhow!("Dynamic field {} has no type", field.object_id))?;
+    let tag = StructTag::from_str(&wrapper.to_string())?;
+    tag.type_params
+        .get(1)
+        .map(|value_type| value_type.to_string())
+        .ok_or_else(|| anyhow!("Dynamic field {} is not a Field<K, V>", field.object_id))
+}
+
+/// Look up a dynamic field by its BCS encoded name.
+///
+/// The field id is derived locally from the parent, the name type and the name bytes, the
+/// dynamic object field wrapper is tried when no plain dynamic field exists under that name.
+pub async fn _get_dynamic_field(
+    parent_object_id: &str,
+    name_type: &str,
+    name_bcs: &[u8],
+) -> Result<DynamicField, anyhow::Error> {
+    let parent = ObjectID::from_str(parent_object_id)?;
+    let name_type = TypeTag::from_str(name_type)?;
+
+    let field_id = derive_dynamic_field_id(parent, &name_type, name_bcs)?;
+    if let Some(field) = fetch_object(field_id).await? {
+        let fields = move_fields(&field)?;
+        return Ok(DynamicField {
+            name: fields["name"].clone(),
+            name_type,
+            field_type: DynamicFieldType::DynamicField,
+            field_id,
+            value_object_id: field_id,
+            value_type: field_value_type(&field)?,
+            content: fields["value"].clone(),
+        });
+    }
+
+    let wrapper_type = TypeTag::Struct(Box::new(DynamicFieldInfo::dynamic_object_field_wrapper(
+        name_type.clone(),
+    )));
+    let field_id = derive_dynamic_field_id(parent, &wrapper_type, name_bcs)?;
+    let field = fetch_object(field_id).await?.ok_or_else(|| {
+        anyhow!(
+            "Dynamic field {} of type {} not found on {}",
+            Hex::encode(name_bcs),
+            name_type,
+            parent
+        )
+    })?;
+    let fields = move_fields(&field)?;
+    let value_object_id = fields["value"]
+        .as_str()
+        .ok_or_else(|| anyhow!("Dynamic object field {} has no object id", field_id))
+        .and_then(|id| Ok(ObjectID::from_str(id)?))?;
+    let value = fetch_object(value_object_id)
+        .await?
+        .ok_or_else(|| anyhow!("Dynamic field value {} not found", value_object_id))?;
+    Ok(DynamicField {
+        name: fields["name"]["name"].clone(),
+        name_type,
+        field_type: DynamicFieldType::DynamicObject,
+        field_id,
+        value_object_id,
+        value_type: value.type_.as_ref().map(|t| t.to_string()).unwrap_or_default(),
+        content: move_fields(&value)?,
+    })
+}
+
+//public functions for ffi
+
+#[repr(C)]
+pub struct CDynamicFieldInfo {
+    name: *mut c_char,
+    name_type: *mut c_char,
+    field_type: *mut c_char,
+    object_type: *mut c_char,
+    object_id: *mut c_char,
+    version: u64,
+    digest: *mut c_char,
+}
+
+#[repr(C)]
+pub struct CDynamicFieldPage {
+    fields: *mut CDynamicFieldInfo,
+    len: usize,
+    next_cursor: *mut c_char,
+    has_next_page: bool,
+    error: *mut c_char,
+}
+
+#[repr(C)]
+pub struct CDynamicField {
+    name: *mut c_char,
+    name_type: *mut c_char,
+    field_type: *mut c_char,
+    field_id: *mut c_char,
+    value_object_id: *mut c_char,
+    value_type: *mut c_char,
+    content: *mut c_char,
+    error: *mut c_char,
+}
+
+fn field_type_name(field_type: &DynamicFieldType) -> String {
+    match field_type {
+        DynamicFieldType::DynamicField => "DynamicField".to_string(),
+        DynamicFieldType::DynamicObject => "DynamicObject".to_string(),
+    }
+}
+
+impl CDynamicFieldInfo {
+    fn from(info: DynamicFieldInfo) -> Self {
+        CDynamicFieldInfo {
+            name: to_c_char(info.name.value.to_string()),
+            name_type: to_c_char(info.name.type_.to_string()),
+            field_type: to_c_char(field_type_name(&info.type_)),
+            object_type: to_c_char(info.object_type),
+            object_id: to_c_char(info.object_id.to_string()),
+            version: info.version.value(),
+            digest: to_c_char(info.digest.to_string()),
+        }
+    }
+
+    fn free(&mut self) {
+        free_c_char(self.name);
+        free_c_char(self.name_type);
+        free_c_char(self.field_type);
+        free_c_char(self.object_type);
+        free_c_char(self.object_id);
+        free_c_char(self.digest);
+    }
+}
+
+// One page of the dynamic fields of `parent_object_id`, pass the previous `next_cursor`
+// (or null for the first page) and a limit of 0 for the node default
+#[no_mangle]
+pub extern "C" fn get_dynamic_fields(
+    parent_object_id: *const c_char,
+    cursor: *const c_char,
+    limit: usize,
+) -> CDynamicFieldPage {
+    let parent_object_id = unsafe {
+        assert!(!parent_object_id.is_null());
+        CStr::from_ptr(parent_object_id).to_str().unwrap_or("Invalid UTF-8")
+    };
+    let cursor = (!cursor.is_null())
+        .then(|| unsafe { CStr::from_ptr(cursor).to_str().unwrap_or("Invalid UTF-8") });
+    let limit = (limit > 0).then_some(limit);
+    // Create a new runtime. This step might vary based on the async runtime you are using.
+    let rt = runtime::Runtime::new().unwrap();
+    match rt.block_on(_get_dynamic_fields(parent_object_id, cursor, limit)) {
+        Ok(page) => {
+            let fields: Box<[CDynamicFieldInfo]> = page
+                .data
+                .into_iter()
+                .map(CDynamicFieldInfo::from)
+                .collect();
+            let len = fields.len();
+            CDynamicFieldPage {
+                fields: Box::into_raw(fields) as *mut CDynamicFieldInfo,
+                len,
+                next_cursor: page
+                    .next_cursor
+                    .map_or(ptr::null_mut(), |cursor| to_c_char(cursor.to_string())),
+                has_next_page: page.has_next_page,
+                error: ptr::null_mut(),
+            }
+        }
+        Err(e) => CDynamicFieldPage {
+            fields: ptr::null_mut(),
+            len: 0,
+            next_cursor: ptr::null_mut(),
+            has_next_page: false,
+            error: to_c_char(e.to_string()),
+        },
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn free_dynamic_field_page(page: CDynamicFieldPage) {
+    if !page.fields.is_null() {
+        unsafe {
+            let mut fields = Box::from_raw(std::slice::from_raw_parts_mut(page.fields, page.len));
+            for field in fields.iter_mut() {
+                field.free();
+            }
+        }
+    }
+    free_c_char(page.next_cursor);
+    free_c_char(page.error);
+}
+
+// Fetch a dynamic field by name, `name_bcs` is the BCS encoding of the name as a `name_type`
+// value (e.g. the 8 little-endian bytes of a u64 or the 32 bytes of an address)
+#[no_mangle]
+pub extern "C" fn get_dynamic_field(
+    parent_object_id: *const c_char,
+    name_type: *const c_char,
+    name_bcs: CU8Array,
+) -> CDynamicField {
+    let parent_object_id = unsafe {
+        assert!(!parent_object_id.is_null());
+        CStr::from_ptr(parent_object_id).to_str().unwrap_or("Invalid UTF-8")
+    };
+    let name_type = unsafe {
+        assert!(!name_type.is_null());
+        CStr::from_ptr(name_type).to_str().unwrap_or("Invalid UTF-8")
+    };
+    let rt = runtime::Runtime::new().unwrap();
+    match rt.block_on(_get_dynamic_field(
+        parent_object_id,
+        name_type,
+        &name_bcs.to_vec(),
+    )) {
+        Ok(field) => CDynamicField {
+            name: to_c_char(field.name.to_string()),
+            name_type: to_c_char(field.name_type.to_string()),
+            field_type: to_c_char(field_type_name(&field.field_type)),
+            field_id: to_c_char(field.field_id.to_string()),
+            value_object_id: to_c_char(field.value_object_id.to_string()),
+            value_type: to_c_char(field.value_type),
+            content: to_c_char(field.content.to_string()),
+            error: ptr::null_mut(),
+        },
+        Err(e) => CDynamicField {
+            name: ptr::null_mut(),
+            name_type: ptr::null_mut(),
+            field_type: ptr::null_mut(),
+            field_id: ptr::null_mut(),
+            value_object_id: ptr::null_mut(),
+            value_type: ptr::null_mut(),
+            content: ptr::null_mut(),
+            error: to_c_char(e.to_string()),
+        },
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn free_dynamic_field(field: CDynamicField) {
+    free_c_char(field.name);
+    free_c_char(field.name_type);
+    free_c_char(field.field_type);
+    free_c_char(field.field_id);
+    free_c_char(field.value_object_id);
+    free_c_char(field.value_type);
+    free_c_char(field.content);
+    free_c_char(field.error);
+}
@@ -0,0 +1,435 @@
+// Opaque handles with versioned accessors for prebuilt hosts, the rules are in README.md
+
+use std::ffi::{c_char, CString};
+use std::ptr;
+use std::str::FromStr;
+
+use move_core_types::language_storage::StructTag;
+use sui_json_rpc_types::{Coin, SuiObjectData, SuiObjectDataFilter, SuiObjectDataOptions};
+use tokio::runtime;
+
+use crate::c_types::{c_str, content_json};
+use crate::name_service::resolve_address;
+use crate::object_read_api::{_get_all_owned_objects, _get_object, ObjectLookup};
+use crate::sui_client::SuiClientSingleton;
+use crate::wallet::{generate_new_private, get_wallet_from_address_private, Wallet};
+
+/// Version of the handle ABI. Raised whenever a `_vN` function is added, existing functions are
+/// never changed or removed.
+pub const SUI_SDK_ABI_VERSION: u32 = 1;
+
+#[no_mangle]
+pub extern "C" fn sui_sdk_abi_version() -> u32 {
+    SUI_SDK_ABI_VERSION
+}
+
+/// An object read from the network.
+pub struct SuiObject {
+    object_id: CString,
+    version: u64,
+    digest: CString,
+    type_: Option<CString>,
+    owner: Option<CString>,
+    previous_transaction: Option<CString>,
+    storage_rebate: u64,
+    display: Option<CString>,
+    content: Option<CString>,
+    bcs: Option<CString>,
+}
+
+impl From<SuiObjectData> for SuiObject {
+    fn from(data: SuiObjectData) -> Self {
+        let json = |value: Option<String>| value.map(|json| CString::new(json).unwrap());
+        SuiObject {
+            object_id: CString::new(data.object_id.to_string()).unwrap(),
+            version: data.version.value(),
+            digest: CString::new(data.digest.to_string()).unwrap(),
+            type_: data.type_.map(|t| CString::new(t.to_string()).unwrap()),
+            owner: data.owner.map(|o| CString::new(o.to_string()).unwrap()),
+            previous_transaction: data
+                .previous_transaction
+                .map(|d| CString::new(d.to_string()).unwrap()),
+            storage_rebate: data.storage_rebate.unwrap_or_default(),
+            display: json(
+                data.display
+                    .as_ref()
+                    .and_then(|d| serde_json::to_string(d).ok()),
+            ),
+            content: json(content_json(data.content.as_ref())),
+            bcs: json(
+                data.bcs
+                    .as_ref()
+                    .and_then(|b| serde_json::to_string(b).ok()),
+            ),
+        }
+    }
+}
+
+pub struct SuiObjectList {
+    objects: Vec<SuiObject>,
+}
+
+/// A coin owned by an address.
+pub struct SuiCoin {
+    coin_type: CString,
+    coin_object_id: CString,
+    version: u64,
+    digest: CString,
+    balance: u64,
+    previous_transaction: CString,
+}
+
+impl From<Coin> for SuiCoin {
+    fn from(coin: Coin) -> Self {
+        SuiCoin {
+            coin_type: CString::new(coin.coin_type).unwrap(),
+            coin_object_id: CString::new(coin.coin_object_id.to_string()).unwrap(),
+            version: coin.version.value(),
+            digest: CString::new(coin.digest.base58_encode()).unwrap(),
+            balance: coin.balance,
+            previous_transaction: CString::new(coin.previous_transaction.base58_encode()).unwrap(),
+        }
+    }
+}
+
+pub struct SuiCoinList {
+    coins: Vec<SuiCoin>,
+}
+
+/// A wallet from the keystore or a freshly generated one. Secrets are wiped when it is freed.
+pub struct SuiWallet {
+    wallet: Wallet,
+}
+
+impl Drop for SuiWallet {
+    fn drop(&mut self) {
+        self.wallet.free();
+    }
+}
+
+fn set_error(error: *mut *mut c_char, message: Option<String>) {
+    if !error.is_null() {
+        unsafe {
+            *error = message.map_or(ptr::null_mut(), |message| {
+                CString::new(message).unwrap().into_raw()
+            });
+        }
+    }
+}
+
+/// Box the value into a handle, or report the error and return null.
+fn into_handle<T>(result: Result<T, anyhow::Error>, error: *mut *mut c_char) -> *mut T {
+    match result {
+        Ok(value) => {
+            set_error(error, None);
+            Box::into_raw(Box::new(value))
+        }
+        Err(e) => {
+            set_error(error, Some(e.to_string()));
+            ptr::null_mut()
+        }
+    }
+}
+
+fn free_handle<T>(handle: *mut T) {
+    if !handle.is_null() {
+        unsafe {
+            drop(Box::from_raw(handle));
+        }
+    }
+}
+
+fn borrow<'a, T>(handle: *const T) -> Option<&'a T> {
+    unsafe { handle.as_ref() }
+}
+
+fn as_ptr(s: Option<&CString>) -> *const c_char {
+    s.map_or(ptr::null(), |s| s.as_ptr())
+}
+
+// Objects
+
+async fn _fetch_object(object_id: &str) -> Result<SuiObject, anyhow::Error> {
+    match _get_object(object_id, SuiObjectDataOptions::full_content()).await? {
+        ObjectLookup::Found(data) => Ok(SuiObject::from(data)),
+        ObjectLookup::Missing(_, message) => Err(anyhow::anyhow!(message)),
+    }
+}
+
+async fn _fetch_owned_objects(
+    address: &str,
+    object_type: Option<&str>,
+) -> Result<SuiObjectList, anyhow::Error> {
+    let filter = object_type
+        .map(|object_type| {
+            StructTag::from_str(object_type)
+                .map(SuiObjectDataFilter::StructType)
+                .map_err(|e| anyhow::anyhow!("Invalid object type {}: {}", object_type, e))
+        })
+        .transpose()?;
+    let objects =
+        _get_all_owned_objects(address, filter, SuiObjectDataOptions::full_content()).await?;
+    Ok(SuiObjectList {
+        objects: objects.into_iter().map(SuiObject::from).collect(),
+    })
+}
+
+/// The object with its type, owner, display and content; missing or deleted objects are errors.
+#[no_mangle]
+pub extern "C" fn sui_object_fetch_v1(
+    object_id: *const c_char,
+    error: *mut *mut c_char,
+) -> *mut SuiObject {
+    let Some(object_id) = c_str(object_id) else {
+        return into_handle(Err(anyhow::anyhow!("Invalid object id")), error);
+    };
+    // Create a new runtime. This step might vary based on the async runtime you are using.
+    let rt = runtime::Runtime::new().unwrap();
+    into_handle(rt.block_on(_fetch_object(object_id)), error)
+}
+
+/// Every object owned by `address`, only those of `object_type` unless it is null.
+#[no_mangle]
+pub extern "C" fn sui_owned_objects_fetch_v1(
+    address: *const c_char,
+    object_type: *const c_char,
+    error: *mut *mut c_char,
+) -> *mut SuiObjectList {
+    let Some(address) = c_str(address) else {
+        return into_handle(Err(anyhow::anyhow!("Invalid address")), error);
+    };
+    let rt = runtime::Runtime::new().unwrap();
+    into_handle(
+        rt.block_on(_fetch_owned_objects(address, c_str(object_type))),
+        error,
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn sui_object_list_len_v1(list: *const SuiObjectList) -> usize {
+    borrow(list).map_or(0, |list| list.objects.len())
+}
+
+/// Borrowed from the list, null when `index` is out of range.
+#[no_mangle]
+pub extern "C" fn sui_object_list_at_v1(
+    list: *const SuiObjectList,
+    index: usize,
+) -> *const SuiObject {
+    borrow(list)
+        .and_then(|list| list.objects.get(index))
+        .map_or(ptr::null(), |object| object as *const SuiObject)
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_object_list_v1(list: *mut SuiObjectList) {
+    free_handle(list);
+}
+
+/// An object from `sui_object_fetch_v1`, never one borrowed from a list.
+#[no_mangle]
+pub extern "C" fn sui_free_object_v1(object: *mut SuiObject) {
+    free_handle(object);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_object_get_id_v1(object: *const SuiObject) -> *const c_char {
+    as_ptr(borrow(object).map(|o| &o.object_id))
+}
+
+#[no_mangle]
+pub extern "C" fn sui_object_get_version_v1(object: *const SuiObject) -> u64 {
+    borrow(object).map_or(0, |o| o.version)
+}
+
+#[no_mangle]
+pub extern "C" fn sui_object_get_digest_v1(object: *const SuiObject) -> *const c_char {
+    as_ptr(borrow(object).map(|o| &o.digest))
+}
+
+#[no_mangle]
+pub extern "C" fn sui_object_get_type_v1(object: *const SuiObject) -> *const c_char {
+    as_ptr(borrow(object).and_then(|o| o.type_.as_ref()))
+}
+
+#[no_mangle]
+pub extern "C" fn sui_object_get_owner_v1(object: *const SuiObject) -> *const c_char {
+    as_ptr(borrow(object).and_then(|o| o.owner.as_ref()))
+}
+
+#[no_mangle]
+pub extern "C" fn sui_object_get_previous_transaction_v1(
+    object: *const SuiObject,
+) -> *const c_char {
+    as_ptr(borrow(object).and_then(|o| o.previous_transaction.as_ref()))
+}
+
+#[no_mangle]
+pub extern "C" fn sui_object_get_storage_rebate_v1(object: *const SuiObject) -> u64 {
+    borrow(object).map_or(0, |o| o.storage_rebate)
+}
+
+/// The `Display` of the object as JSON.
+#[no_mangle]
+pub extern "C" fn sui_object_get_display_json_v1(object: *const SuiObject) -> *const c_char {
+    as_ptr(borrow(object).and_then(|o| o.display.as_ref()))
+}
+
+/// The fields of a Move object, or the modules of a package, as JSON.
+#[no_mangle]
+pub extern "C" fn sui_object_get_content_json_v1(object: *const SuiObject) -> *const c_char {
+    as_ptr(borrow(object).and_then(|o| o.content.as_ref()))
+}
+
+#[no_mangle]
+pub extern "C" fn sui_object_get_bcs_json_v1(object: *const SuiObject) -> *const c_char {
+    as_ptr(borrow(object).and_then(|o| o.bcs.as_ref()))
+}
+
+// Coins
+
+async fn _fetch_coins(address: &str) -> Result<SuiCoinList, anyhow::Error> {
+    let sui = SuiClientSingleton::instance().get_or_init().await?;
+    let owner = resolve_address(address).await?;
+    let mut coins = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = sui
+            .coin_read_api()
+            .get_coins(owner, None, cursor, None)
+            .await?;
+        coins.extend(page.data.into_iter().map(SuiCoin::from));
+        match page.next_cursor {
+            Some(next_cursor) if page.has_next_page => cursor = Some(next_cursor),
+            _ => return Ok(SuiCoinList { coins }),
+        }
+    }
+}
+
+/// The SUI coins owned by `address`, every page of them.
+#[no_mangle]
+pub extern "C" fn sui_coins_fetch_v1(
+    address: *const c_char,
+    error: *mut *mut c_char,
+) -> *mut SuiCoinList {
+    let Some(address) = c_str(address) else {
+        return into_handle(Err(anyhow::anyhow!("Invalid address")), error);
+    };
+    let rt = runtime::Runtime::new().unwrap();
+    into_handle(rt.block_on(_fetch_coins(address)), error)
+}
+
+#[no_mangle]
+pub extern "C" fn sui_coin_list_len_v1(list: *const SuiCoinList) -> usize {
+    borrow(list).map_or(0, |list| list.coins.len())
+}
+
+/// Borrowed from the list, null when `index` is out of range.
+#[no_mangle]
+pub extern "C" fn sui_coin_list_at_v1(list: *const SuiCoinList, index: usize) -> *const SuiCoin {
+    borrow(list)
+        .and_then(|list| list.coins.get(index))
+        .map_or(ptr::null(), |coin| coin as *const SuiCoin)
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_coin_list_v1(list: *mut SuiCoinList) {
+    free_handle(list);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_coin_get_type_v1(coin: *const SuiCoin) -> *const c_char {
+    as_ptr(borrow(coin).map(|c| &c.coin_type))
+}
+
+#[no_mangle]
+pub extern "C" fn sui_coin_get_id_v1(coin: *const SuiCoin) -> *const c_char {
+    as_ptr(borrow(coin).map(|c| &c.coin_object_id))
+}
+
+#[no_mangle]
+pub extern "C" fn sui_coin_get_version_v1(coin: *const SuiCoin) -> u64 {
+    borrow(coin).map_or(0, |c| c.version)
+}
+
+#[no_mangle]
+pub extern "C" fn sui_coin_get_digest_v1(coin: *const SuiCoin) -> *const c_char {
+    as_ptr(borrow(coin).map(|c| &c.digest))
+}
+
+#[no_mangle]
+pub extern "C" fn sui_coin_get_balance_v1(coin: *const SuiCoin) -> u64 {
+    borrow(coin).map_or(0, |c| c.balance)
+}
+
+#[no_mangle]
+pub extern "C" fn sui_coin_get_previous_transaction_v1(coin: *const SuiCoin) -> *const c_char {
+    as_ptr(borrow(coin).map(|c| &c.previous_transaction))
+}
+
+// Wallets
+
+/// A new key pair with its mnemonic, not added to the keystore. `key_scheme` and `word_length`
+/// take the values of `generate_wallet`.
+#[no_mangle]
+pub extern "C" fn sui_wallet_generate_v1(
+    key_scheme: *const c_char,
+    word_length: *const c_char,
+    error: *mut *mut c_char,
+) -> *mut SuiWallet {
+    let result = generate_new_private(
+        c_str(key_scheme).unwrap_or("ed25519"),
+        c_str(word_length).unwrap_or("word12"),
+    );
+    into_handle(result.map(|wallet| SuiWallet { wallet }), error)
+}
+
+/// The keystore entry of `address`, without its private key.
+#[no_mangle]
+pub extern "C" fn sui_wallet_from_address_v1(
+    address: *const c_char,
+    error: *mut *mut c_char,
+) -> *mut SuiWallet {
+    let Some(address) = c_str(address) else {
+        return into_handle(Err(anyhow::anyhow!("Invalid address")), error);
+    };
+    let result = get_wallet_from_address_private(address, false);
+    into_handle(result.map(|wallet| SuiWallet { wallet }), error)
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_wallet_v1(wallet: *mut SuiWallet) {
+    free_handle(wallet);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_wallet_get_address_v1(wallet: *const SuiWallet) -> *const c_char {
+    borrow(wallet).map_or(ptr::null(), |w| w.wallet.address.cast_const())
+}
+
+#[no_mangle]
+pub extern "C" fn sui_wallet_get_public_key_v1(wallet: *const SuiWallet) -> *const c_char {
+    borrow(wallet).map_or(ptr::null(), |w| w.wallet.public_base64_key.cast_const())
+}
+
+#[no_mangle]
+pub extern "C" fn sui_wallet_get_key_scheme_v1(wallet: *const SuiWallet) -> *const c_char {
+    borrow(wallet).map_or(ptr::null(), |w| w.wallet.key_scheme.cast_const())
+}
+
+#[no_mangle]
+pub extern "C" fn sui_wallet_get_alias_v1(wallet: *const SuiWallet) -> *const c_char {
+    borrow(wallet).map_or(ptr::null(), |w| w.wallet.alias.cast_const())
+}
+
+/// Only set for generated wallets.
+#[no_mangle]
+pub extern "C" fn sui_wallet_get_mnemonic_v1(wallet: *const SuiWallet) -> *const c_char {
+    borrow(wallet).map_or(ptr::null(), |w| w.wallet.mnemonic.cast_const())
+}
+
+/// Base64 private key with its scheme flag, only set for generated wallets.
+#[no_mangle]
+pub extern "C" fn sui_wallet_get_private_key_v1(wallet: *const SuiWallet) -> *const c_char {
+    borrow(wallet).map_or(ptr::null(), |w| w.wallet.private_key.cast_const())
+}
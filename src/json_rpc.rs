@@ -0,0 +1,306 @@
+// JSON in, JSON out access to the fullnode for scripting hosts, the rules are in README.md
+
+use std::ffi::{c_char, CString};
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::{json, Value};
+use sui_json_rpc_types::{CheckpointId, SuiObjectDataOptions};
+use tokio::runtime;
+
+use crate::balance::{get_all_balances, get_balance, get_coins};
+use crate::c_types::c_str;
+use crate::checkpoints::{_get_checkpoint, _get_checkpoint_transactions, _get_checkpoints};
+use crate::dynamic_fields::_get_dynamic_fields;
+use crate::object_read_api::{_get_object, _get_owned_objects, ObjectLookup};
+use crate::sui_client::SuiClientSingleton;
+
+pub const RPC_INVALID_REQUEST: i64 = -32600;
+pub const RPC_METHOD_NOT_FOUND: i64 = -32601;
+pub const RPC_INVALID_PARAMS: i64 = -32602;
+/// The request did not get an answer, or a query function failed.
+pub const RPC_SERVER_ERROR: i64 = -32000;
+
+/// Attempts of a request that failed without an answer or with 429/5xx, the delay doubles after
+/// each of them. Retrying is safe for writes as well, executing a signed transaction again
+/// returns the effects of the first execution.
+const MAX_ATTEMPTS: u32 = 3;
+const RETRY_DELAY: Duration = Duration::from_millis(200);
+/// Time an attempt may take before it counts as failed without an answer.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug)]
+pub struct RpcError {
+    pub code: i64,
+    pub message: String,
+    pub data: Option<Value>,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        RpcError {
+            code,
+            message: message.into(),
+            data: None,
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        let mut error = json!({"code": self.code, "message": self.message});
+        if let Some(data) = &self.data {
+            error["data"] = data.clone();
+        }
+        error
+    }
+}
+
+impl From<anyhow::Error> for RpcError {
+    fn from(e: anyhow::Error) -> Self {
+        RpcError::new(RPC_SERVER_ERROR, e.to_string())
+    }
+}
+
+/// A failed attempt, `retry` when sending the request again may succeed.
+struct Failure {
+    retry: bool,
+    message: String,
+}
+
+async fn send(client: &reqwest::Client, url: &str, request: &Value) -> Result<Value, Failure> {
+    let response = client
+        .post(url)
+        .json(request)
+        .send()
+        .await
+        .map_err(|e| Failure {
+            retry: true,
+            message: e.to_string(),
+        })?;
+    let status = response.status();
+    if status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Err(Failure {
+            retry: true,
+            message: format!("HTTP {}", status),
+        });
+    }
+    response.json::<Value>().await.map_err(|e| Failure {
+        retry: e.is_timeout(),
+        message: format!("Invalid response (HTTP {}): {}", status, e),
+    })
+}
+
+/// Call `method` of the node with `params` (an array or an object) and return its result.
+pub async fn _rpc_call(method: &str, params: Value) -> Result<Value, RpcError> {
+    if !params.is_array() && !params.is_object() {
+        return Err(RpcError::new(
+            RPC_INVALID_PARAMS,
+            "params must be a JSON array or object",
+        ));
+    }
+    let singleton = SuiClientSingleton::instance();
+    let sui = singleton.get_or_init().await?;
+    if !sui.available_rpc_methods().iter().any(|m| m == method) {
+        return Err(RpcError::new(
+            RPC_METHOD_NOT_FOUND,
+            format!("Method {} is not provided by the node", method),
+        ));
+    }
+    let environment = singleton.environment().await;
+    let request = json!({"jsonrpc": "2.0", "id": 1, "method": method, "params": params});
+    let client = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(anyhow::Error::from)?;
+
+    let mut attempt = 1;
+    let response = loop {
+        match send(&client, environment.rpc_url(), &request).await {
+            Ok(response) => break response,
+            Err(failure) if failure.retry && attempt < MAX_ATTEMPTS => {
+                tokio::time::sleep(RETRY_DELAY * 2u32.pow(attempt - 1)).await;
+                attempt += 1;
+            }
+            Err(failure) => {
+                return Err(RpcError::new(
+                    RPC_SERVER_ERROR,
+                    format!(
+                        "{} failed after {} attempt(s): {}",
+                        method, attempt, failure.message
+                    ),
+                ))
+            }
+        }
+    };
+
+    match (response.get("result"), response.get("error")) {
+        (_, Some(error)) => Err(RpcError {
+            code: error["code"].as_i64().unwrap_or(RPC_SERVER_ERROR),
+            message: error["message"].as_str().unwrap_or_default().to_string(),
+            data: error.get("data").cloned(),
+        }),
+        (Some(result), None) => Ok(result.clone()),
+        (None, None) => Err(RpcError::new(
+            RPC_SERVER_ERROR,
+            format!("Invalid response: {}", response),
+        )),
+    }
+}
+
+fn to_json_response(result: Result<Value, RpcError>) -> *const c_char {
+    let response = match result {
+        Ok(result) => json!({ "result": result }),
+        Err(error) => json!({ "error": error.to_json() }),
+    };
+    CString::new(response.to_string()).unwrap().into_raw()
+}
+
+fn serialize<T: Serialize>(result: Result<T, anyhow::Error>) -> *const c_char {
+    to_json_response(
+        result
+            .and_then(|value| Ok(serde_json::to_value(value)?))
+            .map_err(RpcError::from),
+    )
+}
+
+/// Answer of a query with a required string argument.
+fn query<'a, T: Serialize, F: std::future::Future<Output = Result<T, anyhow::Error>>>(
+    argument: *const c_char,
+    name: &str,
+    query: impl FnOnce(&'a str) -> F,
+) -> *const c_char {
+    let Some(argument) = c_str(argument) else {
+        return to_json_response(Err(RpcError::new(
+            RPC_INVALID_PARAMS,
+            format!("Invalid {}", name),
+        )));
+    };
+    // Create a new runtime. This step might vary based on the async runtime you are using.
+    let rt = runtime::Runtime::new().unwrap();
+    serialize(rt.block_on(query(argument)))
+}
+
+/// Forward a JSON-RPC call, `params_json` is a JSON array or object (null for no params).
+#[no_mangle]
+pub extern "C" fn sui_rpc_call(method: *const c_char, params_json: *const c_char) -> *const c_char {
+    let Some(method) = c_str(method) else {
+        return to_json_response(Err(RpcError::new(RPC_INVALID_REQUEST, "Invalid method")));
+    };
+    let params = match c_str(params_json) {
+        None if params_json.is_null() => Ok(json!([])),
+        None => Err(RpcError::new(
+            RPC_INVALID_PARAMS,
+            "params are not valid UTF-8",
+        )),
+        Some(params) => serde_json::from_str::<Value>(params)
+            .map_err(|e| RpcError::new(RPC_INVALID_PARAMS, format!("Invalid params: {}", e))),
+    };
+    let rt = runtime::Runtime::new().unwrap();
+    to_json_response(params.and_then(|params| rt.block_on(_rpc_call(method, params))))
+}
+
+/// The object with every field, a missing object is an error carrying its ObjectStatus as data.
+#[no_mangle]
+pub extern "C" fn get_object_json(object_id: *const c_char) -> *const c_char {
+    let Some(object_id) = c_str(object_id) else {
+        return to_json_response(Err(RpcError::new(RPC_INVALID_PARAMS, "Invalid object id")));
+    };
+    let rt = runtime::Runtime::new().unwrap();
+    let result = rt
+        .block_on(_get_object(object_id, SuiObjectDataOptions::full_content()))
+        .map_err(RpcError::from)
+        .and_then(|lookup| match lookup {
+            ObjectLookup::Found(data) => {
+                serde_json::to_value(data).map_err(|e| RpcError::from(anyhow::Error::from(e)))
+            }
+            ObjectLookup::Missing(status, message) => Err(RpcError {
+                code: RPC_SERVER_ERROR,
+                message,
+                data: Some(json!({ "status": status as i32 })),
+            }),
+        });
+    to_json_response(result)
+}
+
+/// One page of the objects owned by `address`, pass the previous `nextCursor` (or null for the
+/// first page) and a limit of 0 for the node default.
+#[no_mangle]
+pub extern "C" fn get_owned_objects_json(
+    address: *const c_char,
+    cursor: *const c_char,
+    limit: usize,
+) -> *const c_char {
+    let cursor = c_str(cursor);
+    let limit = (limit > 0).then_some(limit);
+    query(address, "address", |address| {
+        _get_owned_objects(
+            address,
+            None,
+            SuiObjectDataOptions::full_content(),
+            cursor,
+            limit,
+        )
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn get_dynamic_fields_json(
+    parent_object_id: *const c_char,
+    cursor: *const c_char,
+    limit: usize,
+) -> *const c_char {
+    let cursor = c_str(cursor);
+    let limit = (limit > 0).then_some(limit);
+    query(parent_object_id, "parent object id", |parent_object_id| {
+        _get_dynamic_fields(parent_object_id, cursor, limit)
+    })
+}
+
+#[no_mangle]
+pub extern "C" fn get_coins_json(address: *const c_char) -> *const c_char {
+    query(address, "address", get_coins)
+}
+
+#[no_mangle]
+pub extern "C" fn get_balance_json(address: *const c_char) -> *const c_char {
+    query(address, "address", get_balance)
+}
+
+#[no_mangle]
+pub extern "C" fn get_all_balances_json(address: *const c_char) -> *const c_char {
+    query(address, "address", get_all_balances)
+}
+
+#[no_mangle]
+pub extern "C" fn get_checkpoint_json(sequence_number: u64) -> *const c_char {
+    let rt = runtime::Runtime::new().unwrap();
+    serialize(rt.block_on(_get_checkpoint(CheckpointId::SequenceNumber(
+        sequence_number,
+    ))))
+}
+
+/// One page of checkpoints, `cursor` is the last sequence number of the previous page (or null).
+#[no_mangle]
+pub extern "C" fn get_checkpoints_json(
+    cursor: *const c_char,
+    limit: usize,
+    descending_order: bool,
+) -> *const c_char {
+    let cursor = match c_str(cursor).map(str::parse::<u64>).transpose() {
+        Ok(cursor) => cursor,
+        Err(e) => {
+            return to_json_response(Err(RpcError::new(
+                RPC_INVALID_PARAMS,
+                format!("Invalid cursor: {}", e),
+            )))
+        }
+    };
+    let limit = (limit > 0).then_some(limit);
+    let rt = runtime::Runtime::new().unwrap();
+    serialize(rt.block_on(_get_checkpoints(cursor, limit, descending_order)))
+}
+
+#[no_mangle]
+pub extern "C" fn get_checkpoint_transactions_json(sequence_number: u64) -> *const c_char {
+    let rt = runtime::Runtime::new().unwrap();
+    serialize(rt.block_on(_get_checkpoint_transactions(sequence_number)))
+}
@@ -0,0 +1,839 @@
+use anyhow::anyhow;
+use move_core_types::language_storage::{StructTag, TypeTag};
+use std::ffi::c_char;
+use std::ptr;
+use std::str::FromStr;
+use sui_json_rpc_types::{EventFilter, SuiObjectDataFilter, SuiObjectDataOptions, SuiParsedData};
+use sui_types::base_types::{ObjectID, SuiAddress};
+use sui_types::programmable_transaction_builder::ProgrammableTransactionBuilder;
+use sui_types::transaction::{Argument, Command};
+use sui_types::Identifier;
+use tokio::runtime;
+
+use crate::c_types::{c_str, free_c_char, to_c_char};
+use crate::dynamic_fields::{_get_dynamic_field, _get_dynamic_fields};
+use crate::object_read_api::{ObjectLookup, _get_all_owned_objects, _get_object};
+use crate::sui_client::SuiClientSingleton;
+use crate::transaction_builder::{_execute_transaction, _object_arg};
+
+const KIOSK_MODULE: &str = "kiosk";
+const TRANSFER_POLICY_MODULE: &str = "transfer_policy";
+
+pub struct KioskOwnerCap {
+    pub kiosk_id: String,
+    pub cap_id: String,
+}
+
+pub struct KioskItem {
+    pub object_id: String,
+    pub object_type: String,
+    pub is_listed: bool,
+    pub price: u64,
+    pub is_locked: bool,
+}
+
+pub struct KioskContents {
+    pub kiosk_id: String,
+    pub owner: String,
+    pub profits: u64,
+    pub items: Vec<KioskItem>,
+}
+
+/// u64 values are rendered as strings in Move JSON, small integers as numbers.
+fn json_u64(value: &serde_json::Value) -> Option<u64> {
+    match value {
+        serde_json::Value::Number(number) => number.as_u64(),
+        serde_json::Value::String(string) => string.parse().ok(),
+        serde_json::Value::Object(object) => object.get("value").and_then(json_u64),
+        _ => None,
+    }
+}
+
+async fn object_fields(object_id: &str) -> Result<(String, serde_json::Value), anyhow::Error> {
+    let options = SuiObjectDataOptions::new().with_type().with_content();
+    let data = match _get_object(object_id, options).await? {
+        ObjectLookup::Found(data) => data,
+        ObjectLookup::Missing(_, error) => return Err(anyhow!(error)),
+    };
+    let object_type = data.type_.map(|t| t.to_string()).unwrap_or_default();
+    match data.content {
+        Some(SuiParsedData::MoveObject(object)) => {
+            Ok((object_type, object.fields.to_json_value()))
+        }
+        _ => Err(anyhow!("Object {} has no Move content", object_id)),
+    }
+}
+
+fn kiosk_call(
+    ptb: &mut ProgrammableTransactionBuilder,
+    module: &str,
+    function: &str,
+    type_arguments: Vec<TypeTag>,
+    arguments: Vec<Argument>,
+) -> Result<Argument, anyhow::Error> {
+    Ok(ptb.programmable_move_call(
+        ObjectID::from_single_byte(2),
+        Identifier::new(module)?,
+        Identifier::new(function)?,
+        type_arguments,
+        arguments,
+    ))
+}
+
+async fn item_type(item_id: &str) -> Result<TypeTag, anyhow::Error> {
+    let (object_type, _) = object_fields(item_id).await?;
+    Ok(TypeTag::from_str(&object_type)?)
+}
+
+/// The `TransferPolicy<T>` of a type, found through its `TransferPolicyCreated<T>` events.
+///
+/// Events are read newest first and a policy only counts while the object still exists as a
+/// `TransferPolicy<T>`, a policy can be destroyed once its creator no longer needs it.
+pub async fn _find_transfer_policy(item_type: &TypeTag) -> Result<String, anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    let policy_type = StructTag::from_str(&format!(
+        "0x2::transfer_policy::TransferPolicy<{}>",
+        item_type
+    ))?;
+    let event_type = StructTag::from_str(&format!(
+        "0x2::transfer_policy::TransferPolicyCreated<{}>",
+        item_type
+    ))?;
+    let mut cursor = None;
+    loop {
+        let events = sui_client
+            .event_api()
+            .query_events(
+                EventFilter::MoveEventType(event_type.clone()),
+                cursor,
+                None,
+                true,
+            )
+            .await?;
+        for policy_id in events
+            .data
+            .iter()
+            .filter_map(|event| event.parsed_json["id"].as_str())
+        {
+            let options = SuiObjectDataOptions::new().with_type();
+            if let ObjectLookup::Found(data) = _get_object(policy_id, options).await? {
+                let object_type = data.type_.map(|t| t.to_string()).unwrap_or_default();
+                if StructTag::from_str(&object_type).ok().as_ref() == Some(&policy_type) {
+                    return Ok(policy_id.to_string());
+                }
+            }
+        }
+        if !events.has_next_page {
+            return Err(anyhow!("No TransferPolicy for {}", item_type));
+        }
+        cursor = events.next_cursor;
+    }
+}
+
+/// Build and execute a transaction against a kiosk with the owner cap.
+async fn execute_kiosk_transaction(
+    sender: &str,
+    gas_budget: u64,
+    build: impl FnOnce(&mut ProgrammableTransactionBuilder) -> Result<(), anyhow::Error>,
+) -> Result<String, anyhow::Error> {
+    let mut ptb = ProgrammableTransactionBuilder::new();
+    build(&mut ptb)?;
+    let response = _execute_transaction(sender, ptb.finish(), gas_budget).await?;
+    Ok(response.digest.to_string())
+}
+
+pub async fn _create_kiosk(sender: &str, gas_budget: u64) -> Result<String, anyhow::Error> {
+    execute_kiosk_transaction(sender, gas_budget, |ptb| {
+        kiosk_call(ptb, KIOSK_MODULE, "default", vec![], vec![])?;
+        Ok(())
+    })
+    .await
+}
+
+/// Place an owned item in the kiosk, `lock` locks it with the item's TransferPolicy.
+pub async fn _place_in_kiosk(
+    sender: &str,
+    kiosk_id: &str,
+    cap_id: &str,
+    item_id: &str,
+    lock: bool,
+    gas_budget: u64,
+) -> Result<String, anyhow::Error> {
+    let item_type = item_type(item_id).await?;
+    let kiosk = _object_arg(kiosk_id, true).await?;
+    let cap = _object_arg(cap_id, false).await?;
+    let item = _object_arg(item_id, true).await?;
+    let policy = if lock {
+        Some(_object_arg(&_find_transfer_policy(&item_type).await?, false).await?)
+    } else {
+        None
+    };
+    execute_kiosk_transaction(sender, gas_budget, |ptb| {
+        let kiosk = ptb.obj(kiosk)?;
+        let cap = ptb.obj(cap)?;
+        let item = ptb.obj(item)?;
+        match policy {
+            Some(policy) => {
+                let policy = ptb.obj(policy)?;
+                kiosk_call(ptb, KIOSK_MODULE, "lock", vec![item_type], vec![kiosk, cap, policy, item])?
+            }
+            None => kiosk_call(ptb, KIOSK_MODULE, "place", vec![item_type], vec![kiosk, cap, item])?,
+        };
+        Ok(())
+    })
+    .await
+}
+
+/// List an item of the kiosk for `price` MIST.
+pub async fn _list_in_kiosk(
+    sender: &str,
+    kiosk_id: &str,
+    cap_id: &str,
+    item_id: &str,
+    price: u64,
+    gas_budget: u64,
+) -> Result<String, anyhow::Error> {
+    let item_type = item_type(item_id).await?;
+    let item_id = ObjectID::from_str(item_id)?;
+    let kiosk = _object_arg(kiosk_id, true).await?;
+    let cap = _object_arg(cap_id, false).await?;
+    execute_kiosk_transaction(sender, gas_budget, |ptb| {
+        let arguments = vec![ptb.obj(kiosk)?, ptb.obj(cap)?, ptb.pure(item_id)?, ptb.pure(price)?];
+        kiosk_call(ptb, KIOSK_MODULE, "list", vec![item_type], arguments)?;
+        Ok(())
+    })
+    .await
+}
+
+pub async fn _delist_from_kiosk(
+    sender: &str,
+    kiosk_id: &str,
+    cap_id: &str,
+    item_id: &str,
+    gas_budget: u64,
+) -> Result<String, anyhow::Error> {
+    let item_type = item_type(item_id).await?;
+    let item_id = ObjectID::from_str(item_id)?;
+    let kiosk = _object_arg(kiosk_id, true).await?;
+    let cap = _object_arg(cap_id, false).await?;
+    execute_kiosk_transaction(sender, gas_budget, |ptb| {
+        let arguments = vec![ptb.obj(kiosk)?, ptb.obj(cap)?, ptb.pure(item_id)?];
+        kiosk_call(ptb, KIOSK_MODULE, "delist", vec![item_type], arguments)?;
+        Ok(())
+    })
+    .await
+}
+
+/// Withdraw the kiosk profits to the sender, `amount` of None withdraws everything.
+pub async fn _withdraw_kiosk_profits(
+    sender: &str,
+    kiosk_id: &str,
+    cap_id: &str,
+    amount: Option<u64>,
+    gas_budget: u64,
+) -> Result<String, anyhow::Error> {
+    let sender_address = SuiAddress::from_str(sender)?;
+    let kiosk = _object_arg(kiosk_id, true).await?;
+    let cap = _object_arg(cap_id, false).await?;
+    execute_kiosk_transaction(sender, gas_budget, |ptb| {
+        let arguments = vec![ptb.obj(kiosk)?, ptb.obj(cap)?, ptb.pure(amount)?];
+        let coin = kiosk_call(ptb, KIOSK_MODULE, "withdraw", vec![], arguments)?;
+        let recipient = ptb.pure(sender_address)?;
+        ptb.command(Command::TransferObjects(vec![coin], recipient));
+        Ok(())
+    })
+    .await
+}
+
+/// Price of a listed item, read from the `Listing` dynamic field of the kiosk.
+async fn listing_price(kiosk_id: &str, item_id: &ObjectID) -> Result<u64, anyhow::Error> {
+    // Listing { id: ID, is_exclusive: bool }
+    let mut name = item_id.to_vec();
+    name.push(0);
+    let listing = _get_dynamic_field(kiosk_id, "0x2::kiosk::Listing", &name)
+        .await
+        .map_err(|_| anyhow!("Item {} is not listed in kiosk {}", item_id, kiosk_id))?;
+    json_u64(&listing.content).ok_or_else(|| anyhow!("Invalid listing price for {}", item_id))
+}
+
+/// Rule type names of a TransferPolicy, e.g. `<package>::royalty_rule::Rule`.
+async fn transfer_policy_rules(policy_id: &str) -> Result<Vec<String>, anyhow::Error> {
+    let (_, fields) = object_fields(policy_id).await?;
+    Ok(fields["rules"]["contents"]
+        .as_array()
+        .map(|rules| {
+            rules
+                .iter()
+                .filter_map(|rule| rule.as_str().or_else(|| rule["name"].as_str()))
+                .map(|rule| rule.to_string())
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+/// Royalty owed on a purchase, as computed by `royalty_rule::fee_amount`.
+async fn royalty_fee(policy_id: &str, rule: &str, price: u64) -> Result<u64, anyhow::Error> {
+    // RuleKey<Rule> only holds a dummy bool field
+    let config = _get_dynamic_field(
+        policy_id,
+        &format!(
+            "0x2::transfer_policy::RuleKey<0x{}>",
+            rule.trim_start_matches("0x")
+        ),
+        &[0],
+    )
+    .await?;
+    let amount_bp = json_u64(&config.content["amount_bp"])
+        .ok_or_else(|| anyhow!("Invalid royalty configuration"))?;
+    let min_amount = json_u64(&config.content["min_amount"]).unwrap_or_default();
+    let fee = (price as u128 * amount_bp as u128 / 10_000) as u64;
+    Ok(fee.max(min_amount))
+}
+
+/// Package of the `personal_kiosk` module when `cap_type` is a `PersonalKioskCap`.
+fn personal_kiosk_package(cap_type: &str) -> Result<Option<ObjectID>, anyhow::Error> {
+    let tag = StructTag::from_str(cap_type)?;
+    let is_personal_cap =
+        tag.module.as_str() == "personal_kiosk" && tag.name.as_str() == "PersonalKioskCap";
+    Ok(is_personal_cap.then(|| ObjectID::from(tag.address)))
+}
+
+/// Buy a listed item, paying the listing price and every rule of the item's TransferPolicy.
+///
+/// Royalty and floor price rules are settled in the same transaction. Items under a kiosk
+/// lock or personal kiosk rule need `buyer_kiosk` (kiosk id, owner cap id) and end up locked
+/// there, otherwise the item is placed in `buyer_kiosk` when given or sent to the sender.
+/// The owner cap of a personal kiosk is its `PersonalKioskCap`, the `KioskOwnerCap` inside is
+/// borrowed for the lock or place and returned in the same transaction.
+pub async fn _purchase_from_kiosk(
+    sender: &str,
+    kiosk_id: &str,
+    item_id: &str,
+    buyer_kiosk: Option<(&str, &str)>,
+    gas_budget: u64,
+) -> Result<String, anyhow::Error> {
+    let sender_address = SuiAddress::from_str(sender)?;
+    let item_type = item_type(item_id).await?;
+    let item_object_id = ObjectID::from_str(item_id)?;
+    let price = listing_price(kiosk_id, &item_object_id).await?;
+    let policy_id = _find_transfer_policy(&item_type).await?;
+
+    // Resolve every rule before building the transaction
+    enum Rule {
+        Royalty(ObjectID, u64),
+        KioskLock(ObjectID),
+        PersonalKiosk(ObjectID),
+        FloorPrice(ObjectID),
+    }
+    let mut rules = Vec::new();
+    for rule in transfer_policy_rules(&policy_id).await? {
+        let package = ObjectID::from_str(rule.split("::").next().unwrap_or_default())?;
+        match rule.split("::").nth(1) {
+            Some("royalty_rule") => {
+                rules.push(Rule::Royalty(package, royalty_fee(&policy_id, &rule, price).await?))
+            }
+            Some("kiosk_lock_rule") => rules.push(Rule::KioskLock(package)),
+            Some("personal_kiosk_rule") => rules.push(Rule::PersonalKiosk(package)),
+            Some("floor_price_rule") => rules.push(Rule::FloorPrice(package)),
+            _ => return Err(anyhow!("Unsupported transfer policy rule {}", rule)),
+        }
+    }
+    let must_lock = rules
+        .iter()
+        .any(|rule| matches!(rule, Rule::KioskLock(_) | Rule::PersonalKiosk(_)));
+    if must_lock && buyer_kiosk.is_none() {
+        return Err(anyhow!(
+            "The TransferPolicy of {} requires a buyer kiosk",
+            item_type
+        ));
+    }
+
+    let kiosk = _object_arg(kiosk_id, true).await?;
+    let policy = _object_arg(&policy_id, true).await?;
+    let buyer_kiosk = match buyer_kiosk {
+        Some((buyer_kiosk_id, buyer_cap_id)) => {
+            let (cap_type, _) = object_fields(buyer_cap_id).await?;
+            Some((
+                _object_arg(buyer_kiosk_id, true).await?,
+                _object_arg(buyer_cap_id, true).await?,
+                personal_kiosk_package(&cap_type)?,
+            ))
+        }
+        None => None,
+    };
+    let needs_personal = rules
+        .iter()
+        .any(|rule| matches!(rule, Rule::PersonalKiosk(_)));
+    if needs_personal && !matches!(buyer_kiosk, Some((_, _, Some(_)))) {
+        return Err(anyhow!(
+            "The TransferPolicy of {} requires a personal buyer kiosk",
+            item_type
+        ));
+    }
+
+    execute_kiosk_transaction(sender, gas_budget, |ptb| {
+        let kiosk = ptb.obj(kiosk)?;
+        let policy = ptb.obj(policy)?;
+        let item_id = ptb.pure(item_object_id)?;
+        let price = ptb.pure(price)?;
+        let payment = ptb.command(Command::SplitCoins(Argument::GasCoin, vec![price]));
+        let Argument::Result(purchase) = kiosk_call(
+            ptb,
+            KIOSK_MODULE,
+            "purchase",
+            vec![item_type.clone()],
+            vec![kiosk, item_id, payment],
+        )?
+        else {
+            unreachable!("move calls return Argument::Result");
+        };
+        let item = Argument::NestedResult(purchase, 0);
+        let request = Argument::NestedResult(purchase, 1);
+        let buyer_kiosk = match buyer_kiosk {
+            Some((buyer_kiosk, buyer_cap, personal)) => {
+                Some((ptb.obj(buyer_kiosk)?, ptb.obj(buyer_cap)?, personal))
+            }
+            None => None,
+        };
+
+        // The item has to be locked before the kiosk rules can prove it
+        match buyer_kiosk {
+            Some((buyer_kiosk, buyer_cap, personal)) => {
+                let (owner_cap, borrow) = match personal {
+                    Some(package) => {
+                        let Argument::Result(borrowed) = ptb.programmable_move_call(
+                            package,
+                            Identifier::new("personal_kiosk")?,
+                            Identifier::new("borrow_val")?,
+                            vec![],
+                            vec![buyer_cap],
+                        ) else {
+                            unreachable!("move calls return Argument::Result");
+                        };
+                        (
+                            Argument::NestedResult(borrowed, 0),
+                            Some((package, Argument::NestedResult(borrowed, 1))),
+                        )
+                    }
+                    None => (buyer_cap, None),
+                };
+                if must_lock {
+                    kiosk_call(
+                        ptb,
+                        KIOSK_MODULE,
+                        "lock",
+                        vec![item_type.clone()],
+                        vec![buyer_kiosk, owner_cap, policy, item],
+                    )?;
+                } else {
+                    kiosk_call(
+                        ptb,
+                        KIOSK_MODULE,
+                        "place",
+                        vec![item_type.clone()],
+                        vec![buyer_kiosk, owner_cap, item],
+                    )?;
+                }
+                if let Some((package, borrow)) = borrow {
+                    ptb.programmable_move_call(
+                        package,
+                        Identifier::new("personal_kiosk")?,
+                        Identifier::new("return_val")?,
+                        vec![],
+                        vec![buyer_cap, owner_cap, borrow],
+                    );
+                }
+            }
+            None => {
+                let recipient = ptb.pure(sender_address)?;
+                ptb.command(Command::TransferObjects(vec![item], recipient));
+            }
+        }
+
+        for rule in rules {
+            let (package, module, function, arguments) = match rule {
+                Rule::Royalty(package, fee) => {
+                    let fee = ptb.pure(fee)?;
+                    let fee = ptb.command(Command::SplitCoins(Argument::GasCoin, vec![fee]));
+                    (package, "royalty_rule", "pay", vec![policy, request, fee])
+                }
+                Rule::KioskLock(package) => {
+                    let (buyer_kiosk, _, _) = buyer_kiosk.unwrap();
+                    (package, "kiosk_lock_rule", "prove", vec![request, buyer_kiosk])
+                }
+                Rule::PersonalKiosk(package) => {
+                    let (buyer_kiosk, _, _) = buyer_kiosk.unwrap();
+                    (package, "personal_kiosk_rule", "prove", vec![buyer_kiosk, request])
+                }
+                Rule::FloorPrice(package) => {
+                    (package, "floor_price_rule", "prove", vec![policy, request])
+                }
+            };
+            ptb.programmable_move_call(
+                package,
+                Identifier::new(module)?,
+                Identifier::new(function)?,
+                vec![item_type.clone()],
+                arguments,
+            );
+        }
+        kiosk_call(
+            ptb,
+            TRANSFER_POLICY_MODULE,
+            "confirm_request",
+            vec![item_type.clone()],
+            vec![policy, request],
+        )?;
+        Ok(())
+    })
+    .await
+}
+
+/// Items of a kiosk with their listing and lock state.
+pub async fn _get_kiosk_contents(kiosk_id: &str) -> Result<KioskContents, anyhow::Error> {
+    let (_, fields) = object_fields(kiosk_id).await?;
+    let item_tag = TypeTag::from_str("0x2::kiosk::Item")?;
+    let listing_tag = TypeTag::from_str("0x2::kiosk::Listing")?;
+    let lock_tag = TypeTag::from_str("0x2::kiosk::Lock")?;
+
+    let mut items = Vec::new();
+    let mut listings = Vec::new();
+    let mut locks = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let page = _get_dynamic_fields(kiosk_id, cursor.as_deref(), None).await?;
+        for field in page.data {
+            let id = field.name.value["id"].as_str().unwrap_or_default().to_string();
+            if field.name.type_ == item_tag {
+                items.push(KioskItem {
+                    object_id: id,
+                    object_type: field.object_type,
+                    is_listed: false,
+                    price: 0,
+                    is_locked: false,
+                });
+            } else if field.name.type_ == listing_tag {
+                listings.push((id, field.object_id.to_string()));
+            } else if field.name.type_ == lock_tag {
+                locks.push(id);
+            }
+        }
+        match page.next_cursor {
+            Some(next_cursor) if page.has_next_page => cursor = Some(next_cursor.to_string()),
+            _ => break,
+        }
+    }
+
+    for (id, listing_field_id) in listings {
+        let (_, listing) = object_fields(&listing_field_id).await?;
+        if let Some(item) = items.iter_mut().find(|item| item.object_id == id) {
+            item.is_listed = true;
+            item.price = json_u64(&listing["value"]).unwrap_or_default();
+        }
+    }
+    for id in locks {
+        if let Some(item) = items.iter_mut().find(|item| item.object_id == id) {
+            item.is_locked = true;
+        }
+    }
+
+    Ok(KioskContents {
+        kiosk_id: kiosk_id.to_string(),
+        owner: fields["owner"].as_str().unwrap_or_default().to_string(),
+        profits: json_u64(&fields["profits"]).unwrap_or_default(),
+        items,
+    })
+}
+
+/// Kiosks the address can manage, one entry per `KioskOwnerCap`.
+pub async fn _get_owned_kiosks(address: &str) -> Result<Vec<KioskOwnerCap>, anyhow::Error> {
+    let filter =
+        SuiObjectDataFilter::StructType(StructTag::from_str("0x2::kiosk::KioskOwnerCap")?);
+    let caps = _get_all_owned_objects(
+        address,
+        Some(filter),
+        SuiObjectDataOptions::new().with_content(),
+    )
+    .await?;
+    Ok(caps
+        .into_iter()
+        .filter_map(|cap| {
+            let fields = match cap.content {
+                Some(SuiParsedData::MoveObject(object)) => object.fields.to_json_value(),
+                _ => return None,
+            };
+            Some(KioskOwnerCap {
+                kiosk_id: fields["for"].as_str()?.to_string(),
+                cap_id: cap.object_id.to_string(),
+            })
+        })
+        .collect())
+}
+
+//public functions for ffi
+
+#[repr(C)]
+pub struct CKioskOwnerCap {
+    kiosk_id: *mut c_char,
+    cap_id: *mut c_char,
+}
+
+#[repr(C)]
+pub struct CKioskOwnerCapArray {
+    caps: *mut CKioskOwnerCap,
+    len: usize,
+    error: *mut c_char,
+}
+
+#[repr(C)]
+pub struct CKioskItem {
+    object_id: *mut c_char,
+    object_type: *mut c_char,
+    is_listed: bool,
+    price: u64,
+    is_locked: bool,
+}
+
+#[repr(C)]
+pub struct CKioskContents {
+    kiosk_id: *mut c_char,
+    owner: *mut c_char,
+    profits: u64,
+    items: *mut CKioskItem,
+    len: usize,
+    error: *mut c_char,
+}
+
+fn transaction_message(result: Result<String, anyhow::Error>, action: &str) -> *const c_char {
+    let message = match result {
+        Ok(digest) => format!("{} success. Transaction digest: {}", action, digest),
+        Err(e) => format!("Error: {}", e),
+    };
+    to_c_char(message)
+}
+
+#[no_mangle]
+pub extern "C" fn create_kiosk(sender: *const c_char, gas_budget: u64) -> *const c_char {
+    // Create a new runtime. This step might vary based on the async runtime you are using.
+    let rt = runtime::Runtime::new().unwrap();
+    transaction_message(
+        rt.block_on(_create_kiosk(c_str(sender).unwrap_or_default(), gas_budget)),
+        "Create kiosk",
+    )
+}
+
+// Place an owned item in the kiosk, `lock` locks it under the TransferPolicy of its type
+#[no_mangle]
+pub extern "C" fn kiosk_place(
+    sender: *const c_char,
+    kiosk_id: *const c_char,
+    cap_id: *const c_char,
+    item_id: *const c_char,
+    lock: bool,
+    gas_budget: u64,
+) -> *const c_char {
+    let rt = runtime::Runtime::new().unwrap();
+    transaction_message(
+        rt.block_on(_place_in_kiosk(
+            c_str(sender).unwrap_or_default(),
+            c_str(kiosk_id).unwrap_or_default(),
+            c_str(cap_id).unwrap_or_default(),
+            c_str(item_id).unwrap_or_default(),
+            lock,
+            gas_budget,
+        )),
+        if lock { "Lock item" } else { "Place item" },
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn kiosk_list(
+    sender: *const c_char,
+    kiosk_id: *const c_char,
+    cap_id: *const c_char,
+    item_id: *const c_char,
+    price: u64,
+    gas_budget: u64,
+) -> *const c_char {
+    let rt = runtime::Runtime::new().unwrap();
+    transaction_message(
+        rt.block_on(_list_in_kiosk(
+            c_str(sender).unwrap_or_default(),
+            c_str(kiosk_id).unwrap_or_default(),
+            c_str(cap_id).unwrap_or_default(),
+            c_str(item_id).unwrap_or_default(),
+            price,
+            gas_budget,
+        )),
+        "List item",
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn kiosk_delist(
+    sender: *const c_char,
+    kiosk_id: *const c_char,
+    cap_id: *const c_char,
+    item_id: *const c_char,
+    gas_budget: u64,
+) -> *const c_char {
+    let rt = runtime::Runtime::new().unwrap();
+    transaction_message(
+        rt.block_on(_delist_from_kiosk(
+            c_str(sender).unwrap_or_default(),
+            c_str(kiosk_id).unwrap_or_default(),
+            c_str(cap_id).unwrap_or_default(),
+            c_str(item_id).unwrap_or_default(),
+            gas_budget,
+        )),
+        "Delist item",
+    )
+}
+
+// Buy a listed item. `buyer_kiosk_id`/`buyer_cap_id` may be null, they are required when the
+// TransferPolicy has a kiosk lock or personal kiosk rule
+#[no_mangle]
+pub extern "C" fn kiosk_purchase(
+    sender: *const c_char,
+    kiosk_id: *const c_char,
+    item_id: *const c_char,
+    buyer_kiosk_id: *const c_char,
+    buyer_cap_id: *const c_char,
+    gas_budget: u64,
+) -> *const c_char {
+    let buyer_kiosk = if buyer_kiosk_id.is_null() || buyer_cap_id.is_null() {
+        None
+    } else {
+        Some((
+            c_str(buyer_kiosk_id).unwrap_or_default(),
+            c_str(buyer_cap_id).unwrap_or_default(),
+        ))
+    };
+    let rt = runtime::Runtime::new().unwrap();
+    transaction_message(
+        rt.block_on(_purchase_from_kiosk(
+            c_str(sender).unwrap_or_default(),
+            c_str(kiosk_id).unwrap_or_default(),
+            c_str(item_id).unwrap_or_default(),
+            buyer_kiosk,
+            gas_budget,
+        )),
+        "Purchase item",
+    )
+}
+
+// Withdraw `amount` MIST of profits to the sender, 0 withdraws everything
+#[no_mangle]
+pub extern "C" fn kiosk_withdraw_profits(
+    sender: *const c_char,
+    kiosk_id: *const c_char,
+    cap_id: *const c_char,
+    amount: u64,
+    gas_budget: u64,
+) -> *const c_char {
+    let rt = runtime::Runtime::new().unwrap();
+    transaction_message(
+        rt.block_on(_withdraw_kiosk_profits(
+            c_str(sender).unwrap_or_default(),
+            c_str(kiosk_id).unwrap_or_default(),
+            c_str(cap_id).unwrap_or_default(),
+            (amount > 0).then_some(amount),
+            gas_budget,
+        )),
+        "Withdraw profits",
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn get_kiosk_contents(kiosk_id: *const c_char) -> CKioskContents {
+    let rt = runtime::Runtime::new().unwrap();
+    match rt.block_on(_get_kiosk_contents(c_str(kiosk_id).unwrap_or_default())) {
+        Ok(contents) => {
+            let items: Box<[CKioskItem]> = contents
+                .items
+                .into_iter()
+                .map(|item| CKioskItem {
+                    object_id: to_c_char(item.object_id),
+                    object_type: to_c_char(item.object_type),
+                    is_listed: item.is_listed,
+                    price: item.price,
+                    is_locked: item.is_locked,
+                })
+                .collect();
+            let len = items.len();
+            CKioskContents {
+                kiosk_id: to_c_char(contents.kiosk_id),
+                owner: to_c_char(contents.owner),
+                profits: contents.profits,
+                items: Box::into_raw(items) as *mut CKioskItem,
+                len,
+                error: ptr::null_mut(),
+            }
+        }
+        Err(e) => CKioskContents {
+            kiosk_id: ptr::null_mut(),
+            owner: ptr::null_mut(),
+            profits: 0,
+            items: ptr::null_mut(),
+            len: 0,
+            error: to_c_char(e.to_string()),
+        },
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn free_kiosk_contents(contents: CKioskContents) {
+    if !contents.items.is_null() {
+        unsafe {
+            let items = Box::from_raw(std::slice::from_raw_parts_mut(contents.items, contents.len));
+            for item in items.iter() {
+                free_c_char(item.object_id);
+                free_c_char(item.object_type);
+            }
+        }
+    }
+    free_c_char(contents.kiosk_id);
+    free_c_char(contents.owner);
+    free_c_char(contents.error);
+}
+
+#[no_mangle]
+pub extern "C" fn get_owned_kiosks(address: *const c_char) -> CKioskOwnerCapArray {
+    let rt = runtime::Runtime::new().unwrap();
+    match rt.block_on(_get_owned_kiosks(c_str(address).unwrap_or_default())) {
+        Ok(caps) => {
+            let caps: Box<[CKioskOwnerCap]> = caps
+                .into_iter()
+                .map(|cap| CKioskOwnerCap {
+                    kiosk_id: to_c_char(cap.kiosk_id),
+                    cap_id: to_c_char(cap.cap_id),
+                })
+                .collect();
+            let len = caps.len();
+            CKioskOwnerCapArray {
+                caps: Box::into_raw(caps) as *mut CKioskOwnerCap,
+                len,
+                error: ptr::null_mut(),
+            }
+        }
+        Err(e) => CKioskOwnerCapArray {
+            caps: ptr::null_mut(),
+            len: 0,
+            error: to_c_char(e.to_string()),
+        },
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn free_kiosk_owner_cap_array(array: CKioskOwnerCapArray) {
+    if !array.caps.is_null() {
+        unsafe {
+            let caps = Box::from_raw(std::slice::from_raw_parts_mut(array.caps, array.len));
+            for cap in caps.iter() {
+                free_c_char(cap.kiosk_id);
+                free_c_char(cap.cap_id);
+            }
+        }
+    }
+    free_c_char(array.error);
+}
@@ -1,11 +1,19 @@
-use crate::c_types::{self, CStringArray, CU8Array};
+use crate::c_types::{self, CStringArray, CU8Array, SuiStatus};
+use crate::name_service::resolve_address;
+use crate::signer::sign_intent_message;
 use crate::sui_client::SuiClientSingleton;
+use anyhow::anyhow;
 use std::result::Result::Ok;
 use shared_crypto::intent::{Intent, IntentMessage};
 use tokio::runtime;
 use core::slice;
-use std::ffi::{c_uint, CStr, CString};
-use std::{ffi::c_char, path::PathBuf};
+use fastcrypto::encoding::{Base64, Encoding};
+use fastcrypto::traits::{EncodeDecodeBase64, ToFromBytes};
+use fastcrypto_zkp::zk_login_utils::Bn254FrElement;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::ffi::{CStr, CString};
+use std::{ffi::c_char, path::PathBuf, ptr};
 use std::str::FromStr;
 use sui_keys::keystore::{AccountKeystore, FileBasedKeystore, Keystore};
 use sui_sdk::{
@@ -16,10 +24,11 @@ use sui_sdk::{
     },
 };
 use sui_types::base_types::SuiAddress;
-use sui_types::crypto::PublicKey;
+use sui_types::crypto::{PublicKey, SignatureScheme, ZkLoginPublicIdentifier};
 use sui_types::crypto::Signature;
-use sui_types::multisig::{MultiSig, MultiSigPublicKey, WeightUnit};
-use sui_types::signature::GenericSignature;
+use sui_types::multisig::{MultiSig, MultiSigPublicKey, ThresholdUnit, WeightUnit};
+use sui_types::signature::{AuthenticatorTrait, GenericSignature, VerifyParams};
+use sui_types::signature_verification::VerifiedDigestCache;
 use sui_types::transaction::{Argument, Command, Transaction};
 
 pub fn default_keystore_path() -> PathBuf {
@@ -45,7 +54,8 @@ pub async fn get_or_create_multisig_public_key(
             weights[index],
         ))
     }
-    Ok(MultiSigPublicKey::insecure_new(pk_map, threshold))
+    let (pks, weights) = pk_map.into_iter().unzip();
+    new_multisig_public_key(pks, weights, threshold)
 }
 
 pub async fn get_or_create_multisig_public_key_serialize(
@@ -76,7 +86,7 @@ pub async fn create_sui_transaction(
 ) -> Result<TransactionData, anyhow::Error> {
     let multisig_addr = SuiAddress::from_str(multisig_addr)?;
     let sui_client = SuiClientSingleton::instance().get_or_init().await?;
-    let recipient = SuiAddress::from_str(recipient_address)?;
+    let recipient = resolve_address(recipient_address).await?;
 
     let mut ptb = ProgrammableTransactionBuilder::new();
     // 2) split coin
@@ -109,41 +119,248 @@ pub async fn create_sui_transaction(
     ))
 }
 
+/// A multisig signing session for one transaction.
+///
+/// Partial signatures can be added from any member in any order, they are
+/// kept by member index so the combined `MultiSig` always has the bitmap and
+/// signature order expected by the validators.
+pub struct CMultiSigSession {
+    multisig_pk: MultiSigPublicKey,
+    tx_data: TransactionData,
+    signatures: BTreeMap<u8, GenericSignature>,
+}
+
+impl CMultiSigSession {
+    pub fn new(multisig_pk: MultiSigPublicKey, tx_data: TransactionData) -> Self {
+        Self {
+            multisig_pk,
+            tx_data,
+            signatures: BTreeMap::new(),
+        }
+    }
+
+    pub fn intent_message(&self) -> IntentMessage<TransactionData> {
+        IntentMessage::new(Intent::sui_transaction(), self.tx_data.clone())
+    }
+
+    /// Add a partial signature and return the index of the member that produced it.
+    pub fn add_signature(&mut self, signature: GenericSignature) -> Result<u8, anyhow::Error> {
+        let public_key = signature
+            .to_public_key()
+            .map_err(|e| anyhow!("Cannot read public key from signature: {}", e))?;
+        let index = self
+            .multisig_pk
+            .get_index(&public_key)
+            .ok_or_else(|| anyhow!("Signer is not a member of the multisig"))?;
+        if self.signatures.contains_key(&index) {
+            return Err(anyhow!("Member {} has already signed", index));
+        }
+        self.signatures.insert(index, signature);
+        Ok(index)
+    }
+
+    /// Sign with a key from the local keystore, for members that live on this machine.
+    pub fn sign_with_keystore(&mut self, address: &str) -> Result<u8, anyhow::Error> {
+        let keystore = Keystore::from(FileBasedKeystore::new(&default_keystore_path())?);
+        let signature = Signature::new_secure(
+            &self.intent_message(),
+            keystore.get_key(&SuiAddress::from_str(address)?)?,
+        );
+        self.add_signature(GenericSignature::Signature(signature))
+    }
+
+    /// Sign with the active signer (keystore, registered callback or local signer).
+    pub fn sign_with_signer(&mut self, address: &str) -> Result<u8, anyhow::Error> {
+        let signature =
+            sign_intent_message(&SuiAddress::from_str(address)?, &self.intent_message())?;
+        self.add_signature(GenericSignature::Signature(signature))
+    }
+
+    pub fn bitmap(&self) -> u16 {
+        self.signatures
+            .keys()
+            .fold(0, |bitmap, index| bitmap | (1 << index))
+    }
+
+    pub fn weight(&self) -> u16 {
+        let pubkeys = self.multisig_pk.pubkeys();
+        self.signatures
+            .keys()
+            .map(|index| pubkeys[*index as usize].1 as u16)
+            .sum()
+    }
+
+    pub fn threshold(&self) -> ThresholdUnit {
+        *self.multisig_pk.threshold()
+    }
+
+    pub fn is_threshold_met(&self) -> bool {
+        self.weight() >= self.threshold()
+    }
+
+    pub fn combine(&self) -> Result<MultiSig, anyhow::Error> {
+        if !self.is_threshold_met() {
+            return Err(anyhow!(
+                "Signed weight {} is below the threshold {}",
+                self.weight(),
+                self.threshold()
+            ));
+        }
+        combine_partial_signatures(
+            self.signatures.values().cloned().collect(),
+            &self.multisig_pk,
+        )
+    }
+
+    pub async fn execute(&self) -> Result<String, anyhow::Error> {
+        let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+        let multisig = GenericSignature::MultiSig(self.combine()?);
+        let tx = Transaction::from_generic_sig_data(self.tx_data.clone(), vec![multisig]);
+        let transaction_response = sui_client
+            .quorum_driver_api()
+            .execute_transaction_block(tx, SuiTransactionBlockResponseOptions::default(), None)
+            .await?;
+        Ok(transaction_response.digest.base58_encode())
+    }
+}
+
+/// Parse a multisig member key.
+///
+/// With an empty scheme the key is expected in the Sui `flag || public key` base64 form,
+/// otherwise it is the raw base64 public key of the given scheme.
+pub fn parse_member_public_key(public_key: &str, scheme: &str) -> Result<PublicKey, anyhow::Error> {
+    let scheme = match scheme.to_lowercase().as_str() {
+        "" => {
+            return PublicKey::decode_base64(public_key)
+                .map_err(|e| anyhow!("Invalid public key {}: {}", public_key, e))
+        }
+        "ed25519" => SignatureScheme::ED25519,
+        "secp256k1" => SignatureScheme::Secp256k1,
+        "secp256r1" => SignatureScheme::Secp256r1,
+        "zklogin" | "zkloginauthenticator" => SignatureScheme::ZkLoginAuthenticator,
+        other => return Err(anyhow!("Unsupported multisig member scheme: {}", other)),
+    };
+    let bytes = Base64::decode(public_key)
+        .map_err(|e| anyhow!("Invalid base64 public key {}: {}", public_key, e))?;
+    PublicKey::try_from_bytes(scheme, &bytes)
+        .map_err(|e| anyhow!("Invalid {} public key {}: {}", scheme, public_key, e))
+}
+
+/// Build the public key of a zkLogin multisig member from its issuer and address seed.
+pub fn zklogin_member_public_key(iss: &str, address_seed: &str) -> Result<PublicKey, anyhow::Error> {
+    let address_seed = Bn254FrElement::from_str(address_seed)
+        .map_err(|e| anyhow!("Invalid address seed: {}", e))?;
+    Ok(PublicKey::ZkLogin(
+        ZkLoginPublicIdentifier::new(iss, &address_seed).map_err(|e| anyhow!(e))?,
+    ))
+}
+
+pub fn new_multisig_public_key(
+    pks: Vec<PublicKey>,
+    weights: Vec<WeightUnit>,
+    threshold: ThresholdUnit,
+) -> Result<MultiSigPublicKey, anyhow::Error> {
+    if pks.len() != weights.len() {
+        return Err(anyhow!(
+            "Got {} public keys but {} weights",
+            pks.len(),
+            weights.len()
+        ));
+    }
+    if threshold == 0 {
+        return Err(anyhow!("Threshold must be greater than 0"));
+    }
+    if weights.iter().any(|weight| *weight == 0) {
+        return Err(anyhow!("Member weights must be greater than 0"));
+    }
+    let total_weight: u16 = weights.iter().map(|weight| *weight as u16).sum();
+    if total_weight < threshold {
+        return Err(anyhow!(
+            "Threshold {} is unreachable, total weight is {}",
+            threshold,
+            total_weight
+        ));
+    }
+    MultiSigPublicKey::new(pks, weights, threshold).map_err(|e| anyhow!(e))
+}
+
+pub fn multisig_public_key_from_public_keys(
+    public_keys: Vec<&str>,
+    schemes: Vec<&str>,
+    weights: Vec<u8>,
+    threshold: u16,
+) -> Result<MultiSigPublicKey, anyhow::Error> {
+    let pks = public_keys
+        .iter()
+        .enumerate()
+        .map(|(index, pk)| parse_member_public_key(pk, schemes.get(index).copied().unwrap_or("")))
+        .collect::<Result<Vec<_>, _>>()?;
+    new_multisig_public_key(pks, weights, threshold)
+}
+
+/// Combine partial signatures given in any order into a `MultiSig`,
+/// like `sui keytool multi-sig-combine-partial-sig`.
+pub fn combine_partial_signatures(
+    signatures: Vec<GenericSignature>,
+    multisig_pk: &MultiSigPublicKey,
+) -> Result<MultiSig, anyhow::Error> {
+    // signatures must follow the member order of the bitmap
+    let mut indexed = Vec::with_capacity(signatures.len());
+    for signature in signatures {
+        let public_key = signature
+            .to_public_key()
+            .map_err(|e| anyhow!("Cannot read public key from signature: {}", e))?;
+        let index = multisig_pk
+            .get_index(&public_key)
+            .ok_or_else(|| anyhow!("Signer is not a member of the multisig"))?;
+        indexed.push((index, signature));
+    }
+    indexed.sort_by_key(|(index, _)| *index);
+    MultiSig::combine(
+        indexed.into_iter().map(|(_, signature)| signature).collect(),
+        multisig_pk.clone(),
+    )
+    .map_err(|e| anyhow!("Cannot combine signatures: {}", e))
+}
+
+pub fn decode_multisig(bytes: &[u8]) -> Result<MultiSig, anyhow::Error> {
+    match GenericSignature::from_bytes(bytes).map_err(|e| anyhow!("Invalid signature: {}", e))? {
+        GenericSignature::MultiSig(multisig) => Ok(multisig),
+        _ => Err(anyhow!("Signature is not a multisig")),
+    }
+}
+
+/// Verify a multisig against BCS serialized `TransactionData`.
+///
+/// zkLogin members are checked without JWKs, so a multisig relying on them
+/// has to be verified by the network instead.
+pub fn verify_multisig(multisig: MultiSig, tx_data: &[u8]) -> Result<(), anyhow::Error> {
+    let tx_data: TransactionData = bcs::from_bytes(tx_data)?;
+    let author = SuiAddress::from(multisig.get_pk());
+    GenericSignature::MultiSig(multisig)
+        .verify_authenticator(
+            &IntentMessage::new(Intent::sui_transaction(), tx_data),
+            author,
+            None,
+            &VerifyParams::default(),
+            Arc::new(VerifiedDigestCache::new_empty()),
+        )
+        .map_err(|e| anyhow!("Multisig verification failed: {}", e))
+}
+
 pub async fn _sign_and_execute_transaction(
     tx_data: Vec<u8>,
     signers_addresses: Vec<&str>,
     multisig_pk: Vec<u8>,
 ) -> Result<(), anyhow::Error> {
-    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
-    let keystore_path = default_keystore_path();
-    let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path).unwrap());
     let tx_data: TransactionData = bcs::from_bytes(&tx_data)?;
     let multisig_pk: MultiSigPublicKey = bcs::from_bytes(&multisig_pk)?;
-    let intent_msg = IntentMessage::new(Intent::sui_transaction(), tx_data.clone());
-    let mut signatures = Vec::with_capacity(signers_addresses.len());
+    let mut session = CMultiSigSession::new(multisig_pk, tx_data);
     for address in signers_addresses {
-        signatures.push(
-            GenericSignature::from(Signature::new_secure(
-                &intent_msg,
-                keystore.get_key(&SuiAddress::from_str(address)?)?,
-            ))
-            .to_compressed()
-            .unwrap(),
-        );
+        session.sign_with_signer(address)?;
     }
-
-    let multisig =
-        GenericSignature::MultiSig(MultiSig::insecure_new(signatures, 0b011, multisig_pk));
-
-    let tx = Transaction::from_generic_sig_data(tx_data, vec![multisig]);
-    let transaction_response = sui_client
-        .quorum_driver_api()
-        .execute_transaction_block(tx, SuiTransactionBlockResponseOptions::default(), None)
-        .await?;
-    println!(
-        "Transaction executed. Transaction digest: {}",
-        transaction_response.digest.base58_encode()
-    );
+    let digest = session.execute().await?;
+    println!("Transaction executed. Transaction digest: {}", digest);
     Ok(())
 }
 
@@ -157,6 +374,32 @@ pub struct CMultiSig {
     error: *const c_char,
 }
 
+impl CMultiSig {
+    fn from(result: Result<MultiSigPublicKey, anyhow::Error>) -> Self {
+        match result {
+            Ok(multisig_pk) => CMultiSig {
+                bytes: CU8Array::from(bcs::to_bytes(&multisig_pk).unwrap()),
+                address: CString::new(SuiAddress::from(&multisig_pk).to_string())
+                    .unwrap()
+                    .into_raw(),
+                error: std::ptr::null(),
+            },
+            Err(e) => {
+                let error_message = CString::new(e.to_string()).unwrap().into_raw();
+                CMultiSig {
+                    bytes: c_types::CU8Array {
+                        data: std::ptr::null(),
+                        len: 0,
+                        error: std::ptr::null(),
+                    },
+                    address: std::ptr::null(),
+                    error: error_message,
+                }
+            }
+        }
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn free_multisig(multisig: CMultiSig) {
     unsafe {
@@ -196,43 +439,59 @@ pub extern "C" fn get_or_create_multisig(
         let weights: Vec<u8> =
             unsafe { slice::from_raw_parts(weights.data, weights.len as usize).to_vec() };
 
-        match get_or_create_multisig_public_key(addresses, weights, threshold).await {
-            Ok(multisig_pk) => {
-                let bytes = bcs::to_bytes(&multisig_pk).unwrap();
-                println!("Vec<u8> in Rust: {:?}", bytes);
+        CMultiSig::from(get_or_create_multisig_public_key(addresses, weights, threshold).await)
+    })
+}
 
-                let boxed_bytes = bytes.into_boxed_slice();
-                let data_ptr = boxed_bytes.as_ptr();
-                let len = boxed_bytes.len() as c_uint;
+// Build a multisig from public keys of any member, e.g. a teammate's key or a zkLogin identity.
+// `schemes` holds one of ed25519/secp256k1/secp256r1/zklogin per key for raw base64 keys,
+// pass an empty array (or empty strings) when the keys are already in `flag || public key` form.
+#[no_mangle]
+pub extern "C" fn create_multisig_from_public_keys(
+    public_keys: CStringArray,
+    schemes: CStringArray,
+    weights: CU8Array,
+    threshold: u16,
+) -> CMultiSig {
+    CMultiSig::from((|| {
+        multisig_public_key_from_public_keys(
+            c_strings(&public_keys)?,
+            c_strings(&schemes)?,
+            weights.to_vec(),
+            threshold,
+        )
+    })())
+}
 
-                // Leak the boxed slice to keep it alive
-                std::mem::forget(boxed_bytes);
-                CMultiSig {
-                    bytes: c_types::CU8Array {
-                        data: data_ptr,
-                        len: len,
-                        error: std::ptr::null(),
-                    },
-                    address: CString::new(SuiAddress::from(&multisig_pk).to_string())
-                        .unwrap()
-                        .into_raw(),
-                    error: std::ptr::null(),
-                }
-            }
-            Err(e) => {
-                let error_message = CString::new(e.to_string()).unwrap().into_raw();
-                CMultiSig {
-                    bytes: c_types::CU8Array {
-                        data: std::ptr::null(),
-                        len: 0,
-                        error: std::ptr::null(),
-                    },
-                    address: std::ptr::null(),
-                    error: error_message,
-                }
-            }
-        }
-    })
+// Return the base64 `flag || public key` of a zkLogin member, to be used in create_multisig_from_public_keys
+#[no_mangle]
+pub extern "C" fn zklogin_multisig_member(
+    iss: *const c_char,
+    address_seed: *const c_char,
+) -> *const c_char {
+    let iss = unsafe {
+        assert!(!iss.is_null());
+        CStr::from_ptr(iss).to_str().unwrap_or("Invalid UTF-8")
+    };
+    let address_seed = unsafe {
+        assert!(!address_seed.is_null());
+        CStr::from_ptr(address_seed).to_str().unwrap_or("Invalid UTF-8")
+    };
+    match zklogin_member_public_key(iss, address_seed) {
+        Ok(pk) => CString::new(pk.encode_base64()).unwrap().into_raw(),
+        Err(e) => CString::new(format!("Error: {}", e)).unwrap().into_raw(),
+    }
+}
+
+// Derive the multisig address from a serialized multisig public key
+#[no_mangle]
+pub extern "C" fn multisig_address_from_bytes(multisig: CU8Array) -> *const c_char {
+    match bcs::from_bytes::<MultiSigPublicKey>(&multisig.to_vec()) {
+        Ok(multisig_pk) => CString::new(SuiAddress::from(&multisig_pk).to_string())
+            .unwrap()
+            .into_raw(),
+        Err(e) => CString::new(format!("Error: {}", e)).unwrap().into_raw(),
+    }
 }
 
 #[no_mangle]
@@ -268,3 +527,364 @@ pub extern "C" fn sign_and_execute_transaction_miltisig(
         }
     })
 }
+
+#[repr(C)]
+pub struct CMultiSigSessionResult {
+    session: *mut CMultiSigSession,
+    error: *const c_char,
+}
+
+impl CMultiSigSessionResult {
+    fn from(result: Result<CMultiSigSession, anyhow::Error>) -> Self {
+        match result {
+            Ok(session) => CMultiSigSessionResult {
+                session: Box::into_raw(Box::new(session)),
+                error: ptr::null(),
+            },
+            Err(e) => CMultiSigSessionResult {
+                session: ptr::null_mut(),
+                error: CString::new(e.to_string()).unwrap().into_raw(),
+            },
+        }
+    }
+}
+
+fn c_strings(array: &CStringArray) -> Result<Vec<&str>, anyhow::Error> {
+    if array.len <= 0 {
+        return Ok(Vec::new());
+    }
+    if array.data.is_null() {
+        return Err(anyhow!("String array data is null"));
+    }
+    (0..array.len)
+        .map(|i| {
+            let item = unsafe { *array.data.add(i as usize) };
+            c_types::c_str(item).ok_or_else(|| anyhow!("Invalid string at index {}", i))
+        })
+        .collect()
+}
+
+// The session behind a pointer from CMultiSigSessionResult, which is null when creating it failed
+fn session_ref<'a>(
+    session: *const CMultiSigSession,
+) -> Result<&'a CMultiSigSession, anyhow::Error> {
+    unsafe { session.as_ref() }.ok_or_else(|| anyhow!("Invalid multisig session: null"))
+}
+
+fn session_mut<'a>(
+    session: *mut CMultiSigSession,
+) -> Result<&'a mut CMultiSigSession, anyhow::Error> {
+    unsafe { session.as_mut() }.ok_or_else(|| anyhow!("Invalid multisig session: null"))
+}
+
+// Return null on success, otherwise an error message to be freed with free_error_string
+fn error_or_null<T>(result: Result<T, anyhow::Error>) -> *const c_char {
+    match result {
+        Ok(_) => ptr::null(),
+        Err(e) => CString::new(e.to_string()).unwrap().into_raw(),
+    }
+}
+
+// Start a signing session from a serialized multisig public key (as returned by get_or_create_multisig)
+#[no_mangle]
+pub extern "C" fn create_multisig_session(
+    multisig: CU8Array,
+    tx: CU8Array,
+) -> CMultiSigSessionResult {
+    CMultiSigSessionResult::from((|| -> Result<CMultiSigSession, anyhow::Error> {
+        let multisig_pk: MultiSigPublicKey = bcs::from_bytes(&multisig.to_vec())?;
+        let tx_data: TransactionData = bcs::from_bytes(&tx.to_vec())?;
+        Ok(CMultiSigSession::new(multisig_pk, tx_data))
+    })())
+}
+
+// Start a signing session from base64 `flag || public key` members, no local keys are needed
+#[no_mangle]
+pub extern "C" fn create_multisig_session_from_public_keys(
+    public_keys: CStringArray,
+    weights: CU8Array,
+    threshold: u16,
+    tx: CU8Array,
+) -> CMultiSigSessionResult {
+    CMultiSigSessionResult::from((|| -> Result<CMultiSigSession, anyhow::Error> {
+        let multisig_pk = multisig_public_key_from_public_keys(
+            c_strings(&public_keys)?,
+            Vec::new(),
+            weights.to_vec(),
+            threshold,
+        )?;
+        let tx_data: TransactionData = bcs::from_bytes(&tx.to_vec())?;
+        Ok(CMultiSigSession::new(multisig_pk, tx_data))
+    })())
+}
+
+#[no_mangle]
+pub extern "C" fn destroy_multisig_session(session: *mut CMultiSigSession) {
+    if !session.is_null() {
+        unsafe {
+            let _ = Box::from_raw(session);
+        }
+    }
+}
+
+// Add a base64 serialized `flag || signature || public key` produced by any member
+#[no_mangle]
+pub extern "C" fn multisig_session_add_signature(
+    session: *mut CMultiSigSession,
+    signature: *const c_char,
+) -> SuiStatus {
+    let signature = unsafe {
+        assert!(!signature.is_null());
+        CStr::from_ptr(signature).to_str().unwrap_or("Invalid UTF-8")
+    };
+    error_or_null(session_mut(session).and_then(|session| {
+        GenericSignature::decode_base64(signature)
+            .map_err(|e| anyhow!("Invalid signature: {}", e))
+            .and_then(|signature| session.add_signature(signature))
+    }))
+}
+
+#[no_mangle]
+pub extern "C" fn multisig_session_sign_with_keystore(
+    session: *mut CMultiSigSession,
+    address: *const c_char,
+) -> SuiStatus {
+    let address = unsafe {
+        assert!(!address.is_null());
+        CStr::from_ptr(address).to_str().unwrap_or("Invalid UTF-8")
+    };
+    error_or_null(session_mut(session).and_then(|session| session.sign_with_keystore(address)))
+}
+
+// Sign with the signer registered through register_signer_callback/register_local_signer,
+// or the keystore when none is registered
+#[no_mangle]
+pub extern "C" fn multisig_session_sign_with_signer(
+    session: *mut CMultiSigSession,
+    address: *const c_char,
+) -> SuiStatus {
+    let address = unsafe {
+        assert!(!address.is_null());
+        CStr::from_ptr(address).to_str().unwrap_or("Invalid UTF-8")
+    };
+    error_or_null(session_mut(session).and_then(|session| session.sign_with_signer(address)))
+}
+
+// Intent message bytes that every member has to sign
+#[no_mangle]
+pub extern "C" fn multisig_session_signing_message(session: *const CMultiSigSession) -> CU8Array {
+    match session_ref(session) {
+        Ok(session) => CU8Array::from(bcs::to_bytes(&session.intent_message()).unwrap()),
+        Err(e) => CU8Array::from_error(e.to_string()),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn multisig_session_bitmap(session: *const CMultiSigSession) -> u16 {
+    session_ref(session).map_or(0, |session| session.bitmap())
+}
+
+#[no_mangle]
+pub extern "C" fn multisig_session_weight(session: *const CMultiSigSession) -> u16 {
+    session_ref(session).map_or(0, |session| session.weight())
+}
+
+#[no_mangle]
+pub extern "C" fn multisig_session_threshold(session: *const CMultiSigSession) -> u16 {
+    session_ref(session).map_or(0, |session| session.threshold())
+}
+
+#[no_mangle]
+pub extern "C" fn multisig_session_is_threshold_met(session: *const CMultiSigSession) -> bool {
+    session_ref(session).map_or(false, |session| session.is_threshold_met())
+}
+
+// Serialized `flag || bcs(MultiSig)`, the same format as `sui keytool multi-sig-combine-partial-sig`
+#[no_mangle]
+pub extern "C" fn multisig_session_combine(session: *const CMultiSigSession) -> CU8Array {
+    match session_ref(session).and_then(|session| session.combine()) {
+        Ok(multisig) => CU8Array::from(GenericSignature::MultiSig(multisig).as_ref().to_vec()),
+        Err(e) => CU8Array::from_error(e.to_string()),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn multisig_session_execute(session: *const CMultiSigSession) -> *const c_char {
+    let session = match session_ref(session) {
+        Ok(session) => session,
+        Err(e) => return CString::new(format!("Error: {}", e)).unwrap().into_raw(),
+    };
+    // Create a new runtime. This step might vary based on the async runtime you are using.
+    let rt = runtime::Runtime::new().unwrap();
+    rt.block_on(async {
+        match session.execute().await {
+            Ok(digest) => CString::new(format!("Transaction executed. Transaction digest: {}", digest))
+                .unwrap()
+                .into_raw(),
+            Err(e) => CString::new(format!("Error: {}", e)).unwrap().into_raw(),
+        }
+    })
+}
+
+#[repr(C)]
+pub struct CMultiSigMember {
+    pub public_key: *const c_char,
+    pub weight: u8,
+    pub has_signed: bool,
+    pub signature: *const c_char,
+}
+
+#[repr(C)]
+pub struct CMultiSigInfo {
+    pub address: *const c_char,
+    pub members: *mut CMultiSigMember,
+    pub members_len: usize,
+    pub bitmap: u16,
+    pub threshold: u16,
+    pub signed_weight: u16,
+    pub threshold_met: bool,
+    pub error: *const c_char,
+}
+
+impl CMultiSigInfo {
+    fn from(result: Result<MultiSig, anyhow::Error>) -> Self {
+        // A bitmap that does not decode is an error, not "nobody signed"
+        let decoded = result.and_then(|multisig| {
+            let indices = multisig
+                .get_indices()
+                .map_err(|e| anyhow!("Invalid multisig bitmap: {}", e))?;
+            Ok((multisig, indices))
+        });
+        let (multisig, indices) = match decoded {
+            Ok(decoded) => decoded,
+            Err(e) => {
+                return CMultiSigInfo {
+                    address: ptr::null(),
+                    members: ptr::null_mut(),
+                    members_len: 0,
+                    bitmap: 0,
+                    threshold: 0,
+                    signed_weight: 0,
+                    threshold_met: false,
+                    error: CString::new(e.to_string()).unwrap().into_raw(),
+                }
+            }
+        };
+        let multisig_pk = multisig.get_pk();
+        let mut signed_weight = 0;
+        let members: Box<[CMultiSigMember]> = multisig_pk
+            .pubkeys()
+            .iter()
+            .enumerate()
+            .map(|(index, (pk, weight))| {
+                // signatures are stored in the same order as the bitmap indices
+                let signature = indices
+                    .iter()
+                    .position(|signed_index| *signed_index as usize == index)
+                    .and_then(|position| multisig.get_sigs().get(position));
+                if signature.is_some() {
+                    signed_weight += *weight as u16;
+                }
+                CMultiSigMember {
+                    public_key: CString::new(pk.encode_base64()).unwrap().into_raw(),
+                    weight: *weight,
+                    has_signed: signature.is_some(),
+                    signature: match signature {
+                        Some(sig) => CString::new(Base64::encode(sig.as_ref())).unwrap().into_raw(),
+                        None => ptr::null(),
+                    },
+                }
+            })
+            .collect();
+        let members_len = members.len();
+        let members_ptr = Box::into_raw(members) as *mut CMultiSigMember;
+        let threshold = *multisig_pk.threshold();
+        CMultiSigInfo {
+            address: CString::new(SuiAddress::from(multisig_pk).to_string())
+                .unwrap()
+                .into_raw(),
+            members: members_ptr,
+            members_len,
+            bitmap: indices.iter().fold(0, |bitmap, index| bitmap | (1 << index)),
+            threshold,
+            signed_weight,
+            threshold_met: signed_weight >= threshold,
+            error: ptr::null(),
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn free_multisig_info(info: CMultiSigInfo) {
+    unsafe {
+        if !info.address.is_null() {
+            let _ = CString::from_raw(info.address as *mut c_char);
+        }
+        if !info.error.is_null() {
+            let _ = CString::from_raw(info.error as *mut c_char);
+        }
+        if !info.members.is_null() {
+            let members = Box::from_raw(slice::from_raw_parts_mut(info.members, info.members_len));
+            for member in members.iter() {
+                if !member.public_key.is_null() {
+                    let _ = CString::from_raw(member.public_key as *mut c_char);
+                }
+                if !member.signature.is_null() {
+                    let _ = CString::from_raw(member.signature as *mut c_char);
+                }
+            }
+        }
+    }
+}
+
+// Combine base64 partial signatures in any order for a serialized multisig public key
+#[no_mangle]
+pub extern "C" fn combine_partial_signatures_multisig(
+    multisig: CU8Array,
+    signatures: CStringArray,
+) -> CU8Array {
+    let result = (|| -> Result<Vec<u8>, anyhow::Error> {
+        let multisig_pk: MultiSigPublicKey = bcs::from_bytes(&multisig.to_vec())?;
+        let signatures = c_strings(&signatures)?
+            .iter()
+            .map(|sig| {
+                GenericSignature::decode_base64(sig)
+                    .map_err(|e| anyhow!("Invalid signature: {}", e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let multisig = combine_partial_signatures(signatures, &multisig_pk)?;
+        Ok(GenericSignature::MultiSig(multisig).as_ref().to_vec())
+    })();
+    match result {
+        Ok(bytes) => CU8Array::from(bytes),
+        Err(e) => CU8Array::from_error(e.to_string()),
+    }
+}
+
+// Decode a serialized multisig into its members, bitmap and signed weight
+#[no_mangle]
+pub extern "C" fn decode_multisig_signature(multisig: CU8Array) -> CMultiSigInfo {
+    CMultiSigInfo::from(decode_multisig(&multisig.to_vec()))
+}
+
+// Same as decode_multisig_signature for the base64 form printed by the Sui CLI
+#[no_mangle]
+pub extern "C" fn decode_multisig_signature_base64(multisig: *const c_char) -> CMultiSigInfo {
+    let multisig = unsafe {
+        assert!(!multisig.is_null());
+        CStr::from_ptr(multisig).to_str().unwrap_or("Invalid UTF-8")
+    };
+    CMultiSigInfo::from(
+        Base64::decode(multisig)
+            .map_err(|e| anyhow!("Invalid base64: {}", e))
+            .and_then(|bytes| decode_multisig(&bytes)),
+    )
+}
+
+// Return null when the multisig is valid for the transaction bytes, otherwise the reason
+#[no_mangle]
+pub extern "C" fn verify_multisig_signature(multisig: CU8Array, tx: CU8Array) -> SuiStatus {
+    error_or_null(
+        decode_multisig(&multisig.to_vec()).and_then(|multisig| verify_multisig(multisig, &tx.to_vec())),
+    )
+}
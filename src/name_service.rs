@@ -0,0 +1,73 @@
+use anyhow::anyhow;
+use std::ffi::{c_char, CString};
+use std::str::FromStr;
+use sui_types::base_types::SuiAddress;
+use tokio::runtime;
+
+use crate::c_types::{c_str, ResultCStringArray};
+use crate::sui_client::SuiClientSingleton;
+
+/// SuiNS names are either `alice.sui` or the newer `@alice` form.
+pub fn is_sui_name(input: &str) -> bool {
+    !input.starts_with("0x") && (input.contains('.') || input.contains('@'))
+}
+
+/// Parse a hex address, resolving SuiNS names through the configured fullnode.
+pub async fn resolve_address(address_or_name: &str) -> Result<SuiAddress, anyhow::Error> {
+    if !is_sui_name(address_or_name) {
+        return Ok(SuiAddress::from_str(address_or_name)?);
+    }
+    _resolve_name(address_or_name).await
+}
+
+pub async fn _resolve_name(name: &str) -> Result<SuiAddress, anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    sui_client
+        .read_api()
+        .resolve_name_service_address(name.to_string())
+        .await?
+        .ok_or_else(|| anyhow!("SuiNS name {} does not resolve to an address", name))
+}
+
+/// Names pointing to `address`, the default name first.
+pub async fn _reverse_lookup(address: &str) -> Result<Vec<String>, anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    let address = SuiAddress::from_str(address)?;
+    let mut names = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = sui_client
+            .read_api()
+            .resolve_name_service_names(address, cursor, None)
+            .await?;
+        names.extend(page.data);
+        if !page.has_next_page || page.next_cursor.is_none() {
+            break;
+        }
+        cursor = page.next_cursor;
+    }
+    Ok(names)
+}
+
+//public functions for ffi
+
+// Resolve `alice.sui` (or a hex address) to a hex address, returns "Error: ..." on failure
+#[no_mangle]
+pub extern "C" fn resolve_sui_name(name: *const c_char) -> *const c_char {
+    let name = c_str(name).unwrap_or_default();
+    // Create a new runtime. This step might vary based on the async runtime you are using.
+    let rt = runtime::Runtime::new().unwrap();
+    let result = match rt.block_on(resolve_address(name)) {
+        Ok(address) => address.to_string(),
+        Err(e) => format!("Error: {}", e),
+    };
+    CString::new(result).unwrap().into_raw()
+}
+
+// SuiNS names of an address for display, free with `free_result_string_array`
+#[no_mangle]
+pub extern "C" fn lookup_sui_names(address: *const c_char) -> ResultCStringArray {
+    let address = c_str(address).unwrap_or_default();
+    let rt = runtime::Runtime::new().unwrap();
+    ResultCStringArray::from(rt.block_on(_reverse_lookup(address)))
+}
@@ -1,15 +1,23 @@
+use crate::c_types::{c_str, free_c_char, CStringArray};
+use crate::name_service::resolve_address;
+use crate::object_read_api::{
+    ObjectLookup, _get_all_owned_objects, _get_object, _get_owned_objects,
+};
+use crate::signer::sign_transaction;
+use crate::transaction_builder::{_execute_transaction, _object_arg};
 use crate::sui_client::SuiClientSingleton;
 use anyhow::Result;
 use anyhow::{anyhow, Ok};
-use move_core_types::language_storage::StructTag;
-use shared_crypto::intent::Intent;
+use fastcrypto::encoding::{Encoding, Hex};
+use move_core_types::language_storage::{StructTag, TypeTag};
+use move_core_types::u256::U256;
+use std::collections::BTreeMap;
 use std::ffi::{c_char, CStr, CString};
+use std::ptr;
 use std::str::FromStr;
-use sui_config::{sui_config_dir, SUI_KEYSTORE_FILENAME};
 use sui_json_rpc_types::{
-    SuiObjectData, SuiObjectDataFilter, SuiObjectDataOptions, SuiObjectResponseQuery,
+    EventFilter, SuiObjectData, SuiObjectDataFilter, SuiObjectDataOptions, SuiParsedData,
 };
-use sui_keys::keystore::{AccountKeystore, FileBasedKeystore};
 use sui_sdk::{
     rpc_types::SuiTransactionBlockResponseOptions,
     types::{
@@ -94,8 +102,7 @@ pub async fn _mint(
     );
 
     // 4) sign transaction
-    let keystore = FileBasedKeystore::new(&sui_config_dir()?.join(SUI_KEYSTORE_FILENAME))?;
-    let signature = keystore.sign_secure(&sender, &tx_data, Intent::sui_transaction())?;
+    let signature = sign_transaction(&sender, &tx_data)?;
 
     // 5) execute the transaction
     print!("Executing the transaction...");
@@ -146,7 +153,7 @@ pub async fn _transfer_nft(
     // Convert inputs to CallArg
     let nft_id_argument = CallArg::Object(ObjectArg::ImmOrOwnedObject(object_ref));
     let recipient_argument = CallArg::Pure(
-        bcs::to_bytes(&SuiAddress::from_str(recipient_address).map_err(|e| anyhow!(e))?).unwrap(),
+        bcs::to_bytes(&resolve_address(recipient_address).await?).unwrap(),
     );
     ptb.input(nft_id_argument)?;
     ptb.input(recipient_argument)?;
@@ -178,8 +185,7 @@ pub async fn _transfer_nft(
     );
 
     // 4) sign transaction
-    let keystore = FileBasedKeystore::new(&sui_config_dir()?.join(SUI_KEYSTORE_FILENAME))?;
-    let signature = keystore.sign_secure(&sender, &tx_data, Intent::sui_transaction())?;
+    let signature = sign_transaction(&sender, &tx_data)?;
 
     // 5) execute the transaction
     print!("Executing the transaction...");
@@ -195,25 +201,243 @@ pub async fn _transfer_nft(
     Ok(())
 }
 
+/// Send owned objects to `recipient` with a native `TransferObjects`, any `key + store` object works.
+pub async fn _transfer_objects(
+    sender_address: &str,
+    object_ids: &[String],
+    recipient_address: &str,
+    gas_budget: u64,
+) -> Result<String, anyhow::Error> {
+    if object_ids.is_empty() {
+        return Err(anyhow!("No object to transfer"));
+    }
+    let mut ptb = ProgrammableTransactionBuilder::new();
+    let mut objects = Vec::with_capacity(object_ids.len());
+    for object_id in object_ids {
+        objects.push(ptb.obj(_object_arg(object_id, true).await?)?);
+    }
+    let recipient = ptb.pure(resolve_address(recipient_address).await?)?;
+    ptb.command(Command::TransferObjects(objects, recipient));
+
+    let response = _execute_transaction(sender_address, ptb.finish(), gas_budget).await?;
+    Ok(response.digest.to_string())
+}
+
+/// BCS encode a mint argument described by its Move type.
+fn pure_argument(type_: &str, value: &str) -> Result<Vec<u8>, anyhow::Error> {
+    let invalid =
+        |e: &dyn std::fmt::Display| anyhow!("Invalid {} argument {}: {}", type_, value, e);
+    Ok(match type_ {
+        "u8" => bcs::to_bytes(&value.parse::<u8>().map_err(|e| invalid(&e))?)?,
+        "u16" => bcs::to_bytes(&value.parse::<u16>().map_err(|e| invalid(&e))?)?,
+        "u32" => bcs::to_bytes(&value.parse::<u32>().map_err(|e| invalid(&e))?)?,
+        "u64" => bcs::to_bytes(&value.parse::<u64>().map_err(|e| invalid(&e))?)?,
+        "u128" => bcs::to_bytes(&value.parse::<u128>().map_err(|e| invalid(&e))?)?,
+        "u256" => bcs::to_bytes(&value.parse::<U256>().map_err(|e| invalid(&e))?)?,
+        "bool" => bcs::to_bytes(&value.parse::<bool>().map_err(|e| invalid(&e))?)?,
+        "address" => bcs::to_bytes(&SuiAddress::from_str(value).map_err(|e| invalid(&e))?)?,
+        "string" | "0x1::string::String" | "0x1::ascii::String" => bcs::to_bytes(value)?,
+        "vector<u8>" => bcs::to_bytes(
+            &Hex::decode(value.trim_start_matches("0x")).map_err(|e| invalid(&e))?,
+        )?,
+        // JSON array of strings, e.g. ["rare","gold"]
+        "vector<string>" => {
+            bcs::to_bytes(&serde_json::from_str::<Vec<String>>(value).map_err(|e| invalid(&e))?)?
+        }
+        _ => return Err(anyhow!("Unsupported mint argument type {}", type_)),
+    })
+}
+
+/// Call any mint function, each argument is a `(type, value)` pair.
+///
+/// `object` and `object_ref` arguments take an object id, resolved as mutable and immutable
+/// inputs respectively, every other type is passed as a pure value (see `pure_argument`).
+pub async fn _mint_with_call(
+    sender_address: &str,
+    package_id: &str,
+    module: &str,
+    function: &str,
+    type_arguments: &[String],
+    arguments: &[(String, String)],
+    gas_budget: u64,
+) -> Result<String, anyhow::Error> {
+    let mut ptb = ProgrammableTransactionBuilder::new();
+    let mut call_arguments = Vec::with_capacity(arguments.len());
+    for (type_, value) in arguments {
+        let argument = match type_.as_str() {
+            "object" => ptb.obj(_object_arg(value, true).await?)?,
+            "object_ref" => ptb.obj(_object_arg(value, false).await?)?,
+            _ => ptb.input(CallArg::Pure(pure_argument(type_, value)?))?,
+        };
+        call_arguments.push(argument);
+    }
+    let type_arguments = type_arguments
+        .iter()
+        .map(|type_argument| TypeTag::from_str(type_argument))
+        .collect::<Result<Vec<_>, _>>()?;
+    ptb.command(Command::move_call(
+        ObjectID::from_hex_literal(package_id).map_err(|e| anyhow!(e))?,
+        Identifier::new(module).map_err(|e| anyhow!(e))?,
+        Identifier::new(function).map_err(|e| anyhow!(e))?,
+        type_arguments,
+        call_arguments,
+    ));
+
+    let response = _execute_transaction(sender_address, ptb.finish(), gas_budget).await?;
+    Ok(response.digest.to_string())
+}
+
 pub async fn _get_wallet_objects(address: &str, object_type: &str) -> Result<Vec<SuiObjectData>> {
-    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
-    let active_address: SuiAddress = SuiAddress::from_str(address)?;
-    let query = Some(SuiObjectResponseQuery {
-        filter: Some(SuiObjectDataFilter::StructType(StructTag::from_str(
-            object_type,
-        )?)),
-        options: Some(SuiObjectDataOptions::new().with_type().with_content()),
-    });
-    let owned_objects = sui_client
-        .read_api()
-        .get_owned_objects(active_address, query, None, None)
-        .await?
-        .data;
-    let data: Vec<SuiObjectData> = owned_objects
+    let filter = SuiObjectDataFilter::StructType(
+        StructTag::from_str(object_type)
+            .map_err(|e| anyhow!("Invalid object type {}: {}", object_type, e))?,
+    );
+    _get_all_owned_objects(
+        address,
+        Some(filter),
+        SuiObjectDataOptions::new().with_type().with_content(),
+    )
+    .await
+}
+
+pub const DEFAULT_IPFS_GATEWAY: &str = "https://ipfs.io/ipfs/";
+const DISPLAY_FIELDS: [&str; 6] = [
+    "name",
+    "description",
+    "image_url",
+    "link",
+    "project_url",
+    "creator",
+];
+
+/// An owned object rendered through the `Display` registered for its type.
+pub struct Nft {
+    pub object_id: String,
+    pub object_type: String,
+    pub display: BTreeMap<String, String>,
+}
+
+/// The `Display<T>` templates shared by every object of a collection.
+pub struct NftCollection {
+    pub object_type: String,
+    pub display_object_id: String,
+    pub version: u64,
+    pub templates: BTreeMap<String, String>,
+}
+
+/// Turn `ipfs://`, `ar://` and bare CIDs into URLs a HTTP client can fetch.
+pub fn resolve_image_url(url: &str, ipfs_gateway: &str) -> String {
+    let url = url.trim();
+    let gateway = if ipfs_gateway.is_empty() {
+        DEFAULT_IPFS_GATEWAY
+    } else {
+        ipfs_gateway
+    };
+    let gateway = gateway.trim_end_matches('/');
+    if let Some(path) = url.strip_prefix("ipfs://") {
+        format!("{}/{}", gateway, path.trim_start_matches("ipfs/"))
+    } else if let Some(path) = url.strip_prefix("ar://") {
+        format!("https://arweave.net/{}", path)
+    } else if url.starts_with("Qm") || url.starts_with("bafy") {
+        format!("{}/{}", gateway, url)
+    } else {
+        url.to_string()
+    }
+}
+
+/// Read the NFTs of an address, objects whose type has no `Display` are skipped.
+pub async fn _get_wallet_nfts(
+    address: &str,
+    cursor: Option<&str>,
+    limit: Option<usize>,
+) -> Result<(Vec<Nft>, Option<String>, bool)> {
+    // Coins never carry a Display
+    let filter = SuiObjectDataFilter::MatchNone(vec![SuiObjectDataFilter::StructType(
+        StructTag::from_str("0x2::coin::Coin")?,
+    )]);
+    let page = _get_owned_objects(
+        address,
+        Some(filter),
+        SuiObjectDataOptions::new().with_type().with_display(),
+        cursor,
+        limit,
+    )
+    .await?;
+    let nfts = page
+        .data
         .into_iter()
-        .filter_map(|owned_objects| owned_objects.data)
+        .filter_map(|object| object.data)
+        .filter_map(|data| {
+            let display = data.display.and_then(|display| display.data)?;
+            Some(Nft {
+                object_id: data.object_id.to_string(),
+                object_type: data.type_.map(|t| t.to_string()).unwrap_or_default(),
+                display,
+            })
+        })
         .collect();
-    Ok(data)
+    Ok((
+        nfts,
+        page.next_cursor.map(|cursor| cursor.to_string()),
+        page.has_next_page,
+    ))
+}
+
+/// Find the `Display<T>` object of a collection through its `DisplayCreated<T>` event.
+pub async fn _get_nft_collection(object_type: &str) -> Result<NftCollection> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    let object_type = StructTag::from_str(object_type)
+        .map_err(|e| anyhow!("Invalid object type {}: {}", object_type, e))?;
+    let event_type =
+        StructTag::from_str(&format!("0x2::display::DisplayCreated<{}>", object_type))?;
+    let events = sui_client
+        .event_api()
+        .query_events(EventFilter::MoveEventType(event_type), None, Some(1), false)
+        .await?;
+    let display_object_id = events
+        .data
+        .first()
+        .and_then(|event| event.parsed_json["id"].as_str())
+        .ok_or_else(|| anyhow!("No Display registered for {}", object_type))?
+        .to_string();
+
+    let display = match _get_object(
+        &display_object_id,
+        SuiObjectDataOptions::new().with_content(),
+    )
+    .await?
+    {
+        ObjectLookup::Found(data) => data,
+        ObjectLookup::Missing(_, error) => return Err(anyhow!(error)),
+    };
+    let fields = match display.content {
+        Some(SuiParsedData::MoveObject(object)) => object.fields.to_json_value(),
+        _ => return Err(anyhow!("Display {} has no content", display_object_id)),
+    };
+    // VecMap<String, String> is rendered as {"contents": [{"key": .., "value": ..}]}
+    let templates = fields["fields"]["contents"]
+        .as_array()
+        .map(|entries| {
+            entries
+                .iter()
+                .filter_map(|entry| {
+                    Some((
+                        entry["key"].as_str()?.to_string(),
+                        entry["value"].as_str()?.to_string(),
+                    ))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    Ok(NftCollection {
+        object_type: object_type.to_string(),
+        display_object_id,
+        version: fields["version"]
+            .as_u64()
+            .or_else(|| fields["version"].as_str()?.parse().ok())
+            .unwrap_or_default(),
+        templates,
+    })
 }
 
 #[no_mangle]
@@ -314,4 +538,309 @@ pub extern "C" fn transfer_nft(
             }
         }
     })
-}
\ No newline at end of file
+}
+// Display fields of an NFT, missing fields are null and `fields` holds every field as JSON
+#[repr(C)]
+pub struct CNftDisplay {
+    object_id: *mut c_char,
+    object_type: *mut c_char,
+    name: *mut c_char,
+    description: *mut c_char,
+    image_url: *mut c_char,
+    link: *mut c_char,
+    project_url: *mut c_char,
+    creator: *mut c_char,
+    fields: *mut c_char,
+}
+
+#[repr(C)]
+pub struct CNftPage {
+    nfts: *mut CNftDisplay,
+    len: usize,
+    next_cursor: *mut c_char,
+    has_next_page: bool,
+    error: *mut c_char,
+}
+
+// `templates` holds the raw Display templates, e.g. "name": "Capy {name}"
+#[repr(C)]
+pub struct CNftCollection {
+    object_type: *mut c_char,
+    display_object_id: *mut c_char,
+    version: u64,
+    name: *mut c_char,
+    description: *mut c_char,
+    image_url: *mut c_char,
+    link: *mut c_char,
+    project_url: *mut c_char,
+    creator: *mut c_char,
+    templates: *mut c_char,
+    error: *mut c_char,
+}
+
+fn optional_c_char(value: Option<&String>) -> *mut c_char {
+    value.map_or(ptr::null_mut(), |value| {
+        CString::new(value.as_str()).unwrap().into_raw()
+    })
+}
+
+impl CNftDisplay {
+    fn from(nft: Nft) -> Self {
+        let [name, description, image_url, link, project_url, creator] =
+            DISPLAY_FIELDS.map(|field| optional_c_char(nft.display.get(field)));
+        CNftDisplay {
+            object_id: CString::new(nft.object_id).unwrap().into_raw(),
+            object_type: CString::new(nft.object_type).unwrap().into_raw(),
+            name,
+            description,
+            image_url,
+            link,
+            project_url,
+            creator,
+            fields: CString::new(serde_json::to_string(&nft.display).unwrap())
+                .unwrap()
+                .into_raw(),
+        }
+    }
+
+    fn free(&mut self) {
+        for field in [
+            self.object_id,
+            self.object_type,
+            self.name,
+            self.description,
+            self.image_url,
+            self.link,
+            self.project_url,
+            self.creator,
+            self.fields,
+        ] {
+            free_c_char(field);
+        }
+    }
+}
+
+// One page of the NFTs owned by `address`, pass the previous `next_cursor` (or null for the
+// first page) and a limit of 0 for the node default. A page may hold fewer NFTs than `limit`
+// since objects without Display are skipped, keep reading while `has_next_page` is set
+#[no_mangle]
+pub extern "C" fn get_wallet_nfts(
+    address: *const c_char,
+    cursor: *const c_char,
+    limit: usize,
+) -> CNftPage {
+    let address = c_str(address).unwrap_or_default();
+    let cursor = c_str(cursor);
+    let limit = (limit > 0).then_some(limit);
+    // Create a new runtime. This step might vary based on the async runtime you are using.
+    let rt = runtime::Runtime::new().unwrap();
+    match rt.block_on(_get_wallet_nfts(address, cursor, limit)) {
+        Result::Ok((nfts, next_cursor, has_next_page)) => {
+            let nfts: Box<[CNftDisplay]> = nfts.into_iter().map(CNftDisplay::from).collect();
+            let len = nfts.len();
+            CNftPage {
+                nfts: Box::into_raw(nfts) as *mut CNftDisplay,
+                len,
+                next_cursor: optional_c_char(next_cursor.as_ref()),
+                has_next_page,
+                error: ptr::null_mut(),
+            }
+        }
+        Err(e) => CNftPage {
+            nfts: ptr::null_mut(),
+            len: 0,
+            next_cursor: ptr::null_mut(),
+            has_next_page: false,
+            error: CString::new(e.to_string()).unwrap().into_raw(),
+        },
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn free_nft_page(page: CNftPage) {
+    if !page.nfts.is_null() {
+        unsafe {
+            let mut nfts = Box::from_raw(std::slice::from_raw_parts_mut(page.nfts, page.len));
+            for nft in nfts.iter_mut() {
+                nft.free();
+            }
+        }
+    }
+    free_c_char(page.next_cursor);
+    free_c_char(page.error);
+}
+
+#[no_mangle]
+pub extern "C" fn get_nft_collection(object_type: *const c_char) -> CNftCollection {
+    let object_type = c_str(object_type).unwrap_or_default();
+    let rt = runtime::Runtime::new().unwrap();
+    match rt.block_on(_get_nft_collection(object_type)) {
+        Result::Ok(collection) => {
+            let [name, description, image_url, link, project_url, creator] =
+                DISPLAY_FIELDS.map(|field| optional_c_char(collection.templates.get(field)));
+            CNftCollection {
+                object_type: CString::new(collection.object_type).unwrap().into_raw(),
+                display_object_id: CString::new(collection.display_object_id)
+                    .unwrap()
+                    .into_raw(),
+                version: collection.version,
+                name,
+                description,
+                image_url,
+                link,
+                project_url,
+                creator,
+                templates: CString::new(serde_json::to_string(&collection.templates).unwrap())
+                    .unwrap()
+                    .into_raw(),
+                error: ptr::null_mut(),
+            }
+        }
+        Err(e) => CNftCollection {
+            object_type: ptr::null_mut(),
+            display_object_id: ptr::null_mut(),
+            version: 0,
+            name: ptr::null_mut(),
+            description: ptr::null_mut(),
+            image_url: ptr::null_mut(),
+            link: ptr::null_mut(),
+            project_url: ptr::null_mut(),
+            creator: ptr::null_mut(),
+            templates: ptr::null_mut(),
+            error: CString::new(e.to_string()).unwrap().into_raw(),
+        },
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn free_nft_collection(collection: CNftCollection) {
+    for field in [
+        collection.object_type,
+        collection.display_object_id,
+        collection.name,
+        collection.description,
+        collection.image_url,
+        collection.link,
+        collection.project_url,
+        collection.creator,
+        collection.templates,
+        collection.error,
+    ] {
+        free_c_char(field);
+    }
+}
+
+// Fetchable URL for an NFT image, pass null or "" as gateway to use https://ipfs.io/ipfs/
+#[no_mangle]
+pub extern "C" fn resolve_nft_image_url(
+    url: *const c_char,
+    ipfs_gateway: *const c_char,
+) -> *const c_char {
+    let url = c_str(url).unwrap_or_default();
+    let ipfs_gateway = c_str(ipfs_gateway).unwrap_or_default();
+    CString::new(resolve_image_url(url, ipfs_gateway))
+        .unwrap()
+        .into_raw()
+}
+
+fn c_string_array(array: &CStringArray) -> Vec<String> {
+    (0..array.len as usize)
+        .map(|i| unsafe {
+            CStr::from_ptr(*array.data.add(i))
+                .to_string_lossy()
+                .into_owned()
+        })
+        .collect()
+}
+
+// Transfer any owned object with TransferObjects, no Move function needed
+#[no_mangle]
+pub extern "C" fn transfer_object(
+    sender_address: *const c_char,
+    object_id: *const c_char,
+    recipient_address: *const c_char,
+    gas_budget: u64,
+) -> *const c_char {
+    let object_ids = vec![c_str(object_id).unwrap_or_default().to_string()];
+    transfer_objects_c(sender_address, &object_ids, recipient_address, gas_budget)
+}
+
+// Transfer several owned objects to the same recipient in one transaction
+#[no_mangle]
+pub extern "C" fn transfer_objects(
+    sender_address: *const c_char,
+    object_ids: CStringArray,
+    recipient_address: *const c_char,
+    gas_budget: u64,
+) -> *const c_char {
+    transfer_objects_c(
+        sender_address,
+        &c_string_array(&object_ids),
+        recipient_address,
+        gas_budget,
+    )
+}
+
+fn transfer_objects_c(
+    sender_address: *const c_char,
+    object_ids: &[String],
+    recipient_address: *const c_char,
+    gas_budget: u64,
+) -> *const c_char {
+    let sender_address = c_str(sender_address).unwrap_or_default();
+    let recipient_address = c_str(recipient_address).unwrap_or_default();
+    // Create a new runtime. This step might vary based on the async runtime you are using.
+    let rt = runtime::Runtime::new().unwrap();
+    let message = match rt.block_on(_transfer_objects(
+        sender_address,
+        object_ids,
+        recipient_address,
+        gas_budget,
+    )) {
+        Result::Ok(digest) => format!("Transfer objects success. Transaction digest: {}", digest),
+        Err(e) => format!("Error: {}", e),
+    };
+    CString::new(message).unwrap().into_raw()
+}
+
+// Mint through any Move function. `argument_types[i]` describes `argument_values[i]`:
+// u8..u256, bool, address, string, vector<u8> (hex), vector<string> (JSON array),
+// object (mutable object id) or object_ref (immutable object id)
+#[no_mangle]
+pub extern "C" fn mint_nft_with_call(
+    sender_address: *const c_char,
+    package_id: *const c_char,
+    module: *const c_char,
+    function: *const c_char,
+    type_arguments: CStringArray,
+    argument_types: CStringArray,
+    argument_values: CStringArray,
+    gas_budget: u64,
+) -> *const c_char {
+    let argument_types = c_string_array(&argument_types);
+    let argument_values = c_string_array(&argument_values);
+    let message = if argument_types.len() != argument_values.len() {
+        format!(
+            "Error: {} argument types for {} values",
+            argument_types.len(),
+            argument_values.len()
+        )
+    } else {
+        let arguments: Vec<(String, String)> =
+            argument_types.into_iter().zip(argument_values).collect();
+        let rt = runtime::Runtime::new().unwrap();
+        match rt.block_on(_mint_with_call(
+            c_str(sender_address).unwrap_or_default(),
+            c_str(package_id).unwrap_or_default(),
+            c_str(module).unwrap_or_default(),
+            c_str(function).unwrap_or_default(),
+            &c_string_array(&type_arguments),
+            &arguments,
+            gas_budget,
+        )) {
+            Result::Ok(digest) => format!("Mint NFT success. Transaction digest: {}", digest),
+            Err(e) => format!("Error: {}", e),
+        }
+    };
+    CString::new(message).unwrap().into_raw()
+}
@@ -0,0 +1,555 @@
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::StructTag;
+use std::ffi::{c_char, c_int, CStr, CString};
+use std::ptr;
+use std::str::FromStr;
+use sui_json_rpc_types::{
+    ObjectsPage, SuiObjectData, SuiObjectDataFilter, SuiObjectDataOptions, SuiObjectResponse,
+    SuiObjectResponseError, SuiObjectResponseQuery, SuiPastObjectResponse,
+};
+use sui_types::base_types::{ObjectID, SequenceNumber, SuiAddress};
+use tokio::runtime;
+
+use crate::c_types::{c_str, CStringArray, CSuiObjectData, CSuiObjectDataArray};
+use crate::sui_client::SuiClientSingleton;
+
+/// Outcome of an object lookup, `Exists` is the only state carrying object data.
+#[repr(C)]
+pub enum ObjectStatus {
+    Exists = 0,
+    NotExists = 1,
+    Deleted = 2,
+    VersionNotFound = 3,
+    VersionTooHigh = 4,
+    Error = 5,
+}
+
+pub enum ObjectLookup {
+    Found(SuiObjectData),
+    Missing(ObjectStatus, String),
+}
+
+impl From<SuiObjectResponse> for ObjectLookup {
+    fn from(response: SuiObjectResponse) -> Self {
+        match (response.data, response.error) {
+            (Some(data), _) => ObjectLookup::Found(data),
+            (None, Some(SuiObjectResponseError::NotExists { object_id })) => ObjectLookup::Missing(
+                ObjectStatus::NotExists,
+                format!("Object {} does not exist", object_id),
+            ),
+            (None, Some(SuiObjectResponseError::Deleted { object_id, version, .. })) => {
+                ObjectLookup::Missing(
+                    ObjectStatus::Deleted,
+                    format!("Object {} was deleted at version {}", object_id, version.value()),
+                )
+            }
+            (None, Some(error)) => ObjectLookup::Missing(ObjectStatus::Error, error.to_string()),
+            (None, None) => {
+                ObjectLookup::Missing(ObjectStatus::Error, "Empty object response".to_string())
+            }
+        }
+    }
+}
+
+impl From<SuiPastObjectResponse> for ObjectLookup {
+    fn from(response: SuiPastObjectResponse) -> Self {
+        match response {
+            SuiPastObjectResponse::VersionFound(data) => ObjectLookup::Found(data),
+            SuiPastObjectResponse::ObjectNotExists(object_id) => ObjectLookup::Missing(
+                ObjectStatus::NotExists,
+                format!("Object {} does not exist", object_id),
+            ),
+            SuiPastObjectResponse::ObjectDeleted(object_ref) => ObjectLookup::Missing(
+                ObjectStatus::Deleted,
+                format!(
+                    "Object {} was deleted at version {}",
+                    object_ref.object_id,
+                    object_ref.version.value()
+                ),
+            ),
+            SuiPastObjectResponse::VersionNotFound(object_id, version) => ObjectLookup::Missing(
+                ObjectStatus::VersionNotFound,
+                format!(
+                    "Version {} of object {} was not found",
+                    version.value(),
+                    object_id
+                ),
+            ),
+            SuiPastObjectResponse::VersionTooHigh {
+                object_id,
+                asked_version,
+                latest_version,
+            } => ObjectLookup::Missing(
+                ObjectStatus::VersionTooHigh,
+                format!(
+                    "Version {} of object {} is higher than the latest version {}",
+                    asked_version.value(),
+                    object_id,
+                    latest_version.value()
+                ),
+            ),
+        }
+    }
+}
+
+pub async fn _get_object(
+    object_id: &str,
+    options: SuiObjectDataOptions,
+) -> Result<ObjectLookup, anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    let response = sui_client
+        .read_api()
+        .get_object_with_options(ObjectID::from_str(object_id)?, options)
+        .await?;
+    Ok(ObjectLookup::from(response))
+}
+
+pub async fn _multi_get_objects(
+    object_ids: Vec<String>,
+    options: SuiObjectDataOptions,
+) -> Result<Vec<ObjectLookup>, anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    let object_ids = object_ids
+        .iter()
+        .map(|object_id| ObjectID::from_str(object_id))
+        .collect::<Result<Vec<_>, _>>()?;
+    let responses = sui_client
+        .read_api()
+        .multi_get_object_with_options(object_ids, options)
+        .await?;
+    Ok(responses.into_iter().map(ObjectLookup::from).collect())
+}
+
+pub async fn _get_past_object(
+    object_id: &str,
+    version: u64,
+    options: SuiObjectDataOptions,
+) -> Result<ObjectLookup, anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    let response = sui_client
+        .read_api()
+        .try_get_parsed_past_object(
+            ObjectID::from_str(object_id)?,
+            SequenceNumber::from_u64(version),
+            options,
+        )
+        .await?;
+    Ok(ObjectLookup::from(response))
+}
+
+/// Read one page of the objects owned by `address`, `cursor` is the last object of the previous page.
+pub async fn _get_owned_objects(
+    address: &str,
+    filter: Option<SuiObjectDataFilter>,
+    options: SuiObjectDataOptions,
+    cursor: Option<&str>,
+    limit: Option<usize>,
+) -> Result<ObjectsPage, anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    let cursor = cursor.map(ObjectID::from_str).transpose()?;
+    Ok(sui_client
+        .read_api()
+        .get_owned_objects(
+            SuiAddress::from_str(address)?,
+            Some(SuiObjectResponseQuery::new(filter, Some(options))),
+            cursor,
+            limit,
+        )
+        .await?)
+}
+
+/// Read every page of the objects owned by `address`.
+pub async fn _get_all_owned_objects(
+    address: &str,
+    filter: Option<SuiObjectDataFilter>,
+    options: SuiObjectDataOptions,
+) -> Result<Vec<SuiObjectData>, anyhow::Error> {
+    let mut objects = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let page = _get_owned_objects(
+            address,
+            filter.clone(),
+            options.clone(),
+            cursor.as_deref(),
+            None,
+        )
+        .await?;
+        objects.extend(page.data.into_iter().filter_map(|object| object.data));
+        match page.next_cursor {
+            Some(next_cursor) if page.has_next_page => cursor = Some(next_cursor.to_string()),
+            _ => return Ok(objects),
+        }
+    }
+}
+
+//public functions for ffi
+
+// Which parts of the object the node should return
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct CSuiObjectDataOptions {
+    pub show_type: bool,
+    pub show_owner: bool,
+    pub show_previous_transaction: bool,
+    pub show_display: bool,
+    pub show_content: bool,
+    pub show_bcs: bool,
+    pub show_storage_rebate: bool,
+}
+
+impl From<CSuiObjectDataOptions> for SuiObjectDataOptions {
+    fn from(options: CSuiObjectDataOptions) -> Self {
+        SuiObjectDataOptions {
+            show_type: options.show_type,
+            show_owner: options.show_owner,
+            show_previous_transaction: options.show_previous_transaction,
+            show_display: options.show_display,
+            show_content: options.show_content,
+            show_bcs: options.show_bcs,
+            show_storage_rebate: options.show_storage_rebate,
+        }
+    }
+}
+
+// Owned object filter, invalid inputs are reported when the filter is used
+pub struct CObjectFilter {
+    filter: Result<SuiObjectDataFilter, String>,
+}
+
+impl CObjectFilter {
+    fn new(filter: Result<SuiObjectDataFilter, anyhow::Error>) -> *mut CObjectFilter {
+        Box::into_raw(Box::new(CObjectFilter {
+            filter: filter.map_err(|e| format!("Invalid object filter: {}", e)),
+        }))
+    }
+
+    // Take ownership of the filters of a C array and combine them
+    fn combine(
+        filters: *const *mut CObjectFilter,
+        len: usize,
+        combine: fn(Vec<SuiObjectDataFilter>) -> SuiObjectDataFilter,
+    ) -> *mut CObjectFilter {
+        // Reclaim every box before looking at the results, an invalid filter must not leak the rest.
+        // A null entry is recorded as an error rather than dropped, which would widen the filter
+        let filters: Vec<_> = (0..len)
+            .map(|i| unsafe {
+                let filter = if filters.is_null() {
+                    ptr::null_mut()
+                } else {
+                    *filters.add(i)
+                };
+                if filter.is_null() {
+                    Err(format!("Invalid object filter: null filter at index {}", i))
+                } else {
+                    Box::from_raw(filter).filter
+                }
+            })
+            .collect();
+        let filters: Result<Vec<_>, String> = filters.into_iter().collect();
+        Box::into_raw(Box::new(CObjectFilter {
+            filter: filters.map(combine),
+        }))
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn object_filter_struct_type(struct_type: *const c_char) -> *mut CObjectFilter {
+    CObjectFilter::new(
+        StructTag::from_str(c_str(struct_type).unwrap_or_default())
+            .map(SuiObjectDataFilter::StructType),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn object_filter_package(package_id: *const c_char) -> *mut CObjectFilter {
+    CObjectFilter::new(
+        ObjectID::from_str(c_str(package_id).unwrap_or_default())
+            .map(SuiObjectDataFilter::Package)
+            .map_err(|e| e.into()),
+    )
+}
+
+#[no_mangle]
+pub extern "C" fn object_filter_move_module(
+    package_id: *const c_char,
+    module: *const c_char,
+) -> *mut CObjectFilter {
+    CObjectFilter::new((|| -> Result<SuiObjectDataFilter, anyhow::Error> {
+        Ok(SuiObjectDataFilter::MoveModule {
+            package: ObjectID::from_str(c_str(package_id).unwrap_or_default())?,
+            module: Identifier::new(c_str(module).unwrap_or_default())?,
+        })
+    })())
+}
+
+// The combinators take ownership of the filters passed in
+#[no_mangle]
+pub extern "C" fn object_filter_match_all(
+    filters: *const *mut CObjectFilter,
+    len: usize,
+) -> *mut CObjectFilter {
+    CObjectFilter::combine(filters, len, SuiObjectDataFilter::MatchAll)
+}
+
+#[no_mangle]
+pub extern "C" fn object_filter_match_any(
+    filters: *const *mut CObjectFilter,
+    len: usize,
+) -> *mut CObjectFilter {
+    CObjectFilter::combine(filters, len, SuiObjectDataFilter::MatchAny)
+}
+
+#[no_mangle]
+pub extern "C" fn object_filter_match_none(
+    filters: *const *mut CObjectFilter,
+    len: usize,
+) -> *mut CObjectFilter {
+    CObjectFilter::combine(filters, len, SuiObjectDataFilter::MatchNone)
+}
+
+#[no_mangle]
+pub extern "C" fn destroy_object_filter(filter: *mut CObjectFilter) {
+    if !filter.is_null() {
+        unsafe {
+            let _ = Box::from_raw(filter);
+        }
+    }
+}
+
+#[repr(C)]
+pub struct CSuiObjectPage {
+    objects: CSuiObjectDataArray,
+    next_cursor: *mut c_char,
+    has_next_page: bool,
+    error: *mut c_char,
+}
+
+impl CSuiObjectPage {
+    fn from(result: Result<ObjectsPage, anyhow::Error>) -> Self {
+        match result {
+            Ok(page) => {
+                let objects: Box<[CSuiObjectData]> = page
+                    .data
+                    .into_iter()
+                    .filter_map(|object| object.data)
+                    .map(CSuiObjectData::from)
+                    .collect();
+                let len = objects.len();
+                CSuiObjectPage {
+                    objects: CSuiObjectDataArray {
+                        data: Box::into_raw(objects) as *mut CSuiObjectData,
+                        len,
+                    },
+                    next_cursor: page.next_cursor.map_or(ptr::null_mut(), |cursor| {
+                        CString::new(cursor.to_string()).unwrap().into_raw()
+                    }),
+                    has_next_page: page.has_next_page,
+                    error: ptr::null_mut(),
+                }
+            }
+            Err(e) => CSuiObjectPage {
+                objects: CSuiObjectDataArray {
+                    data: ptr::null_mut(),
+                    len: 0,
+                },
+                next_cursor: ptr::null_mut(),
+                has_next_page: false,
+                error: CString::new(e.to_string()).unwrap().into_raw(),
+            },
+        }
+    }
+}
+
+// One page of owned objects. `filter` may be null and is not consumed, pass the previous
+// `next_cursor` (or null for the first page) and a limit of 0 for the node default
+#[no_mangle]
+pub extern "C" fn get_owned_objects(
+    address: *const c_char,
+    filter: *const CObjectFilter,
+    options: CSuiObjectDataOptions,
+    cursor: *const c_char,
+    limit: usize,
+) -> CSuiObjectPage {
+    let address = c_str(address).unwrap_or_default();
+    let filter = if filter.is_null() {
+        Ok(None)
+    } else {
+        unsafe { &*filter }.filter.clone().map(Some)
+    };
+    let filter = match filter {
+        Ok(filter) => filter,
+        Err(e) => return CSuiObjectPage::from(Err(anyhow::anyhow!(e))),
+    };
+    let cursor = (!cursor.is_null()).then(|| c_str(cursor).unwrap_or_default());
+    let limit = (limit > 0).then_some(limit);
+    let rt = runtime::Runtime::new().unwrap();
+    CSuiObjectPage::from(rt.block_on(_get_owned_objects(
+        address,
+        filter,
+        options.into(),
+        cursor,
+        limit,
+    )))
+}
+
+#[no_mangle]
+pub extern "C" fn free_sui_object_page(page: CSuiObjectPage) {
+    crate::c_types::free_sui_object_data_list(page.objects);
+    unsafe {
+        if !page.next_cursor.is_null() {
+            let _ = CString::from_raw(page.next_cursor);
+        }
+        if !page.error.is_null() {
+            let _ = CString::from_raw(page.error);
+        }
+    }
+}
+
+#[repr(C)]
+pub struct CSuiObjectResult {
+    status: c_int,
+    object: *mut CSuiObjectData,
+    error: *mut c_char,
+}
+
+impl CSuiObjectResult {
+    fn from(result: Result<ObjectLookup, anyhow::Error>) -> Self {
+        match result {
+            Ok(ObjectLookup::Found(data)) => CSuiObjectResult {
+                status: ObjectStatus::Exists as c_int,
+                object: Box::into_raw(Box::new(CSuiObjectData::from(data))),
+                error: ptr::null_mut(),
+            },
+            Ok(ObjectLookup::Missing(status, message)) => CSuiObjectResult {
+                status: status as c_int,
+                object: ptr::null_mut(),
+                error: CString::new(message).unwrap().into_raw(),
+            },
+            Err(e) => CSuiObjectResult {
+                status: ObjectStatus::Error as c_int,
+                object: ptr::null_mut(),
+                error: CString::new(e.to_string()).unwrap().into_raw(),
+            },
+        }
+    }
+
+    fn free(&mut self) {
+        unsafe {
+            if !self.object.is_null() {
+                let mut object = Box::from_raw(self.object);
+                object.free();
+                self.object = ptr::null_mut();
+            }
+            if !self.error.is_null() {
+                let _ = CString::from_raw(self.error);
+                self.error = ptr::null_mut();
+            }
+        }
+    }
+}
+
+#[repr(C)]
+pub struct CSuiObjectResultArray {
+    results: *mut CSuiObjectResult,
+    len: usize,
+    error: *mut c_char,
+}
+
+// Options with every part of the object selected
+#[no_mangle]
+pub extern "C" fn sui_object_data_options_full() -> CSuiObjectDataOptions {
+    CSuiObjectDataOptions {
+        show_type: true,
+        show_owner: true,
+        show_previous_transaction: true,
+        show_display: true,
+        show_content: true,
+        show_bcs: true,
+        show_storage_rebate: true,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn get_object(
+    object_id: *const c_char,
+    options: CSuiObjectDataOptions,
+) -> CSuiObjectResult {
+    let object_id = unsafe {
+        assert!(!object_id.is_null());
+        CStr::from_ptr(object_id).to_str().unwrap_or("Invalid UTF-8")
+    };
+    // Create a new runtime. This step might vary based on the async runtime you are using.
+    let rt = runtime::Runtime::new().unwrap();
+    CSuiObjectResult::from(rt.block_on(_get_object(object_id, options.into())))
+}
+
+// One result per requested id, in the same order
+#[no_mangle]
+pub extern "C" fn multi_get_objects(
+    object_ids: CStringArray,
+    options: CSuiObjectDataOptions,
+) -> CSuiObjectResultArray {
+    let object_ids: Vec<String> = (0..object_ids.len as usize)
+        .map(|i| unsafe {
+            CStr::from_ptr(*object_ids.data.add(i))
+                .to_string_lossy()
+                .into_owned()
+        })
+        .collect();
+    let rt = runtime::Runtime::new().unwrap();
+    match rt.block_on(_multi_get_objects(object_ids, options.into())) {
+        Ok(lookups) => {
+            let results: Box<[CSuiObjectResult]> = lookups
+                .into_iter()
+                .map(|lookup| CSuiObjectResult::from(Ok(lookup)))
+                .collect();
+            let len = results.len();
+            CSuiObjectResultArray {
+                results: Box::into_raw(results) as *mut CSuiObjectResult,
+                len,
+                error: ptr::null_mut(),
+            }
+        }
+        Err(e) => CSuiObjectResultArray {
+            results: ptr::null_mut(),
+            len: 0,
+            error: CString::new(e.to_string()).unwrap().into_raw(),
+        },
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn get_past_object(
+    object_id: *const c_char,
+    version: u64,
+    options: CSuiObjectDataOptions,
+) -> CSuiObjectResult {
+    let object_id = unsafe {
+        assert!(!object_id.is_null());
+        CStr::from_ptr(object_id).to_str().unwrap_or("Invalid UTF-8")
+    };
+    let rt = runtime::Runtime::new().unwrap();
+    CSuiObjectResult::from(rt.block_on(_get_past_object(object_id, version, options.into())))
+}
+
+#[no_mangle]
+pub extern "C" fn free_sui_object_result(mut result: CSuiObjectResult) {
+    result.free();
+}
+
+#[no_mangle]
+pub extern "C" fn free_sui_object_result_array(array: CSuiObjectResultArray) {
+    unsafe {
+        if !array.results.is_null() {
+            let mut results =
+                Box::from_raw(std::slice::from_raw_parts_mut(array.results, array.len));
+            for result in results.iter_mut() {
+                result.free();
+            }
+        }
+        if !array.error.is_null() {
+            let _ = CString::from_raw(array.error);
+        }
+    }
+}
@@ -0,0 +1,223 @@
+// One `sui_free_*` function for every type the library hands to C, the rules are in README.md
+
+use std::ffi::c_char;
+
+use crate::c_types::{
+    self, CAddressResult, CPure, CStringArray, CSuiObjectDataArray, CU8Array, ResultCStringArray,
+};
+use crate::chain_info::{self, CChainInfo};
+use crate::checkpoints::{self, CCheckpoint, CCheckpointPage, CCheckpointTransactionArray};
+use crate::coin_read_api::{self, CBalance, CBalanceArray, CCoinArray};
+use crate::dynamic_fields::{self, CDynamicField, CDynamicFieldPage};
+use crate::kiosk::{self, CKioskContents, CKioskOwnerCapArray};
+use crate::multisig::{self, CMultiSig, CMultiSigInfo, CMultiSigSession};
+use crate::nfts::{self, CNftCollection, CNftPage};
+use crate::object_read_api::{
+    self, CObjectFilter, CSuiObjectPage, CSuiObjectResult, CSuiObjectResultArray,
+};
+use crate::staking::{self, CStakedSuiArray, CValidatorArray};
+use crate::transaction_builder::{self, CArguments, CProgrammableTransactionBuilder, CTypeTags};
+use crate::wallet::{self, DerivedAccountList, ImportResult, KeyResult, Wallet, WalletList};
+use crate::zklogin::{self, CZkLoginAddress, CZkLoginEphemeral};
+
+/// Any `const char *` / `char *` returned by the library, including error messages.
+#[no_mangle]
+pub extern "C" fn sui_free_string(s: *const c_char) {
+    c_types::free_error_string(s);
+}
+
+/// A `CStringArray` returned by the library, the strings and the array.
+#[no_mangle]
+pub extern "C" fn sui_free_string_array(array: CStringArray) {
+    c_types::free_strings(array);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_result_string_array(result: ResultCStringArray) {
+    c_types::free_result_string_array(result);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_address_result(result: CAddressResult) {
+    c_types::free_address_result(result);
+}
+
+/// Bytes and error of a `CU8Array` returned by the library.
+#[no_mangle]
+pub extern "C" fn sui_free_u8_array(array: CU8Array) {
+    c_types::free_u8_array(array);
+}
+
+/// A value from `bsc_basic`, `make_pure` copies it so it can be freed right after.
+#[no_mangle]
+pub extern "C" fn sui_free_pure(value: *mut CPure) {
+    c_types::free_pure(value);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_object_data_array(array: CSuiObjectDataArray) {
+    c_types::free_sui_object_data_list(array);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_object_page(page: CSuiObjectPage) {
+    object_read_api::free_sui_object_page(page);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_object_result(result: CSuiObjectResult) {
+    object_read_api::free_sui_object_result(result);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_object_result_array(array: CSuiObjectResultArray) {
+    object_read_api::free_sui_object_result_array(array);
+}
+
+/// A filter that was not passed to an `object_filter_match_*` combinator.
+#[no_mangle]
+pub extern "C" fn sui_free_object_filter(filter: *mut CObjectFilter) {
+    object_read_api::destroy_object_filter(filter);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_chain_info(info: CChainInfo) {
+    chain_info::free_chain_info(info);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_checkpoint(checkpoint: CCheckpoint) {
+    checkpoints::free_checkpoint(checkpoint);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_checkpoint_page(page: CCheckpointPage) {
+    checkpoints::free_checkpoint_page(page);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_checkpoint_transaction_array(array: CCheckpointTransactionArray) {
+    checkpoints::free_checkpoint_transaction_array(array);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_balance(balance: CBalance) {
+    coin_read_api::free_balance(balance);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_balance_array(array: CBalanceArray) {
+    coin_read_api::free_balance_array(array);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_coin_array(array: CCoinArray) {
+    coin_read_api::free_coin_array(array);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_dynamic_field_page(page: CDynamicFieldPage) {
+    dynamic_fields::free_dynamic_field_page(page);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_dynamic_field(field: CDynamicField) {
+    dynamic_fields::free_dynamic_field(field);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_kiosk_contents(contents: CKioskContents) {
+    kiosk::free_kiosk_contents(contents);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_kiosk_owner_cap_array(array: CKioskOwnerCapArray) {
+    kiosk::free_kiosk_owner_cap_array(array);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_multisig(multisig: CMultiSig) {
+    multisig::free_multisig(multisig);
+}
+
+/// The session of a `CMultiSigSessionResult`, its `error` goes to `sui_free_string`.
+#[no_mangle]
+pub extern "C" fn sui_free_multisig_session(session: *mut CMultiSigSession) {
+    multisig::destroy_multisig_session(session);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_multisig_info(info: CMultiSigInfo) {
+    multisig::free_multisig_info(info);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_nft_page(page: CNftPage) {
+    nfts::free_nft_page(page);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_nft_collection(collection: CNftCollection) {
+    nfts::free_nft_collection(collection);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_validator_array(array: CValidatorArray) {
+    staking::free_validator_array(array);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_staked_sui_array(array: CStakedSuiArray) {
+    staking::free_staked_sui_array(array);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_type_tags(type_tags: *mut CTypeTags) {
+    transaction_builder::destroy_type_tags(type_tags);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_arguments(arguments: *mut CArguments) {
+    transaction_builder::destroy_arguments(arguments);
+}
+
+/// A builder that was not consumed by executing or building its transaction.
+#[no_mangle]
+pub extern "C" fn sui_free_builder(builder: *mut CProgrammableTransactionBuilder) {
+    transaction_builder::destroy_builder(builder);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_wallet(wallet: *mut Wallet) {
+    wallet::free_wallet(wallet);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_wallet_list(wallet_list: WalletList) {
+    wallet::free_wallet_list(wallet_list);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_import_result(result: *mut ImportResult) {
+    wallet::free_import_result(result);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_key_result(result: *mut KeyResult) {
+    wallet::free_key_result(result);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_derived_account_list(account_list: DerivedAccountList) {
+    wallet::free_derived_account_list(account_list);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_zklogin_ephemeral(ephemeral: CZkLoginEphemeral) {
+    zklogin::free_zklogin_ephemeral(ephemeral);
+}
+
+#[no_mangle]
+pub extern "C" fn sui_free_zklogin_address(address: CZkLoginAddress) {
+    zklogin::free_zklogin_address(address);
+}
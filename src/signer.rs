@@ -0,0 +1,224 @@
+use anyhow::anyhow;
+use fastcrypto::traits::{EncodeDecodeBase64, ToFromBytes};
+use once_cell::sync::Lazy;
+use shared_crypto::intent::{Intent, IntentMessage};
+use std::collections::BTreeMap;
+use std::ffi::{c_char, c_int, c_void, CStr, CString};
+use std::ptr;
+use std::sync::{Arc, RwLock};
+use sui_config::{sui_config_dir, SUI_KEYSTORE_FILENAME};
+use sui_keys::keystore::{AccountKeystore, FileBasedKeystore};
+use sui_types::base_types::SuiAddress;
+use sui_types::crypto::{Signature, SuiKeyPair, SuiSignature};
+use sui_types::transaction::TransactionData;
+use zeroize::Zeroizing;
+
+use crate::c_types::{CStringArray, SuiStatus};
+
+/// Largest serialized signature (`flag || signature || public key`) a signer may return.
+pub const MAX_SIGNATURE_LENGTH: usize = 256;
+
+/// Something that can sign on behalf of an address: the local keystore, an HSM, a remote service...
+///
+/// `intent_message` is the BCS encoded `IntentMessage<TransactionData>`, the signer hashes it
+/// with Blake2b-256 and returns the serialized `flag || signature || public key`.
+pub trait TransactionSigner: Send + Sync {
+    fn sign(&self, address: &SuiAddress, intent_message: &[u8])
+        -> Result<Signature, anyhow::Error>;
+}
+
+/// Signs with the keys of the Sui CLI keystore, this is the default signer.
+pub struct KeystoreSigner;
+
+impl TransactionSigner for KeystoreSigner {
+    fn sign(
+        &self,
+        address: &SuiAddress,
+        intent_message: &[u8],
+    ) -> Result<Signature, anyhow::Error> {
+        let keystore = FileBasedKeystore::new(&sui_config_dir()?.join(SUI_KEYSTORE_FILENAME))?;
+        let intent_message: IntentMessage<TransactionData> = bcs::from_bytes(intent_message)?;
+        Ok(Signature::new_secure(
+            &intent_message,
+            keystore.get_key(address)?,
+        ))
+    }
+}
+
+/// Signs with in-memory keys, meant for tests and throwaway accounts.
+pub struct LocalSigner {
+    keys: BTreeMap<SuiAddress, SuiKeyPair>,
+}
+
+impl LocalSigner {
+    pub fn new(keys: Vec<SuiKeyPair>) -> Self {
+        LocalSigner {
+            keys: keys
+                .into_iter()
+                .map(|key| (SuiAddress::from(&key.public()), key))
+                .collect(),
+        }
+    }
+
+    pub fn from_base64_keys(keys: &[impl AsRef<str>]) -> Result<Self, anyhow::Error> {
+        let keys = keys
+            .iter()
+            .map(|key| {
+                SuiKeyPair::decode_base64(key.as_ref())
+                    .map_err(|e| anyhow!("Invalid private key: {}", e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(LocalSigner::new(keys))
+    }
+}
+
+impl TransactionSigner for LocalSigner {
+    fn sign(
+        &self,
+        address: &SuiAddress,
+        intent_message: &[u8],
+    ) -> Result<Signature, anyhow::Error> {
+        let key = self
+            .keys
+            .get(address)
+            .ok_or_else(|| anyhow!("No local key for address {}", address))?;
+        let intent_message: IntentMessage<TransactionData> = bcs::from_bytes(intent_message)?;
+        Ok(Signature::new_secure(&intent_message, key))
+    }
+}
+
+/// C signing callback.
+///
+/// Receives the signer address, the intent message bytes and a `signature` buffer whose capacity
+/// is passed in `*signature_len`. It writes the serialized signature, stores its length in
+/// `*signature_len` and returns 0, any other value is reported as a signing failure.
+pub type SignCallback = extern "C" fn(
+    address: *const c_char,
+    intent_message: *const u8,
+    intent_message_len: usize,
+    signature: *mut u8,
+    signature_len: *mut usize,
+    user_data: *mut c_void,
+) -> c_int;
+
+/// Forwards signing requests to a callback registered from C.
+pub struct CallbackSigner {
+    callback: SignCallback,
+    user_data: *mut c_void,
+}
+
+// The callback owner is responsible for making `user_data` usable from any thread.
+unsafe impl Send for CallbackSigner {}
+unsafe impl Sync for CallbackSigner {}
+
+impl TransactionSigner for CallbackSigner {
+    fn sign(
+        &self,
+        address: &SuiAddress,
+        intent_message: &[u8],
+    ) -> Result<Signature, anyhow::Error> {
+        let address = CString::new(address.to_string())?;
+        let mut signature = [0u8; MAX_SIGNATURE_LENGTH];
+        let mut signature_len = signature.len();
+        let status = (self.callback)(
+            address.as_ptr(),
+            intent_message.as_ptr(),
+            intent_message.len(),
+            signature.as_mut_ptr(),
+            &mut signature_len,
+            self.user_data,
+        );
+        if status != 0 {
+            return Err(anyhow!("Signer callback failed with status {}", status));
+        }
+        if signature_len > signature.len() {
+            return Err(anyhow!("Signer callback returned {} bytes", signature_len));
+        }
+        Signature::from_bytes(&signature[..signature_len])
+            .map_err(|e| anyhow!("Invalid signature from signer callback: {}", e))
+    }
+}
+
+static SIGNER: Lazy<RwLock<Arc<dyn TransactionSigner>>> =
+    Lazy::new(|| RwLock::new(Arc::new(KeystoreSigner)));
+
+/// Replace the signer used by every executor of the library.
+pub fn set_signer(signer: Arc<dyn TransactionSigner>) {
+    *SIGNER.write().unwrap() = signer;
+}
+
+pub fn active_signer() -> Arc<dyn TransactionSigner> {
+    SIGNER.read().unwrap().clone()
+}
+
+/// Sign an intent message with the active signer, checking the signature really belongs to `address`.
+pub fn sign_intent_message(
+    address: &SuiAddress,
+    intent_message: &IntentMessage<TransactionData>,
+) -> Result<Signature, anyhow::Error> {
+    let signature = active_signer().sign(address, &bcs::to_bytes(intent_message)?)?;
+    signature
+        .verify_secure(intent_message, *address, signature.scheme())
+        .map_err(|e| {
+            anyhow!(
+                "Signer returned an invalid signature for {}: {}",
+                address,
+                e
+            )
+        })?;
+    Ok(signature)
+}
+
+/// Sign transaction data for `address` with the active signer.
+pub fn sign_transaction(
+    address: &SuiAddress,
+    tx_data: &TransactionData,
+) -> Result<Signature, anyhow::Error> {
+    sign_intent_message(
+        address,
+        &IntentMessage::new(Intent::sui_transaction(), tx_data.clone()),
+    )
+}
+
+//public functions for ffi
+
+// Route every signature through `callback`, `user_data` is passed back untouched
+#[no_mangle]
+pub extern "C" fn register_signer_callback(callback: SignCallback, user_data: *mut c_void) {
+    set_signer(Arc::new(CallbackSigner {
+        callback,
+        user_data,
+    }));
+}
+
+// Sign with the given base64 `flag || privkey` keys instead of the keystore, returns null or an error
+#[no_mangle]
+pub extern "C" fn register_local_signer(private_keys: CStringArray) -> SuiStatus {
+    if private_keys.len > 0 && private_keys.data.is_null() {
+        return CString::new("Private key array data is null")
+            .unwrap()
+            .into_raw();
+    }
+    let keys: Vec<Zeroizing<String>> = (0..private_keys.len.max(0) as usize)
+        .map(|i| unsafe {
+            Zeroizing::new(
+                CStr::from_ptr(*private_keys.data.add(i))
+                    .to_string_lossy()
+                    .into_owned(),
+            )
+        })
+        .collect();
+    match LocalSigner::from_base64_keys(&keys) {
+        Ok(signer) => {
+            set_signer(Arc::new(signer));
+            ptr::null()
+        }
+        Err(e) => CString::new(e.to_string()).unwrap().into_raw(),
+    }
+}
+
+// Go back to signing with the local keystore
+#[no_mangle]
+pub extern "C" fn reset_signer() {
+    set_signer(Arc::new(KeystoreSigner));
+}
@@ -0,0 +1,354 @@
+use std::collections::HashMap;
+use std::ffi::c_char;
+use std::ptr;
+use std::str::FromStr;
+use sui_json_rpc_types::StakeStatus;
+use sui_types::base_types::SuiAddress;
+use sui_types::programmable_transaction_builder::ProgrammableTransactionBuilder;
+use sui_types::transaction::{Argument, Command, ObjectArg};
+use sui_types::{Identifier, SUI_SYSTEM_PACKAGE_ID};
+use tokio::runtime;
+
+use crate::c_types::{c_str, free_c_char, to_c_char};
+use crate::sui_client::SuiClientSingleton;
+use crate::transaction_builder::{_execute_transaction, _object_arg};
+
+const SUI_SYSTEM_MODULE: &str = "sui_system";
+
+pub struct Validator {
+    pub address: String,
+    pub name: String,
+    pub description: String,
+    pub image_url: String,
+    pub project_url: String,
+    pub commission_rate: u64,
+    pub next_epoch_commission_rate: u64,
+    pub apy: f64,
+    pub staking_pool_id: String,
+    pub total_stake: u64,
+    pub voting_power: u64,
+    pub gas_price: u64,
+}
+
+pub struct StakedSui {
+    pub staked_sui_id: String,
+    pub validator_address: String,
+    pub staking_pool_id: String,
+    pub principal: u64,
+    pub stake_request_epoch: u64,
+    pub stake_active_epoch: u64,
+    pub status: String,
+    pub estimated_reward: u64,
+}
+
+/// Active validators of the current epoch with their APY, commission rates are in basis points.
+pub async fn _get_validators() -> Result<(u64, Vec<Validator>), anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    let system_state = sui_client
+        .governance_api()
+        .get_latest_sui_system_state()
+        .await?;
+    let apys: HashMap<SuiAddress, f64> = sui_client
+        .governance_api()
+        .get_validators_apy()
+        .await?
+        .apys
+        .into_iter()
+        .map(|apy| (apy.address, apy.apy))
+        .collect();
+    let validators = system_state
+        .active_validators
+        .into_iter()
+        .map(|validator| Validator {
+            address: validator.sui_address.to_string(),
+            apy: apys.get(&validator.sui_address).copied().unwrap_or_default(),
+            name: validator.name,
+            description: validator.description,
+            image_url: validator.image_url,
+            project_url: validator.project_url,
+            commission_rate: validator.commission_rate,
+            next_epoch_commission_rate: validator.next_epoch_commission_rate,
+            staking_pool_id: validator.staking_pool_id.to_string(),
+            total_stake: validator.staking_pool_sui_balance,
+            voting_power: validator.voting_power,
+            gas_price: validator.gas_price,
+        })
+        .collect();
+    Ok((system_state.epoch, validators))
+}
+
+/// `StakedSui` objects of an address, rewards are only estimated for active stakes.
+pub async fn _get_stakes(address: &str) -> Result<Vec<StakedSui>, anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    let delegated_stakes = sui_client
+        .governance_api()
+        .get_stakes(SuiAddress::from_str(address)?)
+        .await?;
+    let mut stakes = Vec::new();
+    for delegated_stake in delegated_stakes {
+        for stake in delegated_stake.stakes {
+            let (status, estimated_reward) = match stake.status {
+                StakeStatus::Pending => ("Pending", 0),
+                StakeStatus::Active { estimated_reward } => ("Active", estimated_reward),
+                StakeStatus::Unstaked => ("Unstaked", 0),
+            };
+            stakes.push(StakedSui {
+                staked_sui_id: stake.staked_sui_id.to_string(),
+                validator_address: delegated_stake.validator_address.to_string(),
+                staking_pool_id: delegated_stake.staking_pool.to_string(),
+                principal: stake.principal,
+                stake_request_epoch: stake.stake_request_epoch,
+                stake_active_epoch: stake.stake_active_epoch,
+                status: status.to_string(),
+                estimated_reward,
+            });
+        }
+    }
+    Ok(stakes)
+}
+
+fn sui_system_call(
+    ptb: &mut ProgrammableTransactionBuilder,
+    function: &str,
+    arguments: Vec<Argument>,
+) -> Result<Argument, anyhow::Error> {
+    Ok(ptb.programmable_move_call(
+        SUI_SYSTEM_PACKAGE_ID,
+        Identifier::new(SUI_SYSTEM_MODULE)?,
+        Identifier::new(function)?,
+        vec![],
+        arguments,
+    ))
+}
+
+/// Stake `amount` MIST from the gas coin with a validator, the network minimum is 1 SUI.
+pub async fn _stake_sui(
+    sender: &str,
+    validator_address: &str,
+    amount: u64,
+    gas_budget: u64,
+) -> Result<String, anyhow::Error> {
+    let validator_address = SuiAddress::from_str(validator_address)?;
+    let mut ptb = ProgrammableTransactionBuilder::new();
+    let system_state = ptb.obj(ObjectArg::SUI_SYSTEM_MUT)?;
+    let amount = ptb.pure(amount)?;
+    let stake = ptb.command(Command::SplitCoins(Argument::GasCoin, vec![amount]));
+    let validator = ptb.pure(validator_address)?;
+    sui_system_call(
+        &mut ptb,
+        "request_add_stake",
+        vec![system_state, stake, validator],
+    )?;
+    let response = _execute_transaction(sender, ptb.finish(), gas_budget).await?;
+    Ok(response.digest.to_string())
+}
+
+/// Withdraw a `StakedSui` object, principal and rewards are returned to the sender.
+pub async fn _withdraw_stake(
+    sender: &str,
+    staked_sui_id: &str,
+    gas_budget: u64,
+) -> Result<String, anyhow::Error> {
+    let staked_sui = _object_arg(staked_sui_id, true).await?;
+    let mut ptb = ProgrammableTransactionBuilder::new();
+    let system_state = ptb.obj(ObjectArg::SUI_SYSTEM_MUT)?;
+    let staked_sui = ptb.obj(staked_sui)?;
+    sui_system_call(
+        &mut ptb,
+        "request_withdraw_stake",
+        vec![system_state, staked_sui],
+    )?;
+    let response = _execute_transaction(sender, ptb.finish(), gas_budget).await?;
+    Ok(response.digest.to_string())
+}
+
+//public functions for ffi
+
+#[repr(C)]
+pub struct CValidator {
+    address: *mut c_char,
+    name: *mut c_char,
+    description: *mut c_char,
+    image_url: *mut c_char,
+    project_url: *mut c_char,
+    commission_rate: u64,
+    next_epoch_commission_rate: u64,
+    apy: f64,
+    staking_pool_id: *mut c_char,
+    total_stake: u64,
+    voting_power: u64,
+    gas_price: u64,
+}
+
+#[repr(C)]
+pub struct CValidatorArray {
+    validators: *mut CValidator,
+    len: usize,
+    epoch: u64,
+    error: *mut c_char,
+}
+
+#[repr(C)]
+pub struct CStakedSui {
+    staked_sui_id: *mut c_char,
+    validator_address: *mut c_char,
+    staking_pool_id: *mut c_char,
+    principal: u64,
+    stake_request_epoch: u64,
+    stake_active_epoch: u64,
+    status: *mut c_char,
+    estimated_reward: u64,
+}
+
+#[repr(C)]
+pub struct CStakedSuiArray {
+    stakes: *mut CStakedSui,
+    len: usize,
+    error: *mut c_char,
+}
+
+#[no_mangle]
+pub extern "C" fn get_validators() -> CValidatorArray {
+    // Create a new runtime. This step might vary based on the async runtime you are using.
+    let rt = runtime::Runtime::new().unwrap();
+    match rt.block_on(_get_validators()) {
+        Ok((epoch, validators)) => {
+            let validators: Box<[CValidator]> = validators
+                .into_iter()
+                .map(|validator| CValidator {
+                    address: to_c_char(validator.address),
+                    name: to_c_char(validator.name),
+                    description: to_c_char(validator.description),
+                    image_url: to_c_char(validator.image_url),
+                    project_url: to_c_char(validator.project_url),
+                    commission_rate: validator.commission_rate,
+                    next_epoch_commission_rate: validator.next_epoch_commission_rate,
+                    apy: validator.apy,
+                    staking_pool_id: to_c_char(validator.staking_pool_id),
+                    total_stake: validator.total_stake,
+                    voting_power: validator.voting_power,
+                    gas_price: validator.gas_price,
+                })
+                .collect();
+            let len = validators.len();
+            CValidatorArray {
+                validators: Box::into_raw(validators) as *mut CValidator,
+                len,
+                epoch,
+                error: ptr::null_mut(),
+            }
+        }
+        Err(e) => CValidatorArray {
+            validators: ptr::null_mut(),
+            len: 0,
+            epoch: 0,
+            error: to_c_char(e.to_string()),
+        },
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn free_validator_array(array: CValidatorArray) {
+    if !array.validators.is_null() {
+        unsafe {
+            let validators =
+                Box::from_raw(std::slice::from_raw_parts_mut(array.validators, array.len));
+            for validator in validators.iter() {
+                free_c_char(validator.address);
+                free_c_char(validator.name);
+                free_c_char(validator.description);
+                free_c_char(validator.image_url);
+                free_c_char(validator.project_url);
+                free_c_char(validator.staking_pool_id);
+            }
+        }
+    }
+    free_c_char(array.error);
+}
+
+#[no_mangle]
+pub extern "C" fn get_stakes(address: *const c_char) -> CStakedSuiArray {
+    let rt = runtime::Runtime::new().unwrap();
+    match rt.block_on(_get_stakes(c_str(address).unwrap_or_default())) {
+        Ok(stakes) => {
+            let stakes: Box<[CStakedSui]> = stakes
+                .into_iter()
+                .map(|stake| CStakedSui {
+                    staked_sui_id: to_c_char(stake.staked_sui_id),
+                    validator_address: to_c_char(stake.validator_address),
+                    staking_pool_id: to_c_char(stake.staking_pool_id),
+                    principal: stake.principal,
+                    stake_request_epoch: stake.stake_request_epoch,
+                    stake_active_epoch: stake.stake_active_epoch,
+                    status: to_c_char(stake.status),
+                    estimated_reward: stake.estimated_reward,
+                })
+                .collect();
+            let len = stakes.len();
+            CStakedSuiArray {
+                stakes: Box::into_raw(stakes) as *mut CStakedSui,
+                len,
+                error: ptr::null_mut(),
+            }
+        }
+        Err(e) => CStakedSuiArray {
+            stakes: ptr::null_mut(),
+            len: 0,
+            error: to_c_char(e.to_string()),
+        },
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn free_staked_sui_array(array: CStakedSuiArray) {
+    if !array.stakes.is_null() {
+        unsafe {
+            let stakes = Box::from_raw(std::slice::from_raw_parts_mut(array.stakes, array.len));
+            for stake in stakes.iter() {
+                free_c_char(stake.staked_sui_id);
+                free_c_char(stake.validator_address);
+                free_c_char(stake.staking_pool_id);
+                free_c_char(stake.status);
+            }
+        }
+    }
+    free_c_char(array.error);
+}
+
+#[no_mangle]
+pub extern "C" fn stake_sui(
+    sender: *const c_char,
+    validator_address: *const c_char,
+    amount: u64,
+    gas_budget: u64,
+) -> *const c_char {
+    let rt = runtime::Runtime::new().unwrap();
+    let message = match rt.block_on(_stake_sui(
+        c_str(sender).unwrap_or_default(),
+        c_str(validator_address).unwrap_or_default(),
+        amount,
+        gas_budget,
+    )) {
+        Ok(digest) => format!("Stake success. Transaction digest: {}", digest),
+        Err(e) => format!("Error: {}", e),
+    };
+    to_c_char(message)
+}
+
+#[no_mangle]
+pub extern "C" fn withdraw_stake(
+    sender: *const c_char,
+    staked_sui_id: *const c_char,
+    gas_budget: u64,
+) -> *const c_char {
+    let rt = runtime::Runtime::new().unwrap();
+    let message = match rt.block_on(_withdraw_stake(
+        c_str(sender).unwrap_or_default(),
+        c_str(staked_sui_id).unwrap_or_default(),
+        gas_budget,
+    )) {
+        Ok(digest) => format!("Withdraw stake success. Transaction digest: {}", digest),
+        Err(e) => format!("Error: {}", e),
+    };
+    to_c_char(message)
+}
@@ -2,15 +2,15 @@ use anyhow::{Result, anyhow};
 use futures::{future, stream::StreamExt};
 use once_cell::sync::OnceCell;
 use tokio::runtime;
-use std::ffi::{c_char, c_int, CString};
+use std::ffi::{c_char, CStr, CString};
 use tokio::sync::Mutex;
-use sui_sdk::{SuiClient, SuiClientBuilder};
+use sui_sdk::{SuiClient, SuiClientBuilder, SUI_DEVNET_URL, SUI_TESTNET_URL};
 use sui_sdk::{
     error::SuiRpcResult,
     types::base_types::{ObjectID, SuiAddress},
 };
 
-use crate::c_types::{CStringArray, ResultCStringArray};
+use crate::c_types::ResultCStringArray;
 
 /// Returns a list of RPC methods supported by the node the client is connected to.
 pub async fn _available_rpc_methods() -> Result<Vec<String>, anyhow::Error> {
@@ -69,6 +69,38 @@ pub enum SuiEnvironment {
     Testnet,
     Devnet,
     Mainnet,
+    /// Any other fullnode, e.g. a localnet or the mock server of the integration tests.
+    Custom(String),
+}
+
+impl SuiEnvironment {
+    pub fn name(&self) -> &'static str {
+        match self {
+            SuiEnvironment::Testnet => "testnet",
+            SuiEnvironment::Devnet => "devnet",
+            SuiEnvironment::Mainnet => "mainnet",
+            SuiEnvironment::Custom(_) => "custom",
+        }
+    }
+
+    /// JSON-RPC url of the fullnode the client of this environment talks to.
+    pub fn rpc_url(&self) -> &str {
+        match self {
+            SuiEnvironment::Testnet => SUI_TESTNET_URL,
+            SuiEnvironment::Devnet => SUI_DEVNET_URL,
+            SuiEnvironment::Mainnet => "https://fullnode.mainnet.sui.io:443",
+            SuiEnvironment::Custom(url) => url,
+        }
+    }
+
+    /// Gas faucet of this environment, mainnet and custom nodes have none.
+    pub fn faucet_url(&self) -> Option<&'static str> {
+        match self {
+            SuiEnvironment::Testnet => Some("https://faucet.testnet.sui.io/v1/gas"),
+            SuiEnvironment::Devnet => Some("https://faucet.devnet.sui.io/gas"),
+            SuiEnvironment::Mainnet | SuiEnvironment::Custom(_) => None,
+        }
+    }
 }
 
 pub struct SuiClientSingleton {
@@ -94,6 +126,15 @@ impl SuiClientSingleton {
         Ok(())
     }
 
+    /// The configured environment, Devnet until one of the `build_*` functions is called.
+    pub async fn environment(&self) -> SuiEnvironment {
+        self.environment
+            .lock()
+            .await
+            .clone()
+            .unwrap_or(SuiEnvironment::Devnet)
+    }
+
     pub async fn get_or_init(&self) -> Result<SuiClient> {
         let mut env_guard = self.environment.lock().await;
         let environment = if let Some(env) = &*env_guard {
@@ -108,11 +149,9 @@ impl SuiClientSingleton {
         if let Some(client) = &*client_guard {
             Ok(client.clone())
         } else {
-            let client = match environment {
-                SuiEnvironment::Testnet => SuiClientBuilder::default().build_testnet().await?,
-                SuiEnvironment::Devnet => SuiClientBuilder::default().build_devnet().await?,
-                SuiEnvironment::Mainnet => SuiClientBuilder::default().build("https://fullnode.mainnet.sui.io:443").await?,
-            };
+            let client = SuiClientBuilder::default()
+                .build(environment.rpc_url())
+                .await?;
             *client_guard = Some(client.clone());
             Ok(client)
         }
@@ -233,83 +272,51 @@ pub extern "C" fn build_devnet() -> i32 {
     })
 }
 
+pub async fn _build_custom(rpc_url: &str) -> Result<()> {
+    let sui_client_singleton = SuiClientSingleton::instance();
+
+    // Initialize environment only once
+    match sui_client_singleton
+        .initialize(SuiEnvironment::Custom(rpc_url.to_string()))
+        .await
+    {
+        std::result::Result::Ok(()) => println!("Environment initialized to {}.", rpc_url),
+        Err(e) => eprintln!("Failed to initialize environment: {:?}", e),
+    }
+
+    Ok(())
+}
 
+// Point the shared client at any fullnode JSON-RPC url, e.g. http://127.0.0.1:9000 for a localnet
 #[no_mangle]
-pub extern "C" fn available_rpc_methods() -> ResultCStringArray {
+pub extern "C" fn build_custom(rpc_url: *const c_char) -> i32 {
+    let rpc_url = unsafe {
+        assert!(!rpc_url.is_null());
+        CStr::from_ptr(rpc_url).to_str().unwrap_or("Invalid UTF-8")
+    };
     // Create a new runtime. This step might vary based on the async runtime you are using.
     let rt = runtime::Runtime::new().unwrap();
     // Block on the async function and translate the Result to a C-friendly format.
     rt.block_on(async {
-        match _available_rpc_methods().await {
-            Ok(strings) => {
-                let mut c_strings: Vec<*const c_char> = strings
-                    .into_iter()
-                    .map(|s| CString::new(s).unwrap().into_raw() as *const c_char)
-                    .collect();
-
-                c_strings.shrink_to_fit();
-                let data = c_strings.as_ptr();
-                let len = c_strings.len() as c_int;
-
-                // Prevent Rust from freeing the CString pointers
-                std::mem::forget(c_strings);
-
-                ResultCStringArray {
-                    strings: CStringArray { data, len },
-                    error: std::ptr::null(),
-                }
-            }
-            Err(e) => {
-                let error_message = CString::new(e.to_string()).unwrap().into_raw();
-                ResultCStringArray {
-                    strings: CStringArray {
-                        data: std::ptr::null(),
-                        len: 0,
-                    },
-                    error: error_message,
-                }
-            }
+        match _build_custom(rpc_url).await {
+            std::result::Result::Ok(_) => 0,  // Return 0 to indicate success.
+            Err(_) => 1, // Return 1 or other error codes to indicate an error.
         }
     })
 }
 
+#[no_mangle]
+pub extern "C" fn available_rpc_methods() -> ResultCStringArray {
+    // Create a new runtime. This step might vary based on the async runtime you are using.
+    let rt = runtime::Runtime::new().unwrap();
+    ResultCStringArray::from(rt.block_on(_available_rpc_methods()))
+}
+
 #[no_mangle]
 pub extern "C" fn available_subscriptions() -> ResultCStringArray {
     // Create a new runtime. This step might vary based on the async runtime you are using.
     let rt = runtime::Runtime::new().unwrap();
-    // Block on the async function and translate the Result to a C-friendly format.
-    rt.block_on(async {
-        match _available_subscriptions().await {
-            Ok(strings) => {
-                let mut c_strings: Vec<*const c_char> = strings
-                    .into_iter()
-                    .map(|s| CString::new(s).unwrap().into_raw() as *const c_char)
-                    .collect();
-
-                c_strings.shrink_to_fit();
-                let data = c_strings.as_ptr();
-                let len = c_strings.len() as c_int;
-
-                // Prevent Rust from freeing the CString pointers
-                std::mem::forget(c_strings);
-
-                ResultCStringArray {
-                    strings: CStringArray { data, len },
-                    error: std::ptr::null(),
-                }
-            }
-            Err(e) => {
-                let error_message = CString::new(e.to_string()).unwrap().into_raw();
-                ResultCStringArray {
-                    strings: CStringArray {
-                        data: std::ptr::null(),
-                        len: 0,
-                    },
-                    error: error_message,
-                }
-            }
-        }
-    })
+    ResultCStringArray::from(rt.block_on(_available_subscriptions()))
 }
 
 #[no_mangle]
@@ -1,11 +1,23 @@
 
-mod c_types;
-mod balance;
-mod coin_read_api;
-mod multisig;
-mod nfts;
-mod sui_client;
-mod transactions;
-mod utils;
-mod wallet;
-mod transaction_builder;
\ No newline at end of file
+pub mod c_types;
+pub mod balance;
+pub mod chain_info;
+pub mod checkpoints;
+pub mod coin_read_api;
+pub mod dynamic_fields;
+pub mod kiosk;
+pub mod multisig;
+pub mod name_service;
+pub mod nfts;
+pub mod object_read_api;
+pub mod signer;
+pub mod staking;
+pub mod sui_client;
+pub mod transactions;
+pub mod utils;
+pub mod wallet;
+pub mod transaction_builder;
+pub mod zklogin;
+pub mod ownership;
+pub mod handles;
+pub mod json_rpc;
\ No newline at end of file
@@ -4,22 +4,26 @@ use std::{
 };
 
 use anyhow::Result;
-use shared_crypto::intent::Intent;
-use sui_config::{sui_config_dir, SUI_KEYSTORE_FILENAME};
-use sui_json_rpc_types::{Coin, SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions};
-use sui_keys::keystore::{AccountKeystore, FileBasedKeystore};
+use sui_json_rpc_types::{
+    Coin, SuiObjectDataOptions, SuiTransactionBlockResponse, SuiTransactionBlockResponseOptions,
+};
 use sui_types::{
     base_types::{ObjectID, SuiAddress},
+    object::Owner,
     programmable_transaction_builder::ProgrammableTransactionBuilder,
     quorum_driver_types::ExecuteTransactionRequestType,
     transaction::{
-        Argument, Command, ProgrammableMoveCall, ProgrammableTransaction, Transaction,
+        Argument, Command, ObjectArg, ProgrammableMoveCall, ProgrammableTransaction, Transaction,
         TransactionData,
     },
     Identifier, TypeTag,
 };
 
-use crate::{c_types::CPure, sui_client::SuiClientSingleton};
+use crate::{
+    c_types::{c_str, to_c_char, CPure, CU8Array, SuiStatus},
+    signer::sign_transaction,
+    sui_client::SuiClientSingleton,
+};
 
 // #[repr(C)]
 pub struct CProgrammableTransactionBuilder {
@@ -71,8 +75,10 @@ pub extern "C" fn add_type_tag(type_tags: *mut CTypeTags, tag: *const c_char) {
 
 #[no_mangle]
 pub extern "C" fn destroy_type_tags(type_tags: *mut CTypeTags) {
-    unsafe {
-        Box::from_raw(type_tags);
+    if !type_tags.is_null() {
+        unsafe {
+            drop(Box::from_raw(type_tags));
+        }
     }
 }
 
@@ -83,8 +89,10 @@ pub extern "C" fn create_arguments() -> *mut CArguments {
 
 #[no_mangle]
 pub extern "C" fn destroy_arguments(arguments: *mut CArguments) {
-    unsafe {
-        Box::from_raw(arguments);
+    if !arguments.is_null() {
+        unsafe {
+            drop(Box::from_raw(arguments));
+        }
     }
 }
 
@@ -127,6 +135,36 @@ pub extern "C" fn make_pure(
     arguments.arguments.push(argument);
 }
 
+// Add an object input, shared objects are resolved to their initial shared version.
+// Returns null on success or an error message
+#[no_mangle]
+pub extern "C" fn make_object(
+    builder: *mut CProgrammableTransactionBuilder,
+    arguments: *mut CArguments,
+    object_id: *const c_char,
+    is_mutable: bool,
+) -> SuiStatus {
+    let (Some(builder), Some(arguments)) =
+        (unsafe { builder.as_mut() }, unsafe { arguments.as_mut() })
+    else {
+        return to_c_char("Invalid builder or arguments: null".to_string());
+    };
+    let Some(object_id) = c_str(object_id) else {
+        return to_c_char("Invalid object id: null or not valid UTF-8".to_string());
+    };
+    let result = tokio::runtime::Runtime::new()
+        .unwrap()
+        .block_on(_object_arg(object_id, is_mutable))
+        .and_then(|object_arg| builder.builder.obj(object_arg));
+    match result {
+        Ok(argument) => {
+            arguments.arguments.push(argument);
+            std::ptr::null()
+        }
+        Err(e) => to_c_char(e.to_string()),
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn create_builder() -> *mut CProgrammableTransactionBuilder {
     Box::into_raw(Box::new(CProgrammableTransactionBuilder::new()))
@@ -134,8 +172,10 @@ pub extern "C" fn create_builder() -> *mut CProgrammableTransactionBuilder {
 
 #[no_mangle]
 pub extern "C" fn destroy_builder(builder: *mut CProgrammableTransactionBuilder) {
-    unsafe {
-        Box::from_raw(builder);
+    if !builder.is_null() {
+        unsafe {
+            drop(Box::from_raw(builder));
+        }
     }
 }
 
@@ -213,11 +253,11 @@ pub extern "C" fn add_merge_coins_command(
         .command(Command::MergeCoins(coin[0], agreements));
 }
 
-pub async fn _execute_transaction(
+pub async fn _build_transaction_data(
     sender: &str,
     transaction_data: ProgrammableTransaction,
     gas_budget: u64,
-) -> Result<(SuiTransactionBlockResponse), anyhow::Error> {
+) -> Result<TransactionData, anyhow::Error> {
     let sui_client = SuiClientSingleton::instance().get_or_init().await?;
     let sender_address = SuiAddress::from_str(sender)?;
     let coins = sui_client
@@ -227,17 +267,47 @@ pub async fn _execute_transaction(
     let selected_gas_coins: Vec<_> = coins.data.iter().map(|coin| coin.object_ref()).collect();
     let gas_price = sui_client.read_api().get_reference_gas_price().await?;
     // create the transaction data that will be sent to the network
-    let tx_data = TransactionData::new_programmable(
+    Ok(TransactionData::new_programmable(
         sender_address,
         selected_gas_coins,
         transaction_data,
         gas_budget,
         gas_price,
-    );
+    ))
+}
+
+/// Resolve an object into a transaction input, shared objects use their initial shared version.
+pub async fn _object_arg(object_id: &str, mutable: bool) -> Result<ObjectArg, anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    let object_id = ObjectID::from_str(object_id)?;
+    let object = sui_client
+        .read_api()
+        .get_object_with_options(object_id, SuiObjectDataOptions::new().with_owner())
+        .await?
+        .into_object()?;
+    Ok(match object.owner {
+        Some(Owner::Shared {
+            initial_shared_version,
+        }) => ObjectArg::SharedObject {
+            id: object_id,
+            initial_shared_version,
+            mutable,
+        },
+        _ => ObjectArg::ImmOrOwnedObject(object.object_ref()),
+    })
+}
+
+pub async fn _execute_transaction(
+    sender: &str,
+    transaction_data: ProgrammableTransaction,
+    gas_budget: u64,
+) -> Result<(SuiTransactionBlockResponse), anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    let sender_address = SuiAddress::from_str(sender)?;
+    let tx_data = _build_transaction_data(sender, transaction_data, gas_budget).await?;
 
     // 4) sign transaction
-    let keystore = FileBasedKeystore::new(&sui_config_dir()?.join(SUI_KEYSTORE_FILENAME))?;
-    let signature = keystore.sign_secure(&sender_address, &tx_data, Intent::sui_transaction())?;
+    let signature = sign_transaction(&sender_address, &tx_data)?;
 
     // 5) execute the transaction
     print!("Executing the transaction...");
@@ -280,6 +350,29 @@ pub extern "C" fn execute_transaction(
     c_string.into_raw()
 }
 
+// build the transaction data without signing it, e.g. to collect multisig signatures
+#[no_mangle]
+pub extern "C" fn build_transaction_data(
+    builder: *mut CProgrammableTransactionBuilder,
+    sender: *const c_char,
+    gas_budget: c_ulonglong,
+) -> CU8Array {
+    let builder = unsafe { Box::from_raw(builder) };
+    let sender_str = unsafe { CStr::from_ptr(sender).to_str().unwrap() };
+
+    let transaction_data = builder.builder.finish();
+    let result = tokio::runtime::Runtime::new()
+        .unwrap()
+        .block_on(async move {
+            _build_transaction_data(&sender_str, transaction_data, gas_budget).await
+        });
+
+    match result {
+        Ok(tx_data) => CU8Array::from(bcs::to_bytes(&tx_data).unwrap()),
+        Err(e) => CU8Array::from_error(e.to_string()),
+    }
+}
+
 pub async fn _execute_transaction_allow_sponser(
     sender: &str,
     transaction_data: ProgrammableTransaction,
@@ -306,10 +399,8 @@ pub async fn _execute_transaction_allow_sponser(
     );
 
     // 4) sign transaction
-    let keystore = FileBasedKeystore::new(&sui_config_dir()?.join(SUI_KEYSTORE_FILENAME))?;
-    let signature = keystore.sign_secure(&sender_address, &tx_data, Intent::sui_transaction())?;
-    let sponser_signature =
-        keystore.sign_secure(&sponser_address, &tx_data, Intent::sui_transaction())?;
+    let signature = sign_transaction(&sender_address, &tx_data)?;
+    let sponser_signature = sign_transaction(&sponser_address, &tx_data)?;
 
     // 5) execute the transaction
     print!("Executing the transaction...");
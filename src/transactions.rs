@@ -1,21 +1,18 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use anyhow::bail;
+use anyhow::{anyhow, bail};
 use c_types::{CStringArray, CU8Array};
 use futures::{future, stream::StreamExt};
 use reqwest::Client;
 use serde_json::json;
-use shared_crypto::intent::Intent;
 use std::{
     ffi::{c_char, c_uint, CStr, CString},
     slice,
     str::FromStr,
     time::Duration,
 };
-use sui_config::{sui_config_dir, SUI_KEYSTORE_FILENAME};
 use sui_json_rpc_types::{Coin, SuiObjectDataOptions};
-use sui_keys::keystore::{AccountKeystore, FileBasedKeystore};
 use sui_sdk::{
     rpc_types::SuiTransactionBlockResponseOptions,
     types::{
@@ -23,7 +20,7 @@ use sui_sdk::{
         quorum_driver_types::ExecuteTransactionRequestType,
         transaction::{Argument, Command, Transaction, TransactionData},
     },
-    SuiClient, SuiClientBuilder,
+    SuiClient,
 };
 use sui_types::{
     base_types::{ObjectID, SuiAddress},
@@ -34,10 +31,12 @@ use tokio::runtime;
 use crate::{
     c_types,
     multisig::{_sign_and_execute_transaction, create_sui_transaction},
+    name_service::resolve_address,
     nfts::{_mint, _transfer_nft},
+    signer::sign_transaction,
+    sui_client::SuiClientSingleton,
     transaction_builder::CProgrammableTransactionBuilder,
 };
-const SUI_FAUCET: &str = "https://faucet.devnet.sui.io/gas";
 
 pub async fn _programmable_transaction(
     senderaddress: &str,
@@ -47,12 +46,12 @@ pub async fn _programmable_transaction(
     // 1) get the Sui client, the sender and recipient that we will use
     // for the transaction, and find the coin we use as gas
 
-    let sender = SuiAddress::from_str(senderaddress)?;
-    let recipient = SuiAddress::from_str(recipientaddress)?;
-    let sui = SuiClientBuilder::default().build_devnet().await?;
+    let sender = resolve_address(senderaddress).await?;
+    let recipient = resolve_address(recipientaddress).await?;
+    let sui = SuiClientSingleton::instance().get_or_init().await?;
     let _coin = fetch_coin(&sui, &sender).await?;
     if _coin.is_none() {
-        _request_tokens_from_faucet(senderaddress).await?;
+        _request_tokens_from_faucet(&sender.to_string()).await?;
     }
     // we need to find the coin we will use as gas
     let coins = sui
@@ -94,8 +93,7 @@ pub async fn _programmable_transaction(
     );
 
     // 4) sign transaction
-    let keystore = FileBasedKeystore::new(&sui_config_dir()?.join(SUI_KEYSTORE_FILENAME))?;
-    let signature = keystore.sign_secure(&sender, &tx_data, Intent::sui_transaction())?;
+    let signature = sign_transaction(&sender, &tx_data)?;
 
     // 5) execute the transaction
     print!("Executing the transaction...");
@@ -131,13 +129,13 @@ pub async fn _programmable_transaction_allow_sponser(
     // 1) get the Sui client, the sender and recipient that we will use
     // for the transaction, and find the coin we use as gas
 
-    let sponser = SuiAddress::from_str(sponser_address)?;
-    let sender = SuiAddress::from_str(senderaddress)?;
-    let recipient = SuiAddress::from_str(recipientaddress)?;
-    let sui = SuiClientBuilder::default().build_devnet().await?;
+    let sponser = resolve_address(sponser_address).await?;
+    let sender = resolve_address(senderaddress).await?;
+    let recipient = resolve_address(recipientaddress).await?;
+    let sui = SuiClientSingleton::instance().get_or_init().await?;
     let _coin = fetch_coin(&sui, &sender).await?;
     if _coin.is_none() {
-        _request_tokens_from_faucet(senderaddress).await?;
+        _request_tokens_from_faucet(&sender.to_string()).await?;
     }
     // we need to find the coin we will use as gas
     let coins = sui
@@ -180,9 +178,8 @@ pub async fn _programmable_transaction_allow_sponser(
     );
 
     // 4) sign transaction
-    let keystore = FileBasedKeystore::new(&sui_config_dir()?.join(SUI_KEYSTORE_FILENAME))?;
-    let sender_signature = keystore.sign_secure(&sender, &tx_data, Intent::sui_transaction())?;
-    let sponser_signature = keystore.sign_secure(&sponser, &tx_data, Intent::sui_transaction())?;
+    let sender_signature = sign_transaction(&sender, &tx_data)?;
+    let sponser_signature = sign_transaction(&sponser, &tx_data)?;
 
     // 5) execute the transaction
     print!("Executing the transaction...");
@@ -209,9 +206,13 @@ pub async fn _programmable_transaction_allow_sponser(
     Ok(())
 }
 
-/// Request tokens from the Faucet for the given address
+/// Request tokens from the Faucet of the configured environment for the given address
 #[allow(unused_assignments)]
 pub async fn _request_tokens_from_faucet(address_str: &str) -> Result<(), anyhow::Error> {
+    let environment = SuiClientSingleton::instance().environment().await;
+    let faucet_url = environment
+        .faucet_url()
+        .ok_or_else(|| anyhow!("No faucet for the {} environment", environment.name()))?;
     let json_body = json![{
         "FixedAmountRequest": {
             "recipient": &address_str
@@ -220,7 +221,7 @@ pub async fn _request_tokens_from_faucet(address_str: &str) -> Result<(), anyhow
     // make the request to the faucet JSON RPC API for coin
     let client = Client::new();
     let resp = client
-        .post(SUI_FAUCET)
+        .post(faucet_url)
         .header("Content-Type", "application/json")
         .json(&json_body)
         .send()
@@ -1,26 +1,35 @@
 use anyhow::{anyhow, Ok};
+use fastcrypto::encoding::{Encoding, Hex};
 use fastcrypto::traits::EncodeDecodeBase64;
 use std::ffi::{c_char, c_int, CStr, CString};
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::{ptr, result};
-use sui_keys::key_derive::generate_new_key;
+use bip32::DerivationPath;
+use bip39::{Language, Mnemonic, Seed};
+use sui_keys::key_derive::{derive_key_pair_from_path, generate_new_key, validate_path};
+use sui_config::{Config, PersistedConfig, SUI_CLIENT_CONFIG};
 use sui_keys::keystore::{AccountKeystore, FileBasedKeystore, Keystore};
+use sui_sdk::sui_client_config::{SuiClientConfig, SuiEnv};
 use sui_types::base_types::SuiAddress;
 use sui_types::crypto::{SignatureScheme, SuiKeyPair};
 
-use crate::c_types::{CSuiObjectData, CSuiObjectDataArray};
+use crate::c_types::{
+    c_str, free_secret_c_char, secret_to_c_char, CAddressResult, CStringArray, CSuiObjectData,
+    CSuiObjectDataArray,
+};
 use crate::nfts::_get_wallet_objects;
 
 //Public functions for FFI
 
 #[repr(C)]
 pub struct Wallet {
-    address: *mut c_char,
-    mnemonic: *mut c_char,
-    public_base64_key: *mut c_char,
-    private_key: *mut c_char,
-    key_scheme: *mut c_char,
+    pub(crate) address: *mut c_char,
+    pub(crate) mnemonic: *mut c_char,
+    pub(crate) public_base64_key: *mut c_char,
+    pub(crate) private_key: *mut c_char,
+    pub(crate) key_scheme: *mut c_char,
+    pub(crate) alias: *mut c_char,
 }
 
 #[repr(C)]
@@ -30,6 +39,47 @@ pub struct ImportResult {
     error: *mut c_char,
 }
 
+impl ImportResult {
+    fn from(result: Result<SuiAddress, anyhow::Error>) -> ImportResult {
+        match result {
+            result::Result::Ok(sui_address) => ImportResult {
+                status: ResultStatus::Success as c_int,
+                address: Wallet::string_to_c_char(Some(sui_address.to_string())),
+                error: Wallet::string_to_c_char(None),
+            },
+            result::Result::Err(e) => ImportResult {
+                status: ResultStatus::Error as c_int,
+                address: Wallet::string_to_c_char(None),
+                error: Wallet::string_to_c_char(Some(e.to_string())),
+            },
+        }
+    }
+}
+
+#[repr(C)]
+pub struct KeyResult {
+    status: c_int,
+    key: *mut c_char,
+    error: *mut c_char,
+}
+
+impl KeyResult {
+    fn from(result: Result<String, anyhow::Error>) -> KeyResult {
+        match result {
+            result::Result::Ok(key) => KeyResult {
+                status: ResultStatus::Success as c_int,
+                key: secret_to_c_char(key),
+                error: Wallet::string_to_c_char(None),
+            },
+            result::Result::Err(e) => KeyResult {
+                status: ResultStatus::Error as c_int,
+                key: Wallet::string_to_c_char(None),
+                error: Wallet::string_to_c_char(Some(e.to_string())),
+            },
+        }
+    }
+}
+
 #[repr(C)]
 pub enum ResultStatus {
     Success = 0,
@@ -38,8 +88,64 @@ pub enum ResultStatus {
 
 #[repr(C)]
 pub struct WalletList {
-    wallets: *mut Wallet,
-    length: usize,
+    pub wallets: *mut Wallet,
+    pub length: usize,
+}
+
+#[repr(C)]
+pub struct DerivedAccount {
+    address: *mut c_char,
+    public_base64_key: *mut c_char,
+    derivation_path: *mut c_char,
+    key_scheme: *mut c_char,
+}
+
+// A derived account before it is handed to C, nothing is allocated for C until every account
+// of a list has been derived
+struct DerivedKey {
+    address: SuiAddress,
+    public_base64_key: String,
+    derivation_path: DerivationPath,
+    key_scheme: SignatureScheme,
+}
+
+impl From<DerivedKey> for DerivedAccount {
+    fn from(key: DerivedKey) -> DerivedAccount {
+        DerivedAccount {
+            address: Wallet::string_to_c_char(Some(key.address.to_string())),
+            public_base64_key: Wallet::string_to_c_char(Some(key.public_base64_key)),
+            derivation_path: Wallet::string_to_c_char(Some(key.derivation_path.to_string())),
+            key_scheme: Wallet::string_to_c_char(Some(key.key_scheme.to_string())),
+        }
+    }
+}
+
+#[repr(C)]
+pub struct DerivedAccountList {
+    pub accounts: *mut DerivedAccount,
+    pub length: usize,
+    pub error: *mut c_char,
+}
+
+impl DerivedAccountList {
+    fn from(result: Result<Vec<DerivedKey>, anyhow::Error>) -> DerivedAccountList {
+        match result {
+            result::Result::Ok(keys) => {
+                let accounts: Vec<DerivedAccount> = keys.into_iter().map(Into::into).collect();
+                let length = accounts.len();
+                DerivedAccountList {
+                    accounts: Box::into_raw(accounts.into_boxed_slice()) as *mut DerivedAccount,
+                    length,
+                    error: ptr::null_mut(),
+                }
+            }
+            Err(e) => DerivedAccountList {
+                accounts: ptr::null_mut(),
+                length: 0,
+                error: Wallet::string_to_c_char(Some(e.to_string())),
+            },
+        }
+    }
 }
 
 impl Wallet {
@@ -51,23 +157,26 @@ impl Wallet {
     ) -> Wallet {
         Wallet {
             address: Wallet::string_to_c_char(Some(address.to_string())),
-            mnemonic: Wallet::string_to_c_char(Some(phrase)),
+            mnemonic: secret_to_c_char(phrase),
             public_base64_key: Wallet::string_to_c_char(Some(kp.public().encode_base64())),
-            private_key: Wallet::string_to_c_char(Some(kp.encode_base64())),
+            private_key: secret_to_c_char(kp.encode_base64()),
             key_scheme: Wallet::string_to_c_char(Some(scheme.to_string())),
+            alias: Wallet::string_to_c_char(None),
         }
     }
     fn from_generate_and_add_new_key(
         address: SuiAddress,
         scheme: SignatureScheme,
         phrase: String,
+        alias: Option<String>,
     ) -> Wallet {
         Wallet {
             address: Wallet::string_to_c_char(Some(address.to_string())),
-            mnemonic: Wallet::string_to_c_char(Some(phrase)),
+            mnemonic: secret_to_c_char(phrase),
             public_base64_key: Wallet::string_to_c_char(None),
             private_key: Wallet::string_to_c_char(None),
             key_scheme: Wallet::string_to_c_char(Some(scheme.to_string())),
+            alias: Wallet::string_to_c_char(alias),
         }
     }
     fn new(
@@ -76,13 +185,15 @@ impl Wallet {
         public_base64_key: Option<String>,
         private_key: Option<String>,
         key_scheme: Option<String>,
+        alias: Option<String>,
     ) -> Wallet {
         Wallet {
             address: Wallet::string_to_c_char(address),
-            mnemonic: Wallet::string_to_c_char(mnemonic),
+            mnemonic: mnemonic.map_or(ptr::null_mut(), secret_to_c_char),
             public_base64_key: Wallet::string_to_c_char(public_base64_key),
-            private_key: Wallet::string_to_c_char(private_key),
+            private_key: private_key.map_or(ptr::null_mut(), secret_to_c_char),
             key_scheme: Wallet::string_to_c_char(key_scheme),
+            alias: Wallet::string_to_c_char(alias),
         }
     }
 
@@ -92,28 +203,13 @@ impl Wallet {
             None => ptr::null_mut(),
         }
     }
+    // Prints the public fields only, the mnemonic and private key are never written out
     pub fn show(&self) {
-        let address = unsafe { CStr::from_ptr(self.address) }
-            .to_str()
-            .unwrap_or("Not set");
-        let mnemonic = unsafe { CStr::from_ptr(self.mnemonic) }
-            .to_str()
-            .unwrap_or("Not set");
-        let public_base64_key = unsafe { CStr::from_ptr(self.public_base64_key) }
-            .to_str()
-            .unwrap_or("Not set");
-        let private_key = unsafe { CStr::from_ptr(self.private_key) }
-            .to_str()
-            .unwrap_or("Not set");
-        let key_scheme = unsafe { CStr::from_ptr(self.key_scheme) }
-            .to_str()
-            .unwrap_or("Not set");
-
-        println!("Wallet Address: {}", address);
-        println!("Mnemonic: {}", mnemonic);
-        println!("Public Base64 Key: {}", public_base64_key);
-        println!("Private Key: {}", private_key);
-        println!("Key Scheme: {}", key_scheme);
+        let field = |value: *mut c_char| c_str(value).unwrap_or("Not set");
+        println!("Wallet Address: {}", field(self.address));
+        println!("Public Base64 Key: {}", field(self.public_base64_key));
+        println!("Key Scheme: {}", field(self.key_scheme));
+        println!("Alias: {}", field(self.alias));
     }
     pub fn free(&mut self) {
         unsafe {
@@ -122,7 +218,7 @@ impl Wallet {
                 self.address = ptr::null_mut();
             }
             if !self.mnemonic.is_null() {
-                let _ = CString::from_raw(self.mnemonic);
+                free_secret_c_char(self.mnemonic);
                 self.mnemonic = ptr::null_mut();
             }
             if !self.public_base64_key.is_null() {
@@ -130,13 +226,17 @@ impl Wallet {
                 self.public_base64_key = ptr::null_mut();
             }
             if !self.private_key.is_null() {
-                let _ = CString::from_raw(self.private_key);
+                free_secret_c_char(self.private_key);
                 self.private_key = ptr::null_mut();
             }
             if !self.key_scheme.is_null() {
                 let _ = CString::from_raw(self.key_scheme);
                 self.key_scheme = ptr::null_mut();
             }
+            if !self.alias.is_null() {
+                let _ = CString::from_raw(self.alias);
+                self.alias = ptr::null_mut();
+            }
         }
     }
 }
@@ -149,7 +249,7 @@ pub extern "C" fn get_wallets() -> WalletList {
     // make WalletList from addresses
     let wallets: Vec<Wallet> = addresses
         .iter()
-        .map(|address| get_wallet_from_address_private(&address.to_string()).unwrap())
+        .map(|address| get_wallet_from_address_private(&address.to_string(), false).unwrap())
         .collect();
     let wallets_len = wallets.len();
     let wallet_list = WalletList {
@@ -159,22 +259,29 @@ pub extern "C" fn get_wallets() -> WalletList {
     wallet_list
 }
 
+// Free every wallet of the list, secrets are wiped before their memory is released
 #[no_mangle]
 pub extern "C" fn free_wallet_list(wallet_list: WalletList) {
     if !wallet_list.wallets.is_null() {
-        // Convert the pointer back into a Box to drop it
         unsafe {
-            let _ = Box::from_raw(wallet_list.wallets);
+            let mut wallets = Box::from_raw(std::slice::from_raw_parts_mut(
+                wallet_list.wallets,
+                wallet_list.length,
+            ));
+            for wallet in wallets.iter_mut() {
+                wallet.free();
+            }
         }
     }
 }
 
+// Free a wallet and all of its fields, secrets are wiped before their memory is released
 #[no_mangle]
 pub extern "C" fn free_wallet(wallet: *mut Wallet) {
     if !wallet.is_null() {
-        // Convert the pointer back into a Box to drop it
         unsafe {
-            let _ = Box::from_raw(wallet);
+            let mut wallet = Box::from_raw(wallet);
+            wallet.free();
         }
     }
 }
@@ -206,8 +313,9 @@ pub extern "C" fn generate_and_add_key() -> *mut Wallet {
     let (address, phrase, scheme) = keystore
         .generate_and_add_new_key(SignatureScheme::ED25519, None, None, None)
         .unwrap();
+    let alias = keystore.get_alias_by_address(&address).ok();
     Box::into_raw(Box::new(Wallet::from_generate_and_add_new_key(
-        address, scheme, phrase,
+        address, scheme, phrase, alias,
     ))) as *mut Wallet
 }
 
@@ -276,7 +384,8 @@ pub extern "C" fn import_from_private_key(key_base64: *const c_char) -> *mut Imp
 
     let keystore_path = default_keystore_path();
     let mut keystore = Keystore::from(FileBasedKeystore::new(&keystore_path).unwrap());
-    let key_pair = match SuiKeyPair::decode_base64(key_base64_str.to_str().unwrap_or("")) {
+    // accepts both the legacy base64 `flag || privkey` and the Bech32 `suiprivkey1...` format
+    let key_pair = match decode_private_key(key_base64_str.to_str().unwrap_or(""), "") {
         result::Result::Ok(key_pair) => key_pair,
         Err(err) => {
             return Box::into_raw(Box::new(ImportResult {
@@ -306,6 +415,132 @@ pub extern "C" fn import_from_private_key(key_base64: *const c_char) -> *mut Imp
     Box::into_raw(Box::new(result))
 }
 
+// Same as get_wallet_from_address but also returns the private key, only call it when the
+// key really has to leave the keystore and free the result with free_wallet
+#[no_mangle]
+pub extern "C" fn get_wallet_with_private_key(address: *const c_char) -> *mut Wallet {
+    let c_str = unsafe {
+        assert!(!address.is_null());
+        CStr::from_ptr(address)
+    };
+    let address_str = c_str.to_str().unwrap_or("");
+    let wallet = match get_wallet_from_address_private(address_str, true) {
+        result::Result::Ok(wallet) => wallet,
+        Err(_) => Wallet::new(None, None, None, None, None, None),
+    };
+    Box::into_raw(Box::new(wallet))
+}
+
+// Import a private key in any supported format: Bech32 `suiprivkey1...`, base64 `flag || privkey`
+// or a raw 32-byte hex key, which needs `key_scheme` (ed25519/secp256k1/secp256r1)
+#[no_mangle]
+pub extern "C" fn import_from_private_key_with_scheme(
+    private_key: *const c_char,
+    key_scheme: *const c_char,
+) -> *mut ImportResult {
+    let private_key = unsafe {
+        assert!(!private_key.is_null());
+        CStr::from_ptr(private_key)
+    };
+    let key_scheme = unsafe {
+        assert!(!key_scheme.is_null());
+        CStr::from_ptr(key_scheme)
+    };
+    let result = (|| -> Result<SuiAddress, anyhow::Error> {
+        let key_pair = decode_private_key(
+            private_key.to_str().unwrap_or(""),
+            key_scheme.to_str().unwrap_or(""),
+        )?;
+        let address = SuiAddress::from(&key_pair.public());
+        let mut keystore = Keystore::from(FileBasedKeystore::new(&default_keystore_path())?);
+        keystore.add_key(None, key_pair)?;
+        Ok(address)
+    })();
+    Box::into_raw(Box::new(ImportResult::from(result)))
+}
+
+// Export the private key of a keystore address as "bech32" (suiprivkey, used by the Sui CLI and
+// wallets), "base64" (legacy `flag || privkey`) or "hex" (raw 32 bytes)
+#[no_mangle]
+pub extern "C" fn export_private_key(
+    address: *const c_char,
+    format: *const c_char,
+) -> *mut KeyResult {
+    let address = unsafe {
+        assert!(!address.is_null());
+        CStr::from_ptr(address)
+    };
+    let format = unsafe {
+        assert!(!format.is_null());
+        CStr::from_ptr(format)
+    };
+    let result = (|| -> Result<String, anyhow::Error> {
+        let keystore = Keystore::from(FileBasedKeystore::new(&default_keystore_path())?);
+        let address = SuiAddress::from_str(address.to_str().unwrap_or(""))
+            .map_err(|_| anyhow!("Invalid address"))?;
+        encode_private_key(keystore.get_key(&address)?, format.to_str().unwrap_or(""))
+    })();
+    Box::into_raw(Box::new(KeyResult::from(result)))
+}
+
+// Convert a private key between the bech32, base64 and hex formats without touching the keystore.
+// `key_scheme` is only needed when the input is a raw hex key.
+#[no_mangle]
+pub extern "C" fn convert_private_key(
+    private_key: *const c_char,
+    key_scheme: *const c_char,
+    format: *const c_char,
+) -> *mut KeyResult {
+    let private_key = unsafe {
+        assert!(!private_key.is_null());
+        CStr::from_ptr(private_key)
+    };
+    let key_scheme = unsafe {
+        assert!(!key_scheme.is_null());
+        CStr::from_ptr(key_scheme)
+    };
+    let format = unsafe {
+        assert!(!format.is_null());
+        CStr::from_ptr(format)
+    };
+    let result = (|| -> Result<String, anyhow::Error> {
+        let key_pair = decode_private_key(
+            private_key.to_str().unwrap_or(""),
+            key_scheme.to_str().unwrap_or(""),
+        )?;
+        encode_private_key(&key_pair, format.to_str().unwrap_or(""))
+    })();
+    Box::into_raw(Box::new(KeyResult::from(result)))
+}
+
+// Free the result of the import functions
+#[no_mangle]
+pub extern "C" fn free_import_result(result: *mut ImportResult) {
+    if !result.is_null() {
+        unsafe {
+            let result = Box::from_raw(result);
+            for field in [result.address, result.error] {
+                if !field.is_null() {
+                    let _ = CString::from_raw(field);
+                }
+            }
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn free_key_result(result: *mut KeyResult) {
+    if !result.is_null() {
+        unsafe {
+            let result = Box::from_raw(result);
+            free_secret_c_char(result.key);
+            if !result.error.is_null() {
+                let _ = CString::from_raw(result.error);
+            }
+        }
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn get_wallet_from_address(address: *const c_char) -> *mut Wallet {
     // Safely convert the C string to a Rust string
@@ -315,16 +550,326 @@ pub extern "C" fn get_wallet_from_address(address: *const c_char) -> *mut Wallet
     };
     let address_str = c_str.to_str().unwrap_or("");
     // Get wallet from address and return null if error
-    let wallet = match get_wallet_from_address_private(address_str) {
+    let wallet = match get_wallet_from_address_private(address_str, false) {
         result::Result::Ok(wallet) => wallet,
-        Err(_) => Wallet::new(None, None, None, None, None),
+        Err(_) => Wallet::new(None, None, None, None, None, None),
     };
     Box::into_raw(Box::new(wallet))
 }
 
+// Derive `count` accounts starting at `start_index` on the default path of the scheme,
+// e.g. m/44'/784'/{index}'/0'/0' for ed25519. Nothing is written to the keystore.
+#[no_mangle]
+pub extern "C" fn derive_accounts_from_mnemonic(
+    mnemonic: *const c_char,
+    key_scheme: *const c_char,
+    start_index: u32,
+    count: u32,
+) -> DerivedAccountList {
+    let mnemonic = unsafe {
+        assert!(!mnemonic.is_null());
+        CStr::from_ptr(mnemonic)
+    };
+    let key_scheme = unsafe {
+        assert!(!key_scheme.is_null());
+        CStr::from_ptr(key_scheme)
+    };
+    DerivedAccountList::from((|| -> Result<Vec<DerivedKey>, anyhow::Error> {
+        let scheme = parse_key_scheme(key_scheme.to_str().unwrap_or(""))?;
+        let mnemonic = mnemonic_str(mnemonic)?;
+        (start_index..start_index.saturating_add(count))
+            .map(|index| derive_account(mnemonic, scheme, account_derivation_path(scheme, index)?))
+            .collect()
+    })())
+}
+
+// Derive one account per derivation path, paths are validated against the scheme
+#[no_mangle]
+pub extern "C" fn derive_accounts_from_paths(
+    mnemonic: *const c_char,
+    key_scheme: *const c_char,
+    derivation_paths: CStringArray,
+) -> DerivedAccountList {
+    let mnemonic = unsafe {
+        assert!(!mnemonic.is_null());
+        CStr::from_ptr(mnemonic)
+    };
+    let key_scheme = unsafe {
+        assert!(!key_scheme.is_null());
+        CStr::from_ptr(key_scheme)
+    };
+    DerivedAccountList::from((|| -> Result<Vec<DerivedKey>, anyhow::Error> {
+        let scheme = parse_key_scheme(key_scheme.to_str().unwrap_or(""))?;
+        let mnemonic = mnemonic_str(mnemonic)?;
+        (0..derivation_paths.len)
+            .map(|i| {
+                let path = unsafe { CStr::from_ptr(*derivation_paths.data.add(i as usize)) };
+                let path = path
+                    .to_str()
+                    .map_err(|_| anyhow!("Invalid derivation path: not valid UTF-8"))?;
+                derive_account(mnemonic, scheme, parse_derivation_path(scheme, path)?)
+            })
+            .collect()
+    })())
+}
+
+#[no_mangle]
+pub extern "C" fn free_derived_account_list(account_list: DerivedAccountList) {
+    unsafe {
+        if !account_list.accounts.is_null() {
+            let accounts = Box::from_raw(std::slice::from_raw_parts_mut(
+                account_list.accounts,
+                account_list.length,
+            ));
+            for account in accounts.iter() {
+                for field in [
+                    account.address,
+                    account.public_base64_key,
+                    account.derivation_path,
+                    account.key_scheme,
+                ] {
+                    if !field.is_null() {
+                        let _ = CString::from_raw(field);
+                    }
+                }
+            }
+        }
+        if !account_list.error.is_null() {
+            let _ = CString::from_raw(account_list.error);
+        }
+    }
+}
+
+// Import the account at `derivation_path` into the keystore, an empty path imports account 0
+#[no_mangle]
+pub extern "C" fn import_from_mnemonic_with_path(
+    mnemonic: *const c_char,
+    key_scheme: *const c_char,
+    derivation_path: *const c_char,
+    alias: *const c_char,
+) -> *mut ImportResult {
+    let mnemonic = unsafe {
+        assert!(!mnemonic.is_null());
+        CStr::from_ptr(mnemonic)
+    };
+    let key_scheme = unsafe {
+        assert!(!key_scheme.is_null());
+        CStr::from_ptr(key_scheme)
+    };
+    let derivation_path = unsafe {
+        assert!(!derivation_path.is_null());
+        CStr::from_ptr(derivation_path)
+    };
+    let alias = unsafe {
+        assert!(!alias.is_null());
+        CStr::from_ptr(alias)
+    };
+    let result = (|| -> Result<SuiAddress, anyhow::Error> {
+        let scheme = parse_key_scheme(key_scheme.to_str().unwrap_or(""))?;
+        let path = match derivation_path.to_str().unwrap_or("") {
+            "" => None,
+            path => Some(parse_derivation_path(scheme, path)?),
+        };
+        let alias = match alias.to_str().unwrap_or("") {
+            "" => None,
+            alias => Some(alias.to_string()),
+        };
+        let mnemonic = mnemonic_str(mnemonic)?;
+        let mut keystore = Keystore::from(FileBasedKeystore::new(&default_keystore_path())?);
+        keystore.import_from_mnemonic(mnemonic, scheme, path, alias)
+    })();
+    Box::into_raw(Box::new(ImportResult::from(result)))
+}
+
+// Import the selected account indexes of a mnemonic into the keystore, nothing is imported
+// unless every index can be derived
+#[no_mangle]
+pub extern "C" fn import_accounts_from_mnemonic(
+    mnemonic: *const c_char,
+    key_scheme: *const c_char,
+    account_indexes: *const u32,
+    account_indexes_len: usize,
+) -> DerivedAccountList {
+    let mnemonic = unsafe {
+        assert!(!mnemonic.is_null());
+        CStr::from_ptr(mnemonic)
+    };
+    let key_scheme = unsafe {
+        assert!(!key_scheme.is_null());
+        CStr::from_ptr(key_scheme)
+    };
+    let account_indexes = if account_indexes.is_null() {
+        &[]
+    } else {
+        unsafe { std::slice::from_raw_parts(account_indexes, account_indexes_len) }
+    };
+    DerivedAccountList::from((|| -> Result<Vec<DerivedKey>, anyhow::Error> {
+        let scheme = parse_key_scheme(key_scheme.to_str().unwrap_or(""))?;
+        let mnemonic = mnemonic_str(mnemonic)?;
+        let keys = account_indexes
+            .iter()
+            .map(|index| derive_account(mnemonic, scheme, account_derivation_path(scheme, *index)?))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut keystore = Keystore::from(FileBasedKeystore::new(&default_keystore_path())?);
+        for key in &keys {
+            keystore.import_from_mnemonic(
+                mnemonic,
+                scheme,
+                Some(key.derivation_path.clone()),
+                None,
+            )?;
+        }
+        Ok(keys)
+    })())
+}
+
+// Return the address of a keystore alias
+#[no_mangle]
+pub extern "C" fn get_address_by_alias(alias: *const c_char) -> CAddressResult {
+    let alias = unsafe {
+        assert!(!alias.is_null());
+        CStr::from_ptr(alias)
+    };
+    let result = (|| -> Result<SuiAddress, anyhow::Error> {
+        let keystore = Keystore::from(FileBasedKeystore::new(&default_keystore_path())?);
+        let address = keystore.get_address_by_alias(alias.to_str().unwrap_or("").to_string())?;
+        Ok(*address)
+    })();
+    CAddressResult::from(result)
+}
+
+// Rename a keystore alias, returns the address the alias belongs to
+#[no_mangle]
+pub extern "C" fn rename_alias(
+    old_alias: *const c_char,
+    new_alias: *const c_char,
+) -> CAddressResult {
+    let old_alias = unsafe {
+        assert!(!old_alias.is_null());
+        CStr::from_ptr(old_alias)
+    };
+    let new_alias = unsafe {
+        assert!(!new_alias.is_null());
+        CStr::from_ptr(new_alias)
+    };
+    let result = (|| -> Result<SuiAddress, anyhow::Error> {
+        let mut keystore = Keystore::from(FileBasedKeystore::new(&default_keystore_path())?);
+        let old_alias = old_alias.to_str().unwrap_or("");
+        let address = *keystore.get_address_by_alias(old_alias.to_string())?;
+        keystore.update_alias(old_alias, Some(new_alias.to_str().unwrap_or("")))?;
+        Ok(address)
+    })();
+    CAddressResult::from(result)
+}
+
+// Remove a key from the keystore, the address can also be given as an alias
+#[no_mangle]
+pub extern "C" fn remove_key(address_or_alias: *const c_char) -> CAddressResult {
+    let address_or_alias = unsafe {
+        assert!(!address_or_alias.is_null());
+        CStr::from_ptr(address_or_alias)
+    };
+    let result = (|| -> Result<SuiAddress, anyhow::Error> {
+        let mut keystore = Keystore::from(FileBasedKeystore::new(&default_keystore_path())?);
+        let address = resolve_keystore_address(&keystore, address_or_alias.to_str().unwrap_or(""))?;
+        keystore.remove_key(address)?;
+        // do not leave a removed key as the active address
+        if let Some(mut client_config) = read_client_config()? {
+            if client_config.active_address == Some(address) {
+                client_config.active_address = keystore.addresses().first().copied();
+                client_config.save(&client_config_path()?)?;
+            }
+        }
+        Ok(address)
+    })();
+    CAddressResult::from(result)
+}
+
+// Return the active address stored in client.yaml next to the keystore, or the first keystore
+// address when there is none
+#[no_mangle]
+pub extern "C" fn get_active_address() -> CAddressResult {
+    let result = (|| -> Result<SuiAddress, anyhow::Error> {
+        let keystore = Keystore::from(FileBasedKeystore::new(&default_keystore_path())?);
+        read_client_config()?
+            .and_then(|client_config| client_config.active_address)
+            .or_else(|| keystore.addresses().first().copied())
+            .ok_or_else(|| anyhow!("No address in the keystore"))
+    })();
+    CAddressResult::from(result)
+}
+
+// Persist the active address, like `sui client switch --address`
+#[no_mangle]
+pub extern "C" fn set_active_address(address_or_alias: *const c_char) -> CAddressResult {
+    let address_or_alias = unsafe {
+        assert!(!address_or_alias.is_null());
+        CStr::from_ptr(address_or_alias)
+    };
+    let result = (|| -> Result<SuiAddress, anyhow::Error> {
+        let keystore = Keystore::from(FileBasedKeystore::new(&default_keystore_path())?);
+        let address = resolve_keystore_address(&keystore, address_or_alias.to_str().unwrap_or(""))?;
+        let mut client_config = load_or_create_client_config(&keystore)?;
+        client_config.active_address = Some(address);
+        client_config.save(&client_config_path()?)?;
+        Ok(address)
+    })();
+    CAddressResult::from(result)
+}
+
 //Private functions
 
-fn get_wallet_from_address_private(address: &str) -> Result<Wallet, anyhow::Error> {
+fn resolve_keystore_address(
+    keystore: &Keystore,
+    address_or_alias: &str,
+) -> Result<SuiAddress, anyhow::Error> {
+    let address = match SuiAddress::from_str(address_or_alias) {
+        result::Result::Ok(address) => address,
+        Err(_) => *keystore.get_address_by_alias(address_or_alias.to_string())?,
+    };
+    if !keystore.addresses().contains(&address) {
+        return Err(anyhow!("Address {} is not in the keystore", address));
+    }
+    Ok(address)
+}
+
+fn client_config_path() -> Result<PathBuf, anyhow::Error> {
+    Ok(default_keystore_path()
+        .parent()
+        .ok_or_else(|| anyhow!("Cannot obtain sui config directory"))?
+        .join(SUI_CLIENT_CONFIG))
+}
+
+// client.yaml when it exists, reading never creates it
+fn read_client_config() -> Result<Option<SuiClientConfig>, anyhow::Error> {
+    let path = client_config_path()?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    Ok(Some(PersistedConfig::read(&path)?))
+}
+
+// Only for writes, the file gets the default environments like a fresh `sui client`
+fn load_or_create_client_config(keystore: &Keystore) -> Result<SuiClientConfig, anyhow::Error> {
+    let path = client_config_path()?;
+    if let Some(client_config) = read_client_config()? {
+        return Ok(client_config);
+    }
+    let mut client_config =
+        SuiClientConfig::new(FileBasedKeystore::new(&default_keystore_path())?.into());
+    client_config.add_env(SuiEnv::devnet());
+    client_config.add_env(SuiEnv::testnet());
+    client_config.add_env(SuiEnv::localnet());
+    client_config.active_env = client_config.envs.first().map(|env| env.alias.clone());
+    client_config.active_address = keystore.addresses().first().copied();
+    client_config.save(&path)?;
+    Ok(client_config)
+}
+
+pub(crate) fn get_wallet_from_address_private(
+    address: &str,
+    reveal_private_key: bool,
+) -> Result<Wallet, anyhow::Error> {
     let keystore_path = default_keystore_path();
     let keystore = Keystore::from(
         FileBasedKeystore::new(&keystore_path)
@@ -345,12 +890,103 @@ fn get_wallet_from_address_private(address: &str) -> Result<Wallet, anyhow::Erro
         Some(address.to_string()),
         None,
         Some(key.public().encode_base64()),
-        Some(key.encode_base64()),
+        reveal_private_key.then(|| key.encode_base64()),
         Some(scheme.to_string()),
+        keystore.get_alias_by_address(&address).ok(),
     ))
 }
 
-fn generate_new_private(key_scheme: &str, word_length: &str) -> Result<Wallet, anyhow::Error> {
+fn decode_private_key(private_key: &str, key_scheme: &str) -> Result<SuiKeyPair, anyhow::Error> {
+    let private_key = private_key.trim();
+    if private_key.starts_with("suiprivkey") {
+        return SuiKeyPair::decode(private_key)
+            .map_err(|e| anyhow!("Invalid Bech32 private key: {}", e));
+    }
+    let hex_key = private_key.trim_start_matches("0x");
+    if hex_key.len() == 64 && hex_key.chars().all(|c| c.is_ascii_hexdigit()) {
+        let scheme = parse_key_scheme(key_scheme)
+            .map_err(|e| anyhow!("A key scheme is required for a hex private key: {}", e))?;
+        let mut bytes = vec![scheme.flag()];
+        bytes.extend(Hex::decode(hex_key).map_err(|e| anyhow!("Invalid hex private key: {}", e))?);
+        return SuiKeyPair::from_bytes(&bytes).map_err(|e| anyhow!("Invalid private key: {}", e));
+    }
+    SuiKeyPair::decode_base64(private_key).map_err(|e| anyhow!("Invalid private key: {}", e))
+}
+
+fn encode_private_key(key_pair: &SuiKeyPair, format: &str) -> Result<String, anyhow::Error> {
+    match format.to_lowercase().as_str() {
+        "bech32" | "suiprivkey" => key_pair
+            .encode()
+            .map_err(|e| anyhow!("Cannot encode private key: {}", e)),
+        "base64" => Ok(key_pair.encode_base64()),
+        // drop the scheme flag to get the raw private key
+        "hex" => Ok(format!("0x{}", Hex::encode(&key_pair.to_bytes()[1..]))),
+        other => Err(anyhow!("Unsupported private key format: {}", other)),
+    }
+}
+
+fn parse_key_scheme(key_scheme: &str) -> Result<SignatureScheme, anyhow::Error> {
+    match key_scheme.to_lowercase().as_str() {
+        "ed25519" => Ok(SignatureScheme::ED25519),
+        "secp256k1" => Ok(SignatureScheme::Secp256k1),
+        "secp256r1" => Ok(SignatureScheme::Secp256r1),
+        other => Err(anyhow!("Unsupported key scheme for derivation: {}", other)),
+    }
+}
+
+/// Default derivation path of an account index for each scheme:
+/// m/44'/784'/{index}'/0'/0' for ed25519, m/54'/784'/{index}'/0/0 for secp256k1
+/// and m/74'/784'/{index}'/0/0 for secp256r1.
+fn account_derivation_path(
+    scheme: SignatureScheme,
+    index: u32,
+) -> Result<DerivationPath, anyhow::Error> {
+    let path = match scheme {
+        SignatureScheme::ED25519 => format!("m/44'/784'/{}'/0'/0'", index),
+        SignatureScheme::Secp256k1 => format!("m/54'/784'/{}'/0/0", index),
+        SignatureScheme::Secp256r1 => format!("m/74'/784'/{}'/0/0", index),
+        _ => return Err(anyhow!("Unsupported key scheme for derivation: {}", scheme)),
+    };
+    parse_derivation_path(scheme, &path)
+}
+
+fn parse_derivation_path(
+    scheme: SignatureScheme,
+    path: &str,
+) -> Result<DerivationPath, anyhow::Error> {
+    let path = DerivationPath::from_str(path)
+        .map_err(|e| anyhow!("Invalid derivation path {}: {}", path, e))?;
+    validate_path(&scheme, Some(path)).map_err(|e| anyhow!(e))
+}
+
+fn mnemonic_str(mnemonic: &CStr) -> Result<&str, anyhow::Error> {
+    mnemonic
+        .to_str()
+        .map_err(|_| anyhow!("Invalid mnemonic: not valid UTF-8"))
+}
+
+fn derive_account(
+    mnemonic: &str,
+    scheme: SignatureScheme,
+    path: DerivationPath,
+) -> Result<DerivedKey, anyhow::Error> {
+    let mnemonic = Mnemonic::from_phrase(mnemonic, Language::English)
+        .map_err(|e| anyhow!("Invalid mnemonic: {}", e))?;
+    let seed = Seed::new(&mnemonic, "");
+    let (address, kp) = derive_key_pair_from_path(seed.as_bytes(), Some(path.clone()), &scheme)
+        .map_err(|e| anyhow!(e))?;
+    Ok(DerivedKey {
+        address,
+        public_base64_key: kp.public().encode_base64(),
+        derivation_path: path,
+        key_scheme: scheme,
+    })
+}
+
+pub(crate) fn generate_new_private(
+    key_scheme: &str,
+    word_length: &str,
+) -> Result<Wallet, anyhow::Error> {
     let scheme = match key_scheme.to_lowercase().as_str() {
         "ed25519" => Ok(SignatureScheme::ED25519),
         "secp256k1" => Ok(SignatureScheme::Secp256k1),
@@ -401,12 +1037,10 @@ pub extern "C" fn get_wallet_objects(
     let objects = runtime
         .block_on(_get_wallet_objects(address, object_type))
         .unwrap_or_else(|_| Vec::new());
-    let mut c_objects: Vec<CSuiObjectData> = objects
-        .into_iter()
-        .map(|obj| CSuiObjectData::from(obj))
-        .collect();
-    let ptr = c_objects.as_mut_ptr();
-    let len = c_objects.len();
-    std::mem::forget(c_objects);
-    CSuiObjectDataArray { data: ptr, len }
+    CSuiObjectDataArray::from(
+        objects
+            .into_iter()
+            .map(CSuiObjectData::from)
+            .collect::<Vec<_>>(),
+    )
 }
@@ -0,0 +1,310 @@
+use anyhow::anyhow;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use fastcrypto::traits::{EncodeDecodeBase64, ToFromBytes};
+use fastcrypto_zkp::bn254::utils::{gen_address_seed, get_nonce};
+use fastcrypto_zkp::bn254::zk_login::ZkLoginInputs;
+use shared_crypto::intent::{Intent, IntentMessage};
+use std::ffi::{c_char, CString};
+use std::ptr;
+use sui_sdk::rpc_types::SuiTransactionBlockResponseOptions;
+use sui_types::base_types::SuiAddress;
+use sui_types::crypto::{get_key_pair, Signature, SuiKeyPair};
+use sui_types::signature::GenericSignature;
+use sui_types::transaction::{Transaction, TransactionData};
+use sui_types::zk_login_authenticator::ZkLoginAuthenticator;
+use tokio::runtime;
+
+use crate::c_types::{c_str, free_c_char, free_secret_c_char, secret_to_c_char, CU8Array};
+use crate::multisig::zklogin_member_public_key;
+use crate::sui_client::SuiClientSingleton;
+
+pub struct EphemeralKey {
+    pub keypair: SuiKeyPair,
+    pub randomness: String,
+    pub nonce: String,
+}
+
+pub struct ZkLoginAddress {
+    pub address: SuiAddress,
+    pub address_seed: String,
+    pub iss: String,
+    pub sub: String,
+    pub aud: String,
+}
+
+/// Compute the nonce to put in the OAuth request for an ephemeral key.
+pub fn zklogin_nonce(
+    keypair: &SuiKeyPair,
+    max_epoch: u64,
+    randomness: &str,
+) -> Result<String, anyhow::Error> {
+    let public_key = keypair.public();
+    let mut eph_pk_bytes = vec![public_key.flag()];
+    eph_pk_bytes.extend(public_key.as_ref());
+    get_nonce(&eph_pk_bytes, max_epoch, randomness).map_err(|e| anyhow!(e))
+}
+
+/// Generate an ed25519 ephemeral key valid until `max_epoch`.
+///
+/// When `randomness` is empty a fresh 128 bit value is drawn, it must be kept
+/// together with the key since the prover needs it to rebuild the nonce.
+pub fn generate_ephemeral_key(
+    max_epoch: u64,
+    randomness: &str,
+) -> Result<EphemeralKey, anyhow::Error> {
+    let (_, keypair) = get_key_pair();
+    let keypair = SuiKeyPair::Ed25519(keypair);
+    let randomness = if randomness.is_empty() {
+        rand::random::<u128>().to_string()
+    } else {
+        randomness.to_string()
+    };
+    let nonce = zklogin_nonce(&keypair, max_epoch, &randomness)?;
+    Ok(EphemeralKey {
+        keypair,
+        randomness,
+        nonce,
+    })
+}
+
+/// Derive the zkLogin address of a JWT for the given user salt.
+///
+/// Only the claims are read, the JWT signature is checked by the prover and the network.
+pub fn derive_zklogin_address(jwt: &str, salt: &str) -> Result<ZkLoginAddress, anyhow::Error> {
+    let payload = jwt
+        .split('.')
+        .nth(1)
+        .ok_or_else(|| anyhow!("Invalid JWT: missing payload"))?;
+    let claims: serde_json::Value = serde_json::from_slice(
+        &URL_SAFE_NO_PAD
+            .decode(payload.trim_end_matches('='))
+            .map_err(|e| anyhow!("Invalid JWT payload: {}", e))?,
+    )?;
+    let claim = |name: &str| -> Result<String, anyhow::Error> {
+        match &claims[name] {
+            serde_json::Value::String(value) => Ok(value.clone()),
+            // `aud` may be a list with a single client id
+            serde_json::Value::Array(values) if values.len() == 1 => values[0]
+                .as_str()
+                .map(|value| value.to_string())
+                .ok_or_else(|| anyhow!("Invalid JWT claim {}", name)),
+            _ => Err(anyhow!("JWT claim {} is missing", name)),
+        }
+    };
+    let iss = claim("iss")?;
+    let sub = claim("sub")?;
+    let aud = claim("aud")?;
+    let address_seed = gen_address_seed(salt, "sub", &sub, &aud).map_err(|e| anyhow!(e))?;
+    let address = SuiAddress::from(&zklogin_member_public_key(&iss, &address_seed)?);
+    Ok(ZkLoginAddress {
+        address,
+        address_seed,
+        iss,
+        sub,
+        aud,
+    })
+}
+
+/// Sign a transaction with the ephemeral key, returning the user signature of the authenticator.
+pub fn sign_with_ephemeral_key(
+    private_key: &str,
+    tx_data: &[u8],
+) -> Result<Signature, anyhow::Error> {
+    let keypair = SuiKeyPair::decode_base64(private_key)
+        .map_err(|e| anyhow!("Invalid ephemeral private key: {}", e))?;
+    let tx_data: TransactionData = bcs::from_bytes(tx_data)?;
+    Ok(Signature::new_secure(
+        &IntentMessage::new(Intent::sui_transaction(), tx_data),
+        &keypair,
+    ))
+}
+
+/// Assemble a zkLogin signature from the prover response and the ephemeral signature.
+pub fn assemble_zklogin_signature(
+    proof_json: &str,
+    address_seed: &str,
+    max_epoch: u64,
+    ephemeral_signature: &str,
+) -> Result<GenericSignature, anyhow::Error> {
+    let inputs = ZkLoginInputs::from_json(proof_json, address_seed)
+        .map_err(|e| anyhow!("Invalid prover response: {}", e))?;
+    let user_signature = Signature::decode_base64(ephemeral_signature)
+        .map_err(|e| anyhow!("Invalid ephemeral signature: {}", e))?;
+    Ok(GenericSignature::ZkLoginAuthenticator(
+        ZkLoginAuthenticator::new(inputs, max_epoch, user_signature),
+    ))
+}
+
+pub async fn _execute_zklogin_transaction(
+    tx_data: &[u8],
+    signature: &[u8],
+) -> Result<String, anyhow::Error> {
+    let sui_client = SuiClientSingleton::instance().get_or_init().await?;
+    let tx_data: TransactionData = bcs::from_bytes(tx_data)?;
+    let signature =
+        GenericSignature::from_bytes(signature).map_err(|e| anyhow!("Invalid signature: {}", e))?;
+    let transaction_response = sui_client
+        .quorum_driver_api()
+        .execute_transaction_block(
+            Transaction::from_generic_sig_data(tx_data, vec![signature]),
+            SuiTransactionBlockResponseOptions::default(),
+            None,
+        )
+        .await?;
+    Ok(transaction_response.digest.base58_encode())
+}
+
+//public functions for ffi
+
+#[repr(C)]
+pub struct CZkLoginEphemeral {
+    private_key: *const c_char,
+    public_key: *const c_char,
+    randomness: *const c_char,
+    nonce: *const c_char,
+    max_epoch: u64,
+    error: *const c_char,
+}
+
+#[repr(C)]
+pub struct CZkLoginAddress {
+    address: *const c_char,
+    address_seed: *const c_char,
+    iss: *const c_char,
+    sub: *const c_char,
+    aud: *const c_char,
+    error: *const c_char,
+}
+
+fn string_to_c_char(s: Option<String>) -> *const c_char {
+    match s {
+        Some(str) => CString::new(str).unwrap().into_raw(),
+        None => ptr::null(),
+    }
+}
+
+// Generate an ephemeral key and the OAuth nonce, pass null or "" as randomness to draw a new one
+#[no_mangle]
+pub extern "C" fn zklogin_generate_ephemeral(
+    max_epoch: u64,
+    randomness: *const c_char,
+) -> CZkLoginEphemeral {
+    let ephemeral = match c_str(randomness) {
+        None if !randomness.is_null() => Err(anyhow!("Invalid randomness: not valid UTF-8")),
+        randomness => generate_ephemeral_key(max_epoch, randomness.unwrap_or_default()),
+    };
+    match ephemeral {
+        Ok(ephemeral) => CZkLoginEphemeral {
+            private_key: secret_to_c_char(ephemeral.keypair.encode_base64()),
+            public_key: string_to_c_char(Some(ephemeral.keypair.public().encode_base64())),
+            randomness: string_to_c_char(Some(ephemeral.randomness)),
+            nonce: string_to_c_char(Some(ephemeral.nonce)),
+            max_epoch,
+            error: ptr::null(),
+        },
+        Err(e) => CZkLoginEphemeral {
+            private_key: ptr::null(),
+            public_key: ptr::null(),
+            randomness: ptr::null(),
+            nonce: ptr::null(),
+            max_epoch,
+            error: string_to_c_char(Some(e.to_string())),
+        },
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn free_zklogin_ephemeral(ephemeral: CZkLoginEphemeral) {
+    free_secret_c_char(ephemeral.private_key as *mut c_char);
+    free_c_char(ephemeral.public_key);
+    free_c_char(ephemeral.randomness);
+    free_c_char(ephemeral.nonce);
+    free_c_char(ephemeral.error);
+}
+
+#[no_mangle]
+pub extern "C" fn zklogin_address_from_jwt(
+    jwt: *const c_char,
+    salt: *const c_char,
+) -> CZkLoginAddress {
+    match derive_zklogin_address(
+        c_str(jwt).unwrap_or_default(),
+        c_str(salt).unwrap_or_default(),
+    ) {
+        Ok(zklogin) => CZkLoginAddress {
+            address: string_to_c_char(Some(zklogin.address.to_string())),
+            address_seed: string_to_c_char(Some(zklogin.address_seed)),
+            iss: string_to_c_char(Some(zklogin.iss)),
+            sub: string_to_c_char(Some(zklogin.sub)),
+            aud: string_to_c_char(Some(zklogin.aud)),
+            error: ptr::null(),
+        },
+        Err(e) => CZkLoginAddress {
+            address: ptr::null(),
+            address_seed: ptr::null(),
+            iss: ptr::null(),
+            sub: ptr::null(),
+            aud: ptr::null(),
+            error: string_to_c_char(Some(e.to_string())),
+        },
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn free_zklogin_address(address: CZkLoginAddress) {
+    free_c_char(address.address);
+    free_c_char(address.address_seed);
+    free_c_char(address.iss);
+    free_c_char(address.sub);
+    free_c_char(address.aud);
+    free_c_char(address.error);
+}
+
+// Sign transaction bytes with the ephemeral key, returns the base64 user signature
+#[no_mangle]
+pub extern "C" fn zklogin_sign_with_ephemeral(
+    ephemeral_private_key: *const c_char,
+    tx: CU8Array,
+) -> *const c_char {
+    match sign_with_ephemeral_key(
+        c_str(ephemeral_private_key).unwrap_or_default(),
+        &tx.to_vec(),
+    ) {
+        Ok(signature) => string_to_c_char(Some(signature.encode_base64())),
+        Err(e) => string_to_c_char(Some(format!("Error: {}", e))),
+    }
+}
+
+// Assemble the serialized zkLogin signature from the prover JSON response
+#[no_mangle]
+pub extern "C" fn zklogin_assemble_signature(
+    proof_json: *const c_char,
+    address_seed: *const c_char,
+    max_epoch: u64,
+    ephemeral_signature: *const c_char,
+) -> CU8Array {
+    match assemble_zklogin_signature(
+        c_str(proof_json).unwrap_or_default(),
+        c_str(address_seed).unwrap_or_default(),
+        max_epoch,
+        c_str(ephemeral_signature).unwrap_or_default(),
+    ) {
+        Ok(signature) => CU8Array::from(signature.as_ref().to_vec()),
+        Err(e) => CU8Array::from_error(e.to_string()),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn zklogin_execute_transaction(tx: CU8Array, signature: CU8Array) -> *const c_char {
+    // Create a new runtime. This step might vary based on the async runtime you are using.
+    let rt = runtime::Runtime::new().unwrap();
+    rt.block_on(async {
+        match _execute_zklogin_transaction(&tx.to_vec(), &signature.to_vec()).await {
+            Ok(digest) => string_to_c_char(Some(format!(
+                "Transaction executed. Transaction digest: {}",
+                digest
+            ))),
+            Err(e) => string_to_c_char(Some(format!("Error: {}", e))),
+        }
+    })
+}
@@ -0,0 +1,120 @@
+//! Builds tests/c/harness.c and tests/cpp/wrapper.cpp against the cdylib and runs them, the C
+//! harness online against the mock fullnode and offline against a port nobody listens on, the
+//! C++ program against the mock through the generated header/sui_lib.hpp.
+//!
+//! `SUI_HARNESS_CC` (default `cc`) and `SUI_HARNESS_CXX` (default `c++`) pick the compilers,
+//! `SUI_HARNESS_CFLAGS` adds flags such as `-fsanitize=address -g` to both and
+//! `SUI_HARNESS_RUNNER` wraps the binaries, e.g. `valgrind --error-exitcode=1 --leak-check=full`.
+
+mod common;
+
+use common::mock;
+use fastcrypto::traits::EncodeDecodeBase64;
+use serial_test::serial;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// target/<profile>, where cargo puts the cdylib next to the deps directory of this test
+fn library_dir() -> PathBuf {
+    let exe = std::env::current_exe().unwrap();
+    let deps = exe.parent().unwrap();
+    deps.parent().unwrap().to_path_buf()
+}
+
+fn compile(source: &str, compiler: (&str, &str), flags: &[&str], binary: &Path) {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let library_dir = library_dir();
+    let (variable, default) = compiler;
+    let compiler = std::env::var(variable).unwrap_or_else(|_| default.to_string());
+    let cflags = std::env::var("SUI_HARNESS_CFLAGS").unwrap_or_default();
+    let status = Command::new(compiler)
+        .arg(manifest_dir.join(source))
+        .args(flags)
+        .arg("-Wall")
+        .arg("-Werror")
+        .arg("-Wno-unused-parameter")
+        .arg("-I")
+        .arg(manifest_dir.join("header"))
+        .args(cflags.split_whitespace())
+        .arg(format!("-L{}", library_dir.display()))
+        .arg(format!("-Wl,-rpath,{}", library_dir.display()))
+        .arg("-lsui_rust_sdk")
+        .arg("-o")
+        .arg(binary)
+        .status()
+        .unwrap_or_else(|_| panic!("compiler not found, set {}", variable));
+    assert!(status.success(), "compiling {} failed", source);
+}
+
+fn run_harness(binary: &Path, mode: &str, rpc_url: &str) {
+    let home = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(home.path().join(".sui").join("sui_config")).unwrap();
+    let keypair = common::keypair(3);
+
+    let runner = std::env::var("SUI_HARNESS_RUNNER").unwrap_or_default();
+    let mut runner = runner.split_whitespace();
+    let mut command = match runner.next() {
+        Some(program) => {
+            let mut command = Command::new(program);
+            command.args(runner).arg(binary);
+            command
+        }
+        None => Command::new(binary),
+    };
+    let output = command
+        .arg(mode)
+        .env("HOME", home.path())
+        .env("SUI_HARNESS_RPC_URL", rpc_url)
+        .env("SUI_HARNESS_PRIVATE_KEY", keypair.encode_base64())
+        .env("SUI_HARNESS_SENDER", common::address(&keypair).to_string())
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "harness {} failed ({})\nstdout:\n{}\nstderr:\n{}",
+        mode,
+        output.status,
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+#[serial]
+fn c_harness() {
+    let mock = mock();
+    mock.reset();
+    let out_dir = tempfile::tempdir().unwrap();
+    let binary = out_dir.path().join("harness");
+    compile(
+        "tests/c/harness.c",
+        ("SUI_HARNESS_CC", "cc"),
+        &["-std=c11"],
+        &binary,
+    );
+
+    run_harness(&binary, "online", &mock.url);
+    assert!(!mock.requests("sui_executeTransactionBlock").is_empty());
+
+    // Bind and drop a listener to get a port that refuses connections
+    let closed = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", closed.local_addr().unwrap());
+    drop(closed);
+    run_harness(&binary, "offline", &url);
+}
+
+#[test]
+#[serial]
+fn cpp_wrapper() {
+    let mock = mock();
+    mock.reset();
+    let out_dir = tempfile::tempdir().unwrap();
+    let binary = out_dir.path().join("wrapper");
+    compile(
+        "tests/cpp/wrapper.cpp",
+        ("SUI_HARNESS_CXX", "c++"),
+        &["-std=c++17", "-pthread"],
+        &binary,
+    );
+    run_harness(&binary, "online", &mock.url);
+}
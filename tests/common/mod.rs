@@ -0,0 +1,473 @@
+//! In-process mock of the Sui fullnode JSON-RPC API.
+//!
+//! The server answers plain HTTP/1.1 JSON-RPC requests from scripted handlers, one per method.
+//! `mock()` starts it once per test binary and points `SuiClientSingleton` at it, tests then
+//! override single methods with `respond`/`respond_with`/`fail` and inspect what the SDK sent
+//! with `requests`. Tests changing the mock should be `#[serial]` and start with `reset()`.
+#![allow(dead_code)]
+
+use fastcrypto::bls12381::min_sig::{BLS12381AggregateSignature, BLS12381KeyPair};
+use fastcrypto::ed25519::Ed25519KeyPair;
+use fastcrypto::traits::{AggregateAuthenticator, KeyPair, Signer};
+use once_cell::sync::OnceCell;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::ffi::CString;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use sui_types::base_types::SuiAddress;
+use sui_types::crypto::SuiKeyPair;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+
+/// Base58 of 32 zero bytes, valid for every digest type.
+pub const DIGEST: &str = "11111111111111111111111111111111";
+pub const GAS_COIN_ID: &str = "0x00000000000000000000000000000000000000000000000000000000000000a1";
+pub const OBJECT_ID: &str = "0x00000000000000000000000000000000000000000000000000000000000000b1";
+pub const RECIPIENT: &str = "0x00000000000000000000000000000000000000000000000000000000000000c1";
+pub const NAME_ADDRESS: &str = "0x00000000000000000000000000000000000000000000000000000000000000d1";
+pub const SUI_NAME: &str = "mock.sui";
+pub const CHAIN_IDENTIFIER: &str = "0000mock";
+pub const GAS_BALANCE: u64 = 10_000_000_000;
+pub const REFERENCE_GAS_PRICE: u64 = 1_000;
+pub const LATEST_CHECKPOINT: u64 = 100;
+
+type Handler = Arc<dyn Fn(&Value) -> Result<Value, Value> + Send + Sync>;
+
+pub struct MockRpc {
+    pub url: String,
+    handlers: Mutex<HashMap<String, Handler>>,
+    requests: Mutex<Vec<(String, Value)>>,
+    listener: Mutex<Option<std::net::TcpListener>>,
+    unavailable: AtomicUsize,
+    stalled: AtomicUsize,
+}
+
+/// The mock shared by every test of the binary, the SDK client is configured on first use.
+pub fn mock() -> &'static MockRpc {
+    static MOCK: OnceCell<MockRpc> = OnceCell::new();
+    static CONFIGURED: OnceCell<()> = OnceCell::new();
+    let mock = MOCK.get_or_init(MockRpc::bind);
+    CONFIGURED.get_or_init(|| {
+        mock.serve_forever();
+        let url = CString::new(mock.url.clone()).unwrap();
+        assert_eq!(sui_rust_sdk::sui_client::build_custom(url.as_ptr()), 0);
+    });
+    mock
+}
+
+/// Deterministic test keys, the same seed always gives the same address.
+pub fn keypair(seed: u8) -> SuiKeyPair {
+    SuiKeyPair::Ed25519(Ed25519KeyPair::generate(&mut StdRng::from_seed([seed; 32])))
+}
+
+pub fn address(keypair: &SuiKeyPair) -> SuiAddress {
+    SuiAddress::from(&keypair.public())
+}
+
+impl MockRpc {
+    fn bind() -> MockRpc {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let mock = MockRpc {
+            url,
+            handlers: Mutex::new(HashMap::new()),
+            requests: Mutex::new(Vec::new()),
+            listener: Mutex::new(Some(listener)),
+            unavailable: AtomicUsize::new(0),
+            stalled: AtomicUsize::new(0),
+        };
+        mock.reset();
+        mock
+    }
+
+    // The server gets its own runtime, the FFI functions create and drop one per call
+    fn serve_forever(&'static self) {
+        let listener = self.listener.lock().unwrap().take().unwrap();
+        listener.set_nonblocking(true).unwrap();
+        std::thread::spawn(move || {
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            rt.block_on(async move {
+                let listener = TcpListener::from_std(listener).unwrap();
+                loop {
+                    let (stream, _) = listener.accept().await.unwrap();
+                    tokio::spawn(async move {
+                        let _ = self.serve(stream).await;
+                    });
+                }
+            });
+        });
+    }
+
+    async fn serve(&self, stream: TcpStream) -> std::io::Result<()> {
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        loop {
+            // Request line, then headers until the empty line
+            line.clear();
+            if reader.read_line(&mut line).await? == 0 {
+                return Ok(());
+            }
+            let mut content_length = 0;
+            loop {
+                line.clear();
+                reader.read_line(&mut line).await?;
+                let header = line.trim_end();
+                if header.is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = header.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap_or(0);
+                    }
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).await?;
+
+            let stalled = self
+                .stalled
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok();
+            if stalled {
+                // Keep the connection open without ever answering
+                return std::future::pending().await;
+            }
+
+            let unavailable = self
+                .unavailable
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok();
+            if unavailable {
+                reader
+                    .get_mut()
+                    .write_all(b"HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\n\r\n")
+                    .await?;
+                continue;
+            }
+
+            let response = match serde_json::from_slice::<Value>(&body) {
+                Ok(Value::Array(batch)) => {
+                    Value::Array(batch.iter().map(|call| self.handle(call)).collect())
+                }
+                Ok(call) => self.handle(&call),
+                Err(e) => json!({
+                    "jsonrpc": "2.0",
+                    "id": null,
+                    "error": {"code": -32700, "message": e.to_string()},
+                }),
+            }
+            .to_string();
+            let stream = reader.get_mut();
+            stream
+                .write_all(
+                    format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n",
+                        response.len()
+                    )
+                    .as_bytes(),
+                )
+                .await?;
+            stream.write_all(response.as_bytes()).await?;
+        }
+    }
+
+    fn handle(&self, call: &Value) -> Value {
+        let method = call["method"].as_str().unwrap_or_default().to_string();
+        let params = match &call["params"] {
+            Value::Null => json!([]),
+            params => params.clone(),
+        };
+        self.requests
+            .lock()
+            .unwrap()
+            .push((method.clone(), params.clone()));
+
+        let result = if method == "rpc.discover" {
+            Ok(self.discover())
+        } else {
+            let handler = self.handlers.lock().unwrap().get(&method).cloned();
+            match handler {
+                Some(handler) => handler(&params),
+                None => {
+                    Err(json!({"code": -32601, "message": format!("Method not found: {}", method)}))
+                }
+            }
+        };
+        match result {
+            Ok(result) => json!({"jsonrpc": "2.0", "id": call["id"], "result": result}),
+            Err(error) => json!({"jsonrpc": "2.0", "id": call["id"], "error": error}),
+        }
+    }
+
+    fn discover(&self) -> Value {
+        let methods: Vec<Value> = self
+            .handlers
+            .lock()
+            .unwrap()
+            .keys()
+            .map(|name| json!({"name": name}))
+            .collect();
+        json!({
+            "openrpc": "1.2.6",
+            "info": {"title": "Sui JSON-RPC (mock)", "version": "1.35.0"},
+            "methods": methods,
+        })
+    }
+
+    /// Answer `method` with a fixed result.
+    pub fn respond(&self, method: &str, result: Value) {
+        self.respond_with(method, move |_| Ok(result.clone()));
+    }
+
+    /// Answer `method` from its params, `Err` values are sent as the JSON-RPC error object.
+    pub fn respond_with(
+        &self,
+        method: &str,
+        handler: impl Fn(&Value) -> Result<Value, Value> + Send + Sync + 'static,
+    ) {
+        self.handlers
+            .lock()
+            .unwrap()
+            .insert(method.to_string(), Arc::new(handler));
+    }
+
+    /// Make `method` fail with a server error.
+    pub fn fail(&self, method: &str, message: &str) {
+        let error = json!({"code": -32000, "message": message});
+        self.respond_with(method, move |_| Err(error.clone()));
+    }
+
+    /// Answer the next `times` HTTP requests with 503 Service Unavailable, whatever the method.
+    pub fn unavailable(&self, times: usize) {
+        self.unavailable.store(times, Ordering::SeqCst);
+    }
+
+    /// Leave the next `times` HTTP requests unanswered, the connection stays open.
+    pub fn stall(&self, times: usize) {
+        self.stalled.store(times, Ordering::SeqCst);
+    }
+
+    /// Params of every `method` call received since the last reset.
+    pub fn requests(&self, method: &str) -> Vec<Value> {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(name, _)| name == method)
+            .map(|(_, params)| params.clone())
+            .collect()
+    }
+
+    /// Restore the default fixtures and forget the recorded requests.
+    pub fn reset(&self) {
+        self.requests.lock().unwrap().clear();
+        self.handlers.lock().unwrap().clear();
+        self.unavailable.store(0, Ordering::SeqCst);
+        self.stalled.store(0, Ordering::SeqCst);
+        self.install_fixtures();
+    }
+
+    fn install_fixtures(&self) {
+        self.respond("sui_getChainIdentifier", json!(CHAIN_IDENTIFIER));
+        self.respond(
+            "sui_getLatestCheckpointSequenceNumber",
+            json!(LATEST_CHECKPOINT.to_string()),
+        );
+        self.respond(
+            "suix_getReferenceGasPrice",
+            json!(REFERENCE_GAS_PRICE.to_string()),
+        );
+        self.respond(
+            "suix_getTotalSupply",
+            json!({"value": "10000000000000000000"}),
+        );
+
+        // Every address owns a single gas coin
+        self.respond("suix_getCoins", page(vec![coin(GAS_COIN_ID, GAS_BALANCE)]));
+        self.respond(
+            "suix_getAllCoins",
+            page(vec![coin(GAS_COIN_ID, GAS_BALANCE)]),
+        );
+        self.respond("suix_getBalance", balance(GAS_BALANCE));
+        self.respond("suix_getAllBalances", json!([balance(GAS_BALANCE)]));
+
+        // Every object exists and is owned by RECIPIENT unless a test says otherwise
+        self.respond_with("sui_getObject", |params| {
+            Ok(object(params[0].as_str().unwrap_or(OBJECT_ID), NFT_TYPE))
+        });
+        self.respond_with("sui_multiGetObjects", |params| {
+            Ok(Value::Array(
+                params[0]
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .map(|id| object(id.as_str().unwrap_or(OBJECT_ID), NFT_TYPE))
+                    .collect(),
+            ))
+        });
+        self.respond(
+            "suix_getOwnedObjects",
+            page(vec![object(OBJECT_ID, NFT_TYPE)]),
+        );
+        self.respond("suix_queryEvents", page(vec![]));
+
+        self.respond_with("suix_resolveNameServiceAddress", |params| {
+            Ok(match params[0].as_str() {
+                Some(SUI_NAME) => json!(NAME_ADDRESS),
+                _ => Value::Null,
+            })
+        });
+        self.respond_with("suix_resolveNameServiceNames", |params| {
+            Ok(match params[0].as_str() {
+                Some(NAME_ADDRESS) => page(vec![json!(SUI_NAME)]),
+                _ => page(vec![]),
+            })
+        });
+
+        // Execution is acknowledged right away, the SDK then polls the transaction by digest
+        self.respond(
+            "sui_executeTransactionBlock",
+            json!({"digest": DIGEST, "confirmedLocalExecution": false}),
+        );
+        self.respond_with("sui_getTransactionBlock", |params| {
+            Ok(json!({
+                "digest": params[0],
+                "timestampMs": "1700000000000",
+                "checkpoint": LATEST_CHECKPOINT.to_string(),
+                "confirmedLocalExecution": true,
+            }))
+        });
+        self.respond("sui_dryRunTransactionBlock", dry_run());
+    }
+}
+
+pub const NFT_TYPE: &str = "0x2::devnet_nft::DevNetNFT";
+
+pub fn page(data: Vec<Value>) -> Value {
+    json!({"data": data, "nextCursor": null, "hasNextPage": false})
+}
+
+pub fn coin(object_id: &str, balance: u64) -> Value {
+    json!({
+        "coinType": "0x2::sui::SUI",
+        "coinObjectId": object_id,
+        "version": "1",
+        "digest": DIGEST,
+        "balance": balance.to_string(),
+        "previousTransaction": DIGEST,
+    })
+}
+
+pub fn balance(total: u64) -> Value {
+    json!({
+        "coinType": "0x2::sui::SUI",
+        "coinObjectCount": 1,
+        "totalBalance": total.to_string(),
+        "lockedBalance": {},
+    })
+}
+
+/// A checkpoint with one transaction, signed by a single fixed BLS key.
+pub fn checkpoint(sequence_number: u64) -> Value {
+    let keypair = BLS12381KeyPair::generate(&mut StdRng::from_seed([0; 32]));
+    let signature =
+        BLS12381AggregateSignature::aggregate(&[keypair.sign(&sequence_number.to_le_bytes())])
+            .unwrap();
+    json!({
+        "epoch": "0",
+        "sequenceNumber": sequence_number.to_string(),
+        "digest": DIGEST,
+        "networkTotalTransactions": (sequence_number + 1).to_string(),
+        "previousDigest": null,
+        "epochRollingGasCostSummary": {
+            "computationCost": "0",
+            "storageCost": "0",
+            "storageRebate": "0",
+            "nonRefundableStorageFee": "0",
+        },
+        "timestampMs": "1700000000000",
+        "transactions": [DIGEST],
+        "checkpointCommitments": [],
+        "endOfEpochData": null,
+        "validatorSignature": signature,
+    })
+}
+
+pub fn object(object_id: &str, object_type: &str) -> Value {
+    json!({
+        "data": {
+            "objectId": object_id,
+            "version": "1",
+            "digest": DIGEST,
+            "type": object_type,
+            "owner": {"AddressOwner": RECIPIENT},
+            "previousTransaction": DIGEST,
+            "storageRebate": "100",
+            "content": {
+                "dataType": "moveObject",
+                "type": object_type,
+                "hasPublicTransfer": true,
+                "fields": {
+                    "id": {"id": object_id},
+                    "name": "Mock NFT",
+                },
+            },
+        }
+    })
+}
+
+pub fn missing_object(object_id: &str) -> Value {
+    json!({"error": {"code": "notExists", "object_id": object_id}})
+}
+
+pub fn event(event_type: &str, event_seq: u64, parsed_json: Value) -> Value {
+    json!({
+        "id": {"txDigest": DIGEST, "eventSeq": event_seq.to_string()},
+        "packageId": "0x2",
+        "transactionModule": "transfer_policy",
+        "sender": RECIPIENT,
+        "type": event_type,
+        "parsedJson": parsed_json,
+        "bcsEncoding": "base64",
+        "bcs": "",
+    })
+}
+
+pub fn dry_run() -> Value {
+    json!({
+        "effects": {
+            "messageVersion": "v1",
+            "status": {"status": "success"},
+            "executedEpoch": "0",
+            "gasUsed": {
+                "computationCost": "1000000",
+                "storageCost": "2000000",
+                "storageRebate": "1000000",
+                "nonRefundableStorageFee": "10000",
+            },
+            "transactionDigest": DIGEST,
+            "gasObject": {
+                "owner": {"AddressOwner": RECIPIENT},
+                "reference": {"objectId": GAS_COIN_ID, "version": "2", "digest": DIGEST},
+            },
+            "dependencies": [],
+        },
+        "events": [],
+        "objectChanges": [],
+        "balanceChanges": [],
+        "input": {
+            "messageVersion": "v1",
+            "transaction": {"kind": "ProgrammableTransaction", "inputs": [], "transactions": []},
+            "sender": RECIPIENT,
+            "gasData": {
+                "payment": [{"objectId": GAS_COIN_ID, "version": "1", "digest": DIGEST}],
+                "owner": RECIPIENT,
+                "price": REFERENCE_GAS_PRICE.to_string(),
+                "budget": "5000000",
+            },
+        },
+    })
+}
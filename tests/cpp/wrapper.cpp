@@ -0,0 +1,135 @@
+// Exercises header/sui_lib.hpp against the mock fullnode, driven by tests/c_harness.rs.
+//
+// Nothing here calls a free function: every result is released by its RAII wrapper, so the run
+// stays clean under AddressSanitizer and Valgrind only if the generated wrappers are right.
+
+#include <cstdio>
+#include <cstdlib>
+#include <string>
+#include <vector>
+#include "sui_lib.hpp"
+
+// Fixtures of the mock fullnode, keep in sync with tests/common/mod.rs
+const std::string GAS_COIN_ID = "0x00000000000000000000000000000000000000000000000000000000000000a1";
+const std::string OBJECT_ID = "0x00000000000000000000000000000000000000000000000000000000000000b1";
+const std::string RECIPIENT = "0x00000000000000000000000000000000000000000000000000000000000000c1";
+const std::string NAME_ADDRESS = "0x00000000000000000000000000000000000000000000000000000000000000d1";
+const std::string SUI_NAME = "mock.sui";
+const std::string NFT_TYPE = "0x2::devnet_nft::DevNetNFT";
+const std::string CHAIN_IDENTIFIER = "0000mock";
+const uint64_t GAS_BALANCE = 10000000000ULL;
+
+static int checks = 0;
+static int failures = 0;
+
+#define CHECK(condition)                                                        \
+    do                                                                          \
+    {                                                                           \
+        checks++;                                                               \
+        if (!(condition))                                                       \
+        {                                                                       \
+            failures++;                                                         \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, #condition); \
+        }                                                                       \
+    } while (0)
+
+// Runs `call` and returns the code of the sui::Error it throws, or a sentinel when it does not
+template <typename F>
+int error_code(F call)
+{
+    try
+    {
+        call();
+    }
+    catch (const sui::Error &e)
+    {
+        return e.code();
+    }
+    return 1000;
+}
+
+void test_strings_and_vectors()
+{
+    CHECK(sui::get_chain_identifier() == CHAIN_IDENTIFIER);
+    CHECK(sui::resolve_sui_name(SUI_NAME) == NAME_ADDRESS);
+    // Message strings are returned as they are, only error fields and SuiStatus results throw
+    CHECK(sui::resolve_sui_name("unknown.sui").rfind("Error: ", 0) == 0);
+    CHECK(error_code([] { sui::verify_multisig_signature({}, {}); }) == -1);
+
+    std::vector<std::string> names = sui::lookup_sui_names(NAME_ADDRESS);
+    CHECK(names.size() == 1 && names[0] == SUI_NAME);
+    CHECK(error_code([] { sui::lookup_sui_names("not an address"); }) == -1);
+
+    std::vector<std::string> methods = sui::available_rpc_methods();
+    CHECK(!methods.empty());
+}
+
+void test_owned_results()
+{
+    auto coins = sui::get_coins_sync(RECIPIENT);
+    CHECK(coins->length == 1);
+    for (const CCoin &coin : sui::span(coins->coins, coins->length))
+    {
+        CHECK(coin.coin_object_id == GAS_COIN_ID);
+        CHECK(coin.balance == GAS_BALANCE);
+    }
+
+    auto object = sui::get_object(OBJECT_ID, sui::sui_object_data_options_full());
+    CHECK(object->object != nullptr && object->object->object_id == OBJECT_ID);
+    CHECK(error_code([] { sui::get_object("not an object id", sui::sui_object_data_options_full()); }) == Error);
+
+    // Moving transfers the ownership, the moved-from value releases nothing
+    auto moved = std::move(object);
+    CHECK(moved->status == Exists);
+
+    // The status of the result becomes the error code
+    int code = error_code([] { sui::import_from_private_key("not a key"); });
+    CHECK(code != 0 && code != 1000);
+}
+
+void test_handles()
+{
+    CHECK(sui::sui_sdk_abi_version() == SUI_SDK_ABI_VERSION);
+
+    auto object = sui::sui_object_fetch_v1(OBJECT_ID);
+    CHECK(sui::sui_object_get_id_v1(object.get()) == OBJECT_ID);
+    CHECK(sui::sui_object_get_type_v1(object.get()) == NFT_TYPE);
+    CHECK(error_code([] { sui::sui_object_fetch_v1("not an object id"); }) == -1);
+
+    auto objects = sui::sui_owned_objects_fetch_v1(RECIPIENT, nullptr);
+    CHECK(sui::sui_object_list_len_v1(objects.get()) == 1);
+
+    auto wallet = sui::sui_wallet_generate_v1("ed25519", "word12");
+    CHECK(sui::sui_wallet_get_mnemonic_v1(wallet.get()).has_value());
+    CHECK(!sui::sui_wallet_get_alias_v1(wallet.get()).has_value());
+}
+
+void test_futures()
+{
+    auto chain_identifier = sui::get_chain_identifier_async();
+    auto coins = sui::get_coins_sync_async(RECIPIENT);
+    auto missing = sui::resolve_sui_name_async("unknown.sui");
+
+    CHECK(chain_identifier.get() == CHAIN_IDENTIFIER);
+    CHECK(coins.get()->length == 1);
+    CHECK(error_code([&] { missing.get(); }) == -1);
+}
+
+int main(int, char **argv)
+{
+    const char *rpc_url = getenv("SUI_HARNESS_RPC_URL");
+    if (rpc_url == nullptr)
+    {
+        fprintf(stderr, "usage: SUI_HARNESS_RPC_URL=... %s\n", argv[0]);
+        return 2;
+    }
+    CHECK(sui::build_custom(rpc_url) == 0);
+
+    test_strings_and_vectors();
+    test_owned_results();
+    test_handles();
+    test_futures();
+
+    printf("%d checks, %d failures\n", checks, failures);
+    return failures == 0 ? 0 : 1;
+}
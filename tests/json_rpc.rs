@@ -0,0 +1,159 @@
+//! The JSON gateway against the mock fullnode.
+
+mod common;
+
+use common::{mock, GAS_BALANCE, GAS_COIN_ID, OBJECT_ID, RECIPIENT, REFERENCE_GAS_PRICE};
+use serde_json::{json, Value};
+use serial_test::serial;
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+use sui_rust_sdk::json_rpc::{
+    self, RPC_INVALID_PARAMS, RPC_INVALID_REQUEST, RPC_METHOD_NOT_FOUND, RPC_SERVER_ERROR,
+};
+
+fn take_json(s: *const c_char) -> Value {
+    let string = unsafe { CStr::from_ptr(s) }.to_str().unwrap().to_string();
+    unsafe { drop(CString::from_raw(s as *mut c_char)) };
+    serde_json::from_str(&string).unwrap()
+}
+
+fn rpc_call(method: &str, params: Option<&str>) -> Value {
+    let method = CString::new(method).unwrap();
+    let params = params.map(|params| CString::new(params).unwrap());
+    take_json(json_rpc::sui_rpc_call(
+        method.as_ptr(),
+        params
+            .as_ref()
+            .map_or(ptr::null(), |params| params.as_ptr()),
+    ))
+}
+
+#[test]
+#[serial]
+fn forwards_listed_methods() {
+    let mock = mock();
+    mock.reset();
+
+    assert_eq!(
+        rpc_call("suix_getReferenceGasPrice", None),
+        json!({"result": REFERENCE_GAS_PRICE.to_string()})
+    );
+    assert_eq!(mock.requests("suix_getReferenceGasPrice"), vec![json!([])]);
+
+    let params = json!([RECIPIENT]).to_string();
+    let response = rpc_call("suix_getBalance", Some(&params));
+    assert_eq!(
+        response["result"]["totalBalance"],
+        json!(GAS_BALANCE.to_string())
+    );
+    assert_eq!(mock.requests("suix_getBalance"), vec![json!([RECIPIENT])]);
+}
+
+#[test]
+#[serial]
+fn rejects_calls_before_sending_them() {
+    let mock = mock();
+    mock.reset();
+
+    let response = rpc_call("sui_notAMethod", None);
+    assert_eq!(response["error"]["code"], json!(RPC_METHOD_NOT_FOUND));
+    assert!(mock.requests("sui_notAMethod").is_empty());
+
+    for params in ["not json", "42", "\"0x1\""] {
+        let response = rpc_call("suix_getBalance", Some(params));
+        assert_eq!(
+            response["error"]["code"],
+            json!(RPC_INVALID_PARAMS),
+            "{}",
+            params
+        );
+    }
+    assert!(mock.requests("suix_getBalance").is_empty());
+
+    let response = take_json(json_rpc::sui_rpc_call(ptr::null(), ptr::null()));
+    assert_eq!(response["error"]["code"], json!(RPC_INVALID_REQUEST));
+}
+
+#[test]
+#[serial]
+fn node_errors_pass_through() {
+    let mock = mock();
+    mock.reset();
+    mock.fail("suix_getBalance", "balance index unavailable");
+
+    let params = json!([RECIPIENT]).to_string();
+    assert_eq!(
+        rpc_call("suix_getBalance", Some(&params)),
+        json!({"error": {"code": -32000, "message": "balance index unavailable"}})
+    );
+}
+
+#[test]
+#[serial]
+fn retries_an_unavailable_node() {
+    let mock = mock();
+    mock.reset();
+
+    mock.unavailable(2);
+    let response = rpc_call("suix_getReferenceGasPrice", None);
+    assert_eq!(response["result"], json!(REFERENCE_GAS_PRICE.to_string()));
+    assert_eq!(mock.requests("suix_getReferenceGasPrice").len(), 1);
+
+    mock.unavailable(10);
+    let response = rpc_call("suix_getReferenceGasPrice", None);
+    assert_eq!(response["error"]["code"], json!(RPC_SERVER_ERROR));
+    assert!(response["error"]["message"]
+        .as_str()
+        .unwrap()
+        .contains("3 attempt(s)"));
+    mock.reset();
+}
+
+#[test]
+#[serial]
+fn retries_a_node_that_never_answers() {
+    let mock = mock();
+    mock.reset();
+    // The client discovers the node's methods on first use, that request must not be stalled
+    rpc_call("suix_getReferenceGasPrice", None);
+    mock.reset();
+
+    // The first attempt times out, the second one is answered
+    mock.stall(1);
+    let response = rpc_call("suix_getReferenceGasPrice", None);
+    assert_eq!(response["result"], json!(REFERENCE_GAS_PRICE.to_string()));
+    assert_eq!(mock.requests("suix_getReferenceGasPrice").len(), 1);
+}
+
+#[test]
+#[serial]
+fn query_functions_answer_in_json() {
+    let mock = mock();
+    mock.reset();
+    let address = CString::new(RECIPIENT).unwrap();
+    let object_id = CString::new(OBJECT_ID).unwrap();
+
+    let coins = take_json(json_rpc::get_coins_json(address.as_ptr()));
+    assert_eq!(
+        coins["result"]["data"][0]["coinObjectId"],
+        json!(GAS_COIN_ID)
+    );
+
+    let balance = take_json(json_rpc::get_balance_json(address.as_ptr()));
+    assert_eq!(
+        balance["result"]["totalBalance"],
+        json!(GAS_BALANCE.to_string())
+    );
+
+    let object = take_json(json_rpc::get_object_json(object_id.as_ptr()));
+    assert_eq!(object["result"]["objectId"], json!(OBJECT_ID));
+
+    mock.respond("sui_getObject", common::missing_object(OBJECT_ID));
+    let missing = take_json(json_rpc::get_object_json(object_id.as_ptr()));
+    assert_eq!(missing["error"]["code"], json!(RPC_SERVER_ERROR));
+    assert_eq!(missing["error"]["data"]["status"], json!(1));
+
+    let cursor = CString::new("not a sequence number").unwrap();
+    let checkpoints = take_json(json_rpc::get_checkpoints_json(cursor.as_ptr(), 0, false));
+    assert_eq!(checkpoints["error"]["code"], json!(RPC_INVALID_PARAMS));
+}
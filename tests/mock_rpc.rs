@@ -0,0 +1,438 @@
+//! The SDK end to end against the mock fullnode, no network access needed.
+
+mod common;
+
+use common::{mock, GAS_BALANCE, GAS_COIN_ID, NAME_ADDRESS, OBJECT_ID, RECIPIENT, SUI_NAME};
+use fastcrypto::encoding::{Base64, Encoding};
+use fastcrypto::traits::EncodeDecodeBase64;
+use move_core_types::language_storage::TypeTag;
+use serde_json::{json, Value};
+use serial_test::serial;
+use std::ffi::{c_int, c_void, CStr, CString};
+use std::str::FromStr;
+use std::sync::Arc;
+use sui_json_rpc_types::SuiObjectDataOptions;
+use sui_rust_sdk::object_read_api::{ObjectLookup, ObjectStatus};
+use sui_rust_sdk::signer::{reset_signer, set_signer, LocalSigner};
+use sui_rust_sdk::{
+    balance, chain_info, checkpoints, dynamic_fields, handles, kiosk, multisig, name_service, nfts,
+    object_read_api, sui_client, transaction_builder, transactions,
+};
+use sui_types::base_types::{ObjectID, SuiAddress};
+use sui_types::programmable_transaction_builder::ProgrammableTransactionBuilder;
+use sui_types::transaction::{ProgrammableTransaction, TransactionData, TransactionDataAPI};
+use tokio::runtime::Runtime;
+
+fn transfer_sui(recipient: &str, amount: u64) -> ProgrammableTransaction {
+    let mut ptb = ProgrammableTransactionBuilder::new();
+    ptb.pay_sui(vec![SuiAddress::from_str(recipient).unwrap()], vec![amount])
+        .unwrap();
+    ptb.finish()
+}
+
+fn take_c_string(s: *const std::ffi::c_char) -> String {
+    let string = unsafe { CStr::from_ptr(s) }.to_str().unwrap().to_string();
+    unsafe { drop(CString::from_raw(s as *mut std::ffi::c_char)) };
+    string
+}
+
+#[test]
+#[serial]
+fn client_discovers_mock_methods() {
+    let mock = mock();
+    mock.reset();
+    let rt = Runtime::new().unwrap();
+    let methods = rt.block_on(sui_client::_available_rpc_methods()).unwrap();
+    assert!(methods.contains(&"suix_getCoins".to_string()));
+    assert_eq!(
+        take_c_string(chain_info::get_chain_identifier()),
+        common::CHAIN_IDENTIFIER
+    );
+}
+
+#[test]
+#[serial]
+fn coins_and_balances() {
+    let mock = mock();
+    mock.reset();
+    let rt = Runtime::new().unwrap();
+
+    let coins = rt.block_on(balance::get_coins(RECIPIENT)).unwrap();
+    assert_eq!(coins.data.len(), 1);
+    assert_eq!(coins.data[0].balance, GAS_BALANCE);
+    assert_eq!(
+        coins.data[0].coin_object_id,
+        ObjectID::from_str(GAS_COIN_ID).unwrap()
+    );
+    assert_eq!(mock.requests("suix_getCoins")[0][0], RECIPIENT);
+
+    let balance = rt.block_on(balance::get_balance(RECIPIENT)).unwrap();
+    assert_eq!(balance.total_balance, GAS_BALANCE as u128);
+    let balances = rt.block_on(balance::get_all_balances(RECIPIENT)).unwrap();
+    assert_eq!(balances.len(), 1);
+}
+
+#[test]
+#[serial]
+fn sui_names_resolve_to_addresses() {
+    let mock = mock();
+    mock.reset();
+    let rt = Runtime::new().unwrap();
+
+    let address = rt.block_on(name_service::resolve_address(SUI_NAME)).unwrap();
+    assert_eq!(address, SuiAddress::from_str(NAME_ADDRESS).unwrap());
+    assert!(rt
+        .block_on(name_service::resolve_address("unknown.sui"))
+        .is_err());
+
+    // Hex addresses never hit the name service
+    rt.block_on(name_service::resolve_address(RECIPIENT)).unwrap();
+    assert_eq!(mock.requests("suix_resolveNameServiceAddress").len(), 2);
+
+    let names = rt
+        .block_on(name_service::_reverse_lookup(NAME_ADDRESS))
+        .unwrap();
+    assert_eq!(names, vec![SUI_NAME.to_string()]);
+
+    // Names are accepted wherever the coin API takes an address
+    rt.block_on(balance::get_coins(SUI_NAME)).unwrap();
+    assert_eq!(mock.requests("suix_getCoins")[0][0], NAME_ADDRESS);
+}
+
+#[test]
+#[serial]
+fn object_lookups_report_missing_objects() {
+    let mock = mock();
+    mock.reset();
+    let rt = Runtime::new().unwrap();
+
+    let options = SuiObjectDataOptions::full_content();
+    match rt
+        .block_on(object_read_api::_get_object(OBJECT_ID, options.clone()))
+        .unwrap()
+    {
+        ObjectLookup::Found(data) => {
+            assert_eq!(data.object_id, ObjectID::from_str(OBJECT_ID).unwrap());
+            assert_eq!(data.type_.unwrap().to_string(), common::NFT_TYPE);
+        }
+        ObjectLookup::Missing(_, error) => panic!("unexpected missing object: {}", error),
+    }
+
+    mock.respond_with("sui_getObject", |params| {
+        Ok(common::missing_object(params[0].as_str().unwrap()))
+    });
+    let lookup = rt
+        .block_on(object_read_api::_get_object(OBJECT_ID, options))
+        .unwrap();
+    assert!(matches!(
+        lookup,
+        ObjectLookup::Missing(ObjectStatus::NotExists, _)
+    ));
+}
+
+#[test]
+#[serial]
+fn dynamic_field_reports_its_value_type() {
+    let mock = mock();
+    mock.reset();
+    let rt = Runtime::new().unwrap();
+
+    // Every id resolves to a Field<u64, u64> named 7 holding 42
+    mock.respond_with("sui_getObject", |params| {
+        let field_type = "0x2::dynamic_field::Field<u64, u64>";
+        let mut field = common::object(params[0].as_str().unwrap(), field_type);
+        field["data"]["content"]["fields"] =
+            json!({"id": {"id": OBJECT_ID}, "name": "7", "value": "42"});
+        Ok(field)
+    });
+    let field = rt
+        .block_on(dynamic_fields::_get_dynamic_field(
+            OBJECT_ID,
+            "u64",
+            &7u64.to_le_bytes(),
+        ))
+        .unwrap();
+    assert_eq!(field.value_type, "u64");
+    assert_eq!(field.content, json!("42"));
+}
+
+#[test]
+#[serial]
+fn builder_selects_gas_and_reference_price() {
+    let mock = mock();
+    mock.reset();
+    let rt = Runtime::new().unwrap();
+
+    let tx_data = rt
+        .block_on(transaction_builder::_build_transaction_data(
+            RECIPIENT,
+            transfer_sui(RECIPIENT, 1_000),
+            5_000_000,
+        ))
+        .unwrap();
+    assert_eq!(tx_data.gas_price(), common::REFERENCE_GAS_PRICE);
+    assert_eq!(tx_data.gas_budget(), 5_000_000);
+    assert_eq!(
+        tx_data.gas()[0].0,
+        ObjectID::from_str(GAS_COIN_ID).unwrap()
+    );
+}
+
+#[test]
+#[serial]
+fn execute_signs_with_registered_signer() {
+    let mock = mock();
+    mock.reset();
+    let keypair = common::keypair(1);
+    let sender = common::address(&keypair).to_string();
+    set_signer(Arc::new(LocalSigner::new(vec![keypair])));
+    let rt = Runtime::new().unwrap();
+
+    let response = rt
+        .block_on(transaction_builder::_execute_transaction(
+            &sender,
+            transfer_sui(RECIPIENT, 1_000),
+            5_000_000,
+        ))
+        .unwrap();
+    reset_signer();
+
+    let executed = mock.requests("sui_executeTransactionBlock");
+    assert_eq!(executed.len(), 1);
+    let tx_bytes = Base64::decode(executed[0][0].as_str().unwrap()).unwrap();
+    let tx_data: TransactionData = bcs::from_bytes(&tx_bytes).unwrap();
+    assert_eq!(tx_data.sender().to_string(), sender);
+    assert_eq!(executed[0][1].as_array().unwrap().len(), 1);
+    // The response comes from polling the transaction the SDK actually built
+    assert_eq!(response.digest, tx_data.digest());
+}
+
+#[test]
+#[serial]
+fn nft_transfer_resolves_objects() {
+    let mock = mock();
+    mock.reset();
+    let keypair = common::keypair(2);
+    let sender = common::address(&keypair).to_string();
+    set_signer(Arc::new(LocalSigner::new(vec![keypair])));
+    let rt = Runtime::new().unwrap();
+
+    let digest = rt.block_on(nfts::_transfer_objects(
+        &sender,
+        &[OBJECT_ID.to_string()],
+        SUI_NAME,
+        5_000_000,
+    ));
+    reset_signer();
+    assert!(digest.is_ok(), "{:?}", digest.err());
+    assert_eq!(mock.requests("sui_getObject")[0][0], OBJECT_ID);
+    assert_eq!(mock.requests("suix_resolveNameServiceAddress").len(), 1);
+}
+
+#[test]
+#[serial]
+fn transfer_policy_skips_destroyed_policies() {
+    let mock = mock();
+    mock.reset();
+    let destroyed = "0x00000000000000000000000000000000000000000000000000000000000000e1";
+    let live = "0x00000000000000000000000000000000000000000000000000000000000000e2";
+    let created = format!(
+        "0x2::transfer_policy::TransferPolicyCreated<{}>",
+        common::NFT_TYPE
+    );
+    // Newest first, as requested
+    mock.respond(
+        "suix_queryEvents",
+        common::page(vec![
+            common::event(&created, 1, json!({"id": destroyed})),
+            common::event(&created, 0, json!({"id": live})),
+        ]),
+    );
+    mock.respond_with("sui_getObject", move |params| {
+        Ok(match params[0].as_str() {
+            Some(id) if id == live => common::object(
+                live,
+                &format!("0x2::transfer_policy::TransferPolicy<{}>", common::NFT_TYPE),
+            ),
+            Some(id) => common::missing_object(id),
+            None => Value::Null,
+        })
+    });
+    let rt = Runtime::new().unwrap();
+
+    let item_type = TypeTag::from_str(common::NFT_TYPE).unwrap();
+    let policy = rt
+        .block_on(kiosk::_find_transfer_policy(&item_type))
+        .unwrap();
+    assert_eq!(policy, live);
+    assert_eq!(mock.requests("suix_queryEvents")[0][3], json!(true));
+
+    mock.respond("suix_queryEvents", common::page(vec![]));
+    assert!(rt
+        .block_on(kiosk::_find_transfer_policy(&item_type))
+        .is_err());
+}
+
+#[test]
+#[serial]
+fn multisig_transaction_uses_mock_coins() {
+    let mock = mock();
+    mock.reset();
+    let public_keys: Vec<String> = (10..13)
+        .map(|seed| common::keypair(seed).public().encode_base64())
+        .collect();
+    let multisig_pk = multisig::multisig_public_key_from_public_keys(
+        public_keys.iter().map(String::as_str).collect(),
+        vec![],
+        vec![1, 1, 1],
+        2,
+    )
+    .unwrap();
+    let multisig_address = SuiAddress::from(&multisig_pk).to_string();
+    let rt = Runtime::new().unwrap();
+
+    let tx_data = rt
+        .block_on(multisig::create_sui_transaction(
+            &multisig_address,
+            RECIPIENT,
+            1_000,
+        ))
+        .unwrap();
+    assert_eq!(tx_data.sender().to_string(), multisig_address);
+    assert_eq!(tx_data.gas_price(), common::REFERENCE_GAS_PRICE);
+    assert_eq!(mock.requests("suix_getCoins")[0][0], multisig_address);
+}
+
+#[test]
+#[serial]
+fn wallet_objects_come_from_owned_objects() {
+    let mock = mock();
+    mock.reset();
+    let rt = Runtime::new().unwrap();
+
+    let objects = rt
+        .block_on(nfts::_get_wallet_objects(RECIPIENT, common::NFT_TYPE))
+        .unwrap();
+    assert_eq!(objects.len(), 1);
+    assert_eq!(
+        objects[0].object_id,
+        ObjectID::from_str(OBJECT_ID).unwrap()
+    );
+    assert_eq!(mock.requests("suix_getOwnedObjects")[0][0], RECIPIENT);
+}
+
+#[test]
+#[serial]
+fn rpc_errors_are_reported() {
+    let mock = mock();
+    mock.reset();
+    mock.fail("suix_getReferenceGasPrice", "gas price unavailable");
+    let rt = Runtime::new().unwrap();
+
+    let error = rt
+        .block_on(chain_info::_get_reference_gas_price())
+        .unwrap_err();
+    assert!(error.to_string().contains("gas price unavailable"));
+    drop(rt);
+    assert_eq!(chain_info::get_reference_gas_price(), 0);
+
+    let message = take_c_string(nfts::transfer_object(
+        CString::new(RECIPIENT).unwrap().as_ptr(),
+        CString::new(OBJECT_ID).unwrap().as_ptr(),
+        CString::new(RECIPIENT).unwrap().as_ptr(),
+        5_000_000,
+    ));
+    assert!(message.starts_with("Error:"), "{}", message);
+}
+
+#[test]
+#[serial]
+fn faucet_follows_the_environment() {
+    let mock = mock();
+    mock.reset();
+
+    // The mock is a custom node, requesting tokens fails before any request is sent
+    let error = Runtime::new()
+        .unwrap()
+        .block_on(transactions::_request_tokens_from_faucet(RECIPIENT))
+        .unwrap_err();
+    assert!(error.to_string().contains("custom"), "{}", error);
+
+    assert!(sui_client::SuiEnvironment::Mainnet.faucet_url().is_none());
+    assert!(sui_client::SuiEnvironment::Testnet
+        .faucet_url()
+        .unwrap()
+        .contains("testnet"));
+}
+
+extern "C" fn count_and_stop(
+    _checkpoint: *const checkpoints::CCheckpoint,
+    user_data: *mut c_void,
+) -> c_int {
+    unsafe { *(user_data as *mut u32) += 1 };
+    1
+}
+
+#[test]
+#[serial]
+fn follow_checkpoints_from_genesis_or_latest() {
+    let mock = mock();
+    let follow = |start| {
+        mock.reset();
+        mock.respond(
+            "sui_getCheckpoints",
+            common::page(vec![common::checkpoint(0)]),
+        );
+        let mut calls = 0u32;
+        let status = checkpoints::follow_checkpoints(
+            start,
+            10,
+            count_and_stop,
+            &mut calls as *mut u32 as *mut c_void,
+        );
+        assert!(status.is_null(), "{}", take_c_string(status));
+        assert_eq!(calls, 1);
+        mock.requests("sui_getCheckpoints")[0][0].clone()
+    };
+
+    // 0 is the genesis checkpoint, the first page starts without a cursor
+    assert_eq!(follow(0), Value::Null);
+    assert!(mock
+        .requests("sui_getLatestCheckpointSequenceNumber")
+        .is_empty());
+
+    assert_eq!(
+        follow(checkpoints::FOLLOW_FROM_LATEST),
+        json!((common::LATEST_CHECKPOINT - 1).to_string())
+    );
+}
+
+#[test]
+#[serial]
+fn coin_handles_hold_every_page() {
+    let mock = mock();
+    mock.reset();
+    mock.respond_with("suix_getCoins", |params| {
+        Ok(match params[2].as_str() {
+            None => json!({
+                "data": [common::coin(GAS_COIN_ID, GAS_BALANCE)],
+                "nextCursor": GAS_COIN_ID,
+                "hasNextPage": true,
+            }),
+            Some(_) => common::page(vec![common::coin(OBJECT_ID, 1)]),
+        })
+    });
+
+    let address = CString::new(RECIPIENT).unwrap();
+    let coins = handles::sui_coins_fetch_v1(address.as_ptr(), std::ptr::null_mut());
+    assert_eq!(handles::sui_coin_list_len_v1(coins), 2);
+    assert_eq!(
+        handles::sui_coin_get_balance_v1(handles::sui_coin_list_at_v1(coins, 1)),
+        1
+    );
+    handles::sui_free_coin_list_v1(coins);
+
+    let requests = mock.requests("suix_getCoins");
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[1][2], json!(GAS_COIN_ID));
+}
@@ -0,0 +1,413 @@
+//! Every value handed to C goes back to the allocator through its `sui_free_*` function, no more
+//! and no less: a leak leaves allocations behind, a double free removes more than it added.
+//!
+//! The allocator counts live allocations per thread, the functions below run entirely on the
+//! calling thread. Each scenario runs once to warm up lazily initialised state before the
+//! counted run. Network backed results are covered by tests/c/harness.c under ASan/Valgrind.
+
+mod common;
+
+use fastcrypto::traits::EncodeDecodeBase64;
+use serial_test::serial;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::ffi::{c_char, CString};
+use sui_json_rpc_types::SuiObjectData;
+use sui_rust_sdk::c_types::{
+    self, CStringArray, CSuiObjectData, CSuiObjectDataArray, CU8Array, ResultCStringArray,
+};
+use sui_rust_sdk::{
+    handles, multisig, object_read_api, ownership, transaction_builder, wallet, zklogin,
+};
+use sui_types::crypto::Signature;
+use sui_types::multisig::MultiSig;
+use sui_types::signature::GenericSignature;
+
+struct CountingAllocator;
+
+thread_local! {
+    static LIVE_ALLOCATIONS: Cell<isize> = const { Cell::new(0) };
+}
+
+fn count(delta: isize) {
+    let _ = LIVE_ALLOCATIONS.try_with(|live| live.set(live.get() + delta));
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            count(1);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            count(1);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        count(-1);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn assert_balanced(name: &str, scenario: impl Fn()) {
+    scenario();
+    let before = LIVE_ALLOCATIONS.with(Cell::get);
+    scenario();
+    let after = LIVE_ALLOCATIONS.with(Cell::get);
+    assert_eq!(
+        after - before,
+        0,
+        "{}: allocations left behind (or freed twice)",
+        name
+    );
+}
+
+fn c_string(s: &str) -> CString {
+    CString::new(s).unwrap()
+}
+
+#[test]
+#[serial]
+fn strings_and_arrays_are_released() {
+    assert_balanced("string array", || {
+        ownership::sui_free_string_array(CStringArray::from(vec![
+            "alice.sui".to_string(),
+            "bob.sui".to_string(),
+        ]));
+        ownership::sui_free_string_array(CStringArray::from(Vec::new()));
+    });
+    assert_balanced("result string array", || {
+        ownership::sui_free_result_string_array(ResultCStringArray::from(Ok(vec![
+            "suix_getCoins".to_string(),
+        ])));
+        ownership::sui_free_result_string_array(ResultCStringArray::from(Err(anyhow::anyhow!(
+            "unreachable"
+        ))));
+    });
+    assert_balanced("byte array", || {
+        ownership::sui_free_u8_array(CU8Array::from(vec![1, 2, 3]));
+        ownership::sui_free_u8_array(CU8Array::from_error("bad bytes".to_string()));
+    });
+    assert_balanced("pure value", || {
+        let type_ = c_string("u64");
+        let value = c_string("1000");
+        ownership::sui_free_pure(c_types::bsc_basic(type_.as_ptr(), value.as_ptr()));
+        ownership::sui_free_pure(std::ptr::null_mut());
+    });
+    assert_balanced("string", || {
+        let error = CString::new("Error: boom").unwrap().into_raw();
+        ownership::sui_free_string(error as *const c_char);
+        ownership::sui_free_string(std::ptr::null());
+    });
+}
+
+#[test]
+#[serial]
+fn object_data_arrays_are_released() {
+    let object: SuiObjectData =
+        serde_json::from_value(common::object(common::OBJECT_ID, common::NFT_TYPE)["data"].clone())
+            .unwrap();
+    assert_balanced("object data array", || {
+        let objects = vec![
+            CSuiObjectData::from(object.clone()),
+            CSuiObjectData::from(object.clone()),
+        ];
+        ownership::sui_free_object_data_array(CSuiObjectDataArray::from(objects));
+        ownership::sui_free_object_data_array(CSuiObjectDataArray::from(Vec::new()));
+    });
+}
+
+#[test]
+#[serial]
+fn wallets_and_keys_are_released() {
+    // The keystore functions work on $HOME/.sui
+    let home = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(home.path().join(".sui").join("sui_config")).unwrap();
+    std::env::set_var("HOME", home.path());
+
+    let mnemonic =
+        c_string("unhappy above olympic pig brick embark chest crisp sheriff awful frown smooth");
+    let ed25519 = c_string("ed25519");
+    assert_balanced("derived accounts", || {
+        ownership::sui_free_derived_account_list(wallet::derive_accounts_from_mnemonic(
+            mnemonic.as_ptr(),
+            ed25519.as_ptr(),
+            0,
+            3,
+        ));
+        let unknown = c_string("rsa");
+        ownership::sui_free_derived_account_list(wallet::derive_accounts_from_mnemonic(
+            mnemonic.as_ptr(),
+            unknown.as_ptr(),
+            0,
+            1,
+        ));
+        // The accounts derived before an invalid path are released with the error
+        let paths = [c_string("m/44'/784'/0'/0'/0'"), c_string("m/1'")];
+        let paths: Vec<*const c_char> = paths.iter().map(|path| path.as_ptr()).collect();
+        let list = wallet::derive_accounts_from_paths(
+            mnemonic.as_ptr(),
+            ed25519.as_ptr(),
+            CStringArray {
+                data: paths.as_ptr(),
+                len: paths.len() as i32,
+            },
+        );
+        ownership::sui_free_derived_account_list(list);
+    });
+
+    // An index past the hardened range fails the whole import, the first one is not imported
+    let indexes = [0u32, 1 << 31];
+    let list = wallet::import_accounts_from_mnemonic(
+        mnemonic.as_ptr(),
+        ed25519.as_ptr(),
+        indexes.as_ptr(),
+        indexes.len(),
+    );
+    assert!(!list.error.is_null());
+    ownership::sui_free_derived_account_list(list);
+    let wallets = wallet::get_wallets();
+    assert_eq!(wallets.length, 0);
+    ownership::sui_free_wallet_list(wallets);
+
+    let invalid_utf8 = CString::new(vec![0xff, 0xfe]).unwrap();
+    let list = wallet::derive_accounts_from_mnemonic(invalid_utf8.as_ptr(), ed25519.as_ptr(), 0, 1);
+    assert!(!list.error.is_null() && list.length == 0);
+    ownership::sui_free_derived_account_list(list);
+
+    let word_length = c_string("word12");
+    assert_balanced("generated wallet", || {
+        ownership::sui_free_wallet(wallet::generate_wallet(
+            ed25519.as_ptr(),
+            word_length.as_ptr(),
+        ));
+    });
+
+    assert_balanced("wallet handle", || {
+        let mut error: *mut c_char = std::ptr::null_mut();
+        let handle =
+            handles::sui_wallet_generate_v1(ed25519.as_ptr(), word_length.as_ptr(), &mut error);
+        assert!(!handles::sui_wallet_get_mnemonic_v1(handle).is_null());
+        handles::sui_free_wallet_v1(handle);
+        let unknown = c_string("0x0");
+        let handle = handles::sui_wallet_from_address_v1(unknown.as_ptr(), &mut error);
+        assert!(handle.is_null() && !error.is_null());
+        ownership::sui_free_string(error);
+        handles::sui_free_wallet_v1(std::ptr::null_mut());
+    });
+
+    let private_key = c_string(&common::keypair(4).encode_base64());
+    let bech32 = c_string("bech32");
+    let empty = c_string("");
+    assert_balanced("key results", || {
+        ownership::sui_free_key_result(wallet::convert_private_key(
+            private_key.as_ptr(),
+            empty.as_ptr(),
+            bech32.as_ptr(),
+        ));
+        let pem = c_string("pem");
+        ownership::sui_free_key_result(wallet::convert_private_key(
+            private_key.as_ptr(),
+            empty.as_ptr(),
+            pem.as_ptr(),
+        ));
+    });
+
+    assert_balanced("import results", || {
+        ownership::sui_free_import_result(wallet::import_from_private_key(private_key.as_ptr()));
+        let invalid = c_string("not a key");
+        ownership::sui_free_import_result(wallet::import_from_private_key(invalid.as_ptr()));
+        ownership::sui_free_wallet_list(wallet::get_wallets());
+    });
+
+    assert_balanced("address results", || {
+        ownership::sui_free_address_result(wallet::get_active_address());
+        let unknown = c_string("unknown alias");
+        ownership::sui_free_address_result(wallet::get_address_by_alias(unknown.as_ptr()));
+    });
+}
+
+#[test]
+#[serial]
+fn multisig_and_zklogin_results_are_released() {
+    let keypairs: Vec<_> = (20..23).map(common::keypair).collect();
+    let public_keys: Vec<String> = keypairs
+        .iter()
+        .map(|keypair| keypair.public().encode_base64())
+        .collect();
+    let multisig_pk = multisig::multisig_public_key_from_public_keys(
+        public_keys.iter().map(String::as_str).collect(),
+        vec![],
+        vec![1, 1, 1],
+        2,
+    )
+    .unwrap();
+    let multisig_bytes = bcs::to_bytes(&multisig_pk).unwrap();
+    let signatures: Vec<CString> = keypairs[..2]
+        .iter()
+        .map(|keypair| c_string(&Signature::new_hashed(b"message", keypair).encode_base64()))
+        .collect();
+    let signature_ptrs: Vec<*const c_char> = signatures.iter().map(|s| s.as_ptr()).collect();
+    // Signed by members 0 and 1, with a bitmap naming member 15 instead of member 1
+    let corrupt_bytes = {
+        let combined = multisig::combine_partial_signatures_multisig(
+            CU8Array {
+                data: multisig_bytes.as_ptr(),
+                len: multisig_bytes.len() as u32,
+                error: std::ptr::null(),
+            },
+            CStringArray {
+                data: signature_ptrs.as_ptr(),
+                len: 2,
+            },
+        );
+        let mut json =
+            serde_json::to_value(multisig::decode_multisig(&combined.to_vec()).unwrap()).unwrap();
+        ownership::sui_free_u8_array(combined);
+        json["bitmap"] = serde_json::json!(1 | (1 << 15));
+        let corrupt: MultiSig = serde_json::from_value(json).unwrap();
+        GenericSignature::MultiSig(corrupt).as_ref().to_vec()
+    };
+
+    assert_balanced("multisig", || {
+        let keys: Vec<CString> = public_keys.iter().map(|pk| c_string(pk)).collect();
+        let key_ptrs: Vec<*const c_char> = keys.iter().map(|pk| pk.as_ptr()).collect();
+        let weights = [1u8, 1, 1];
+        let borrowed = |data: *const *const c_char, len| CStringArray { data, len };
+        let multisig = multisig::create_multisig_from_public_keys(
+            borrowed(key_ptrs.as_ptr(), 3),
+            borrowed(std::ptr::null(), 0),
+            CU8Array {
+                data: weights.as_ptr(),
+                len: 3,
+                error: std::ptr::null(),
+            },
+            2,
+        );
+        ownership::sui_free_multisig(multisig);
+    });
+
+    assert_balanced("multisig info", || {
+        // Borrowed bytes, only library allocations go through sui_free_*
+        let multisig = CU8Array {
+            data: multisig_bytes.as_ptr(),
+            len: multisig_bytes.len() as u32,
+            error: std::ptr::null(),
+        };
+        let combined = multisig::combine_partial_signatures_multisig(
+            multisig,
+            CStringArray {
+                data: signature_ptrs.as_ptr(),
+                len: 2,
+            },
+        );
+        let combined_copy = CU8Array {
+            data: combined.data,
+            len: combined.len,
+            error: std::ptr::null(),
+        };
+        ownership::sui_free_multisig_info(multisig::decode_multisig_signature(combined_copy));
+        ownership::sui_free_u8_array(combined);
+
+        let invalid = c_string("not base64");
+        ownership::sui_free_multisig_info(multisig::decode_multisig_signature_base64(
+            invalid.as_ptr(),
+        ));
+
+        let corrupt = multisig::decode_multisig_signature(CU8Array {
+            data: corrupt_bytes.as_ptr(),
+            len: corrupt_bytes.len() as u32,
+            error: std::ptr::null(),
+        });
+        assert!(!corrupt.error.is_null());
+        assert!(corrupt.members.is_null());
+        ownership::sui_free_multisig_info(corrupt);
+    });
+
+    assert_balanced("zklogin", || {
+        ownership::sui_free_zklogin_ephemeral(zklogin::zklogin_generate_ephemeral(
+            10,
+            std::ptr::null(),
+        ));
+        let jwt = c_string("not a jwt");
+        let salt = c_string("1");
+        ownership::sui_free_zklogin_address(zklogin::zklogin_address_from_jwt(
+            jwt.as_ptr(),
+            salt.as_ptr(),
+        ));
+    });
+}
+
+#[test]
+#[serial]
+fn handles_are_released() {
+    assert_balanced("object filters", || {
+        let nft_type = c_string(common::NFT_TYPE);
+        let package = c_string("0x2");
+        let filters = [
+            object_read_api::object_filter_struct_type(nft_type.as_ptr()),
+            object_read_api::object_filter_package(package.as_ptr()),
+        ];
+        // The combinator takes the filters over
+        let filter = object_read_api::object_filter_match_any(filters.as_ptr(), filters.len());
+        ownership::sui_free_object_filter(filter);
+        let invalid = c_string("not a type");
+        ownership::sui_free_object_filter(object_read_api::object_filter_struct_type(
+            invalid.as_ptr(),
+        ));
+        // Filters after an invalid one are taken over as well
+        let filters = [
+            object_read_api::object_filter_struct_type(invalid.as_ptr()),
+            object_read_api::object_filter_package(package.as_ptr()),
+        ];
+        let filter = object_read_api::object_filter_match_all(filters.as_ptr(), filters.len());
+        ownership::sui_free_object_filter(filter);
+        // So are the filters around a null entry
+        let filters = [
+            object_read_api::object_filter_package(package.as_ptr()),
+            std::ptr::null_mut(),
+            object_read_api::object_filter_package(package.as_ptr()),
+        ];
+        let filter = object_read_api::object_filter_match_any(filters.as_ptr(), filters.len());
+        assert!(!filter.is_null());
+        ownership::sui_free_object_filter(filter);
+        ownership::sui_free_object_filter(std::ptr::null_mut());
+    });
+
+    assert_balanced("transaction builder", || {
+        let builder = transaction_builder::create_builder();
+        let coin = transaction_builder::create_arguments();
+        transaction_builder::add_argument_gas_coin(coin);
+        let amounts = transaction_builder::create_arguments();
+        let type_ = c_string("u64");
+        let value = c_string("1000");
+        let amount = c_types::bsc_basic(type_.as_ptr(), value.as_ptr());
+        transaction_builder::make_pure(builder, amounts, amount);
+        ownership::sui_free_pure(amount);
+        transaction_builder::add_split_coins_command(builder, coin, amounts);
+
+        let type_tags = transaction_builder::create_type_tags();
+        let tag = c_string("0x2::sui::SUI");
+        transaction_builder::add_type_tag(type_tags, tag.as_ptr());
+
+        ownership::sui_free_type_tags(type_tags);
+        ownership::sui_free_arguments(coin);
+        ownership::sui_free_arguments(amounts);
+        ownership::sui_free_builder(builder);
+        ownership::sui_free_builder(std::ptr::null_mut());
+    });
+}
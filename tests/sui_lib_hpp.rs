@@ -0,0 +1,20 @@
+//! header/sui_lib.hpp is generated from header/sui_lib.h by build.sh, the committed copy has to
+//! follow the committed C header.
+
+#[allow(dead_code)]
+#[path = "../tools/sui_lib_hpp.rs"]
+mod sui_lib_hpp;
+
+use std::fs;
+use std::path::Path;
+
+#[test]
+fn committed_wrapper_matches_the_c_header() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let source = fs::read_to_string(manifest_dir.join(sui_lib_hpp::C_HEADER)).unwrap();
+    let committed = fs::read_to_string(manifest_dir.join(sui_lib_hpp::CPP_HEADER)).unwrap();
+    assert!(
+        sui_lib_hpp::generate(&source) == committed,
+        "header/sui_lib.hpp is out of date, run build.sh"
+    );
+}
@@ -0,0 +1,616 @@
+//! Generates header/sui_lib.hpp, a header-only C++17 wrapper over header/sui_lib.h.
+//!
+//! The wrapper is derived from the C header alone, so it follows every `cbindgen` run:
+//! - the `sui_free_*` / `free_*` / `destroy_*` declarations tell which function releases which
+//!   type, results of those types come back as RAII values (`sui::Owned`, `sui::Handle`),
+//! - an `error` field, a `char **error` out-parameter or a `SuiStatus` result becomes a
+//!   `sui::Error` exception carrying the `status` of the result when it has one,
+//! - strings, string arrays and byte arrays are taken and returned as `std::string` and
+//!   `std::vector`,
+//! - calls that only take values also get a `_async` variant returning a `std::future`.
+//!
+//! build.sh runs it from the crate root after cbindgen, like sui_lib.h the output is committed.
+//! tests/sui_lib_hpp.rs checks that the committed wrapper matches the header.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+use std::fs;
+
+pub const C_HEADER: &str = "header/sui_lib.h";
+pub const CPP_HEADER: &str = "header/sui_lib.hpp";
+
+struct Param {
+    type_: String,
+    name: String,
+}
+
+struct Function {
+    ret: String,
+    name: String,
+    params: Vec<Param>,
+}
+
+/// Split `const char *name` into its type and name.
+fn split_declarator(declarator: &str) -> Param {
+    let declarator = declarator.trim();
+    let split = declarator
+        .rfind(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .map_or(0, |i| i + 1);
+    Param {
+        type_: normalize_type(&declarator[..split]),
+        name: declarator[split..].to_string(),
+    }
+}
+
+fn normalize_type(type_: &str) -> String {
+    type_
+        .replace("struct ", "")
+        .replace('*', " * ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace("* *", "**")
+}
+
+fn strip_comments(source: &str) -> String {
+    let mut out = String::new();
+    let mut rest = source;
+    while let Some(start) = rest.find("/*") {
+        out.push_str(&rest[..start]);
+        rest = rest[start..]
+            .find("*/")
+            .map_or("", |end| &rest[start + end + 2..]);
+    }
+    out.push_str(rest);
+    out.lines()
+        .filter(|line| !line.trim_start().starts_with('#'))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Field names of every `typedef struct Name { ... } Name;`.
+fn parse_structs(source: &str) -> BTreeMap<String, Vec<Param>> {
+    let mut structs = BTreeMap::new();
+    let mut rest = source;
+    while let Some(start) = rest.find("typedef struct ") {
+        rest = &rest[start + "typedef struct ".len()..];
+        let (Some(open), Some(semicolon)) = (rest.find('{'), rest.find(';')) else {
+            break;
+        };
+        if semicolon < open {
+            // Opaque type
+            continue;
+        }
+        let name = rest[..open].trim().to_string();
+        let close = rest.find('}').unwrap();
+        let fields = rest[open + 1..close]
+            .split(';')
+            .filter(|field| !field.trim().is_empty())
+            .map(|field| split_declarator(field.split('[').next().unwrap()))
+            .collect();
+        structs.insert(name, fields);
+        rest = &rest[close..];
+    }
+    structs
+}
+
+fn parse_functions(source: &str) -> Vec<Function> {
+    source
+        .split(';')
+        .map(|statement| statement.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|statement| statement.contains('(') && !statement.contains("typedef"))
+        .filter(|statement| !statement.contains('{') && !statement.contains('}'))
+        .map(|statement| {
+            let open = statement.find('(').unwrap();
+            let head = split_declarator(&statement[..open]);
+            let params = statement[open + 1..statement.rfind(')').unwrap()]
+                .split(',')
+                .map(str::trim)
+                .filter(|param| !param.is_empty() && *param != "void")
+                .map(split_declarator)
+                .collect();
+            Function {
+                ret: head.type_,
+                name: head.name,
+                params,
+            }
+        })
+        .collect()
+}
+
+fn is_free_function(name: &str) -> bool {
+    name.starts_with("sui_free_") || name.starts_with("free_") || name.starts_with("destroy_")
+}
+
+/// The function releasing each type, `sui_free_*` preferred over the older names.
+fn free_functions(functions: &[Function]) -> BTreeMap<String, String> {
+    let mut frees = BTreeMap::new();
+    for function in functions.iter().filter(|f| is_free_function(&f.name)) {
+        if function.params.len() != 1 || function.ret != "void" {
+            continue;
+        }
+        let type_ = function.params[0].type_.replace("const ", "");
+        if type_ == "char *" {
+            continue;
+        }
+        let preferred = function.name.starts_with("sui_free_");
+        if preferred || !frees.contains_key(&type_) {
+            frees.insert(type_, function.name.clone());
+        }
+    }
+    frees
+}
+
+struct Generator {
+    structs: BTreeMap<String, Vec<Param>>,
+    frees: BTreeMap<String, String>,
+}
+
+/// How a C result is handed to C++.
+enum Returns {
+    Void,
+    /// Passed through unchanged: scalars, borrowed pointers, plain structs
+    Value(String),
+    /// `return convert(value);`
+    Converted {
+        type_: String,
+        convert: &'static str,
+    },
+    /// A message on failure, null on success
+    Status,
+    /// Wrapped into an RAII type, then its `error` field is checked
+    Owned {
+        type_: String,
+        pointer: bool,
+        check: String,
+    },
+    /// `{ T *value; const char *error; }` whose value has a free function
+    ValueOrError {
+        type_: String,
+        field: String,
+    },
+}
+
+impl Returns {
+    fn cpp_type(&self) -> &str {
+        match self {
+            Returns::Void | Returns::Status => "void",
+            Returns::Value(type_)
+            | Returns::Converted { type_, .. }
+            | Returns::Owned { type_, .. }
+            | Returns::ValueOrError { type_, .. } => type_,
+        }
+    }
+
+    /// Statements returning the C++ result of `value`.
+    fn body(&self, value: &str) -> String {
+        match self {
+            Returns::Void => format!("    {};\n", value),
+            Returns::Value(_) => format!("    return {};\n", value),
+            Returns::Converted { convert, .. } => format!("    return {}({});\n", convert, value),
+            Returns::Status => format!("    detail::check_status({});\n", value),
+            Returns::Owned {
+                type_,
+                pointer,
+                check,
+            } => {
+                let check = match (check.is_empty(), pointer) {
+                    (true, _) => String::new(),
+                    (false, false) => format!("    {}\n", check),
+                    (false, true) => format!("    if (result)\n    {{\n        {}\n    }}\n", check),
+                };
+                format!("    {} result({});\n{}    return result;\n", type_, value, check)
+            }
+            Returns::ValueOrError { type_, field } => format!(
+                "    auto raw = {};\n    {} result(raw.{});\n    detail::check_status(raw.error);\n    return result;\n",
+                value, type_, field
+            ),
+        }
+    }
+}
+
+impl Generator {
+    fn has_field(&self, type_: &str, field: &str) -> bool {
+        self.structs
+            .get(type_)
+            .is_some_and(|fields| fields.iter().any(|f| f.name == field))
+    }
+
+    /// `detail::check` of the `error` field of a result, with its `status` as the error code.
+    fn error_check(&self, type_: &str) -> String {
+        if !self.has_field(type_, "error") {
+            return String::new();
+        }
+        let code = if self.has_field(type_, "status") {
+            "result->status"
+        } else {
+            "-1"
+        };
+        format!("detail::check(result->error, {});", code)
+    }
+
+    fn returns(&self, function: &Function) -> Returns {
+        let ret = function.ret.as_str();
+        match ret {
+            "void" => Returns::Void,
+            "const char *" | "char *" if function.name.ends_with("_v1") => Returns::Converted {
+                type_: "std::optional<std::string>".to_string(),
+                convert: "detail::borrowed",
+            },
+            "SuiStatus" => Returns::Status,
+            "const char *" | "char *" => Returns::Converted {
+                type_: "std::string".to_string(),
+                convert: "detail::take_string",
+            },
+            "ResultCStringArray" => Returns::Converted {
+                type_: "std::vector<std::string>".to_string(),
+                convert: "detail::take_strings",
+            },
+            "CU8Array" => Returns::Converted {
+                type_: "std::vector<uint8_t>".to_string(),
+                convert: "detail::take_bytes",
+            },
+            _ => self.returns_struct(ret),
+        }
+    }
+
+    fn returns_struct(&self, ret: &str) -> Returns {
+        if let Some(free) = self.frees.get(ret) {
+            let pointee = ret.strip_suffix(" *");
+            let type_ = match pointee {
+                Some(pointee) => format!("Handle<{}, {}>", pointee, free),
+                None => format!("Owned<{}, {}>", ret, free),
+            };
+            return Returns::Owned {
+                type_,
+                pointer: pointee.is_some(),
+                check: self.error_check(pointee.unwrap_or(ret)),
+            };
+        }
+        if let Some([value, error]) = self.structs.get(ret).map(Vec::as_slice) {
+            if let (true, Some(free)) = (error.name == "error", self.frees.get(&value.type_)) {
+                return Returns::ValueOrError {
+                    type_: format!("Handle<{}, {}>", value.type_.trim_end_matches(" *"), free),
+                    field: value.name.clone(),
+                };
+            }
+        }
+        Returns::Value(ret.to_string())
+    }
+
+    fn wrapper(&self, function: &Function, out: &mut String) {
+        let returns = self.returns(function);
+        // (C++ type, name) of the parameters, statements preparing the C arguments
+        let mut params: Vec<(String, String)> = Vec::new();
+        let mut prepare = String::new();
+        let mut args = Vec::new();
+        let mut out_error = false;
+        let mut by_value = true;
+        for param in &function.params {
+            let name = param.name.clone();
+            match param.type_.as_str() {
+                "const char *" => {
+                    args.push(format!("{}.c_str()", name));
+                    params.push(("Str".to_string(), name));
+                }
+                "CStringArray" => {
+                    let _ = writeln!(prepare, "    detail::StringArray {0}_array({0});", name);
+                    args.push(format!("{}_array.get()", name));
+                    params.push(("std::vector<std::string>".to_string(), name));
+                }
+                "CU8Array" => {
+                    args.push(format!("detail::bytes({})", name));
+                    params.push(("std::vector<uint8_t>".to_string(), name));
+                }
+                "char **" if name == "error" => {
+                    out_error = true;
+                    prepare.push_str("    char *error = nullptr;\n");
+                    args.push("&error".to_string());
+                }
+                type_ => {
+                    by_value &= !type_.contains('*');
+                    args.push(name.clone());
+                    params.push((type_.to_string(), name));
+                }
+            }
+        }
+
+        let call = format!("::{}({})", function.name, args.join(", "));
+        let body = if out_error {
+            // The error is reported before the (null) result is looked at
+            let mut body = match returns {
+                Returns::Void => format!("    {};\n", call),
+                _ => format!("    auto raw = {};\n", call),
+            };
+            body.push_str("    detail::check_status(error);\n");
+            if !matches!(returns, Returns::Void) {
+                body.push_str(&returns.body("raw"));
+            }
+            body
+        } else {
+            returns.body(&call)
+        };
+        let declared: Vec<String> = params
+            .iter()
+            .map(|(type_, name)| match type_.strip_suffix('*') {
+                Some(pointer) => format!("{}*{}", pointer, name),
+                None => format!("{} {}", type_, name),
+            })
+            .collect();
+        let cpp_type = returns.cpp_type();
+        let _ = write!(
+            out,
+            "inline {} {}({})\n{{\n{}{}}}\n\n",
+            cpp_type,
+            function.name,
+            declared.join(", "),
+            prepare,
+            body
+        );
+
+        if by_value && cpp_type != "void" {
+            let mut async_args = vec![function.name.clone()];
+            async_args.extend(
+                params
+                    .iter()
+                    .map(|(_, name)| format!("std::move({})", name)),
+            );
+            let _ = write!(
+                out,
+                "inline std::future<{0}> {1}_async({2})\n{{\n    return std::async(std::launch::async, {3});\n}}\n\n",
+                cpp_type,
+                function.name,
+                declared.join(", "),
+                async_args.join(", ")
+            );
+        }
+    }
+}
+
+const PRELUDE: &str = r#"// Generated by tools/sui_lib_hpp.rs from sui_lib.h, do not edit.
+//
+// Header-only C++17 wrapper of the C API: results are RAII values released with their sui_free_*
+// function, errors are thrown as sui::Error, strings and arrays are std::string and std::vector,
+// and calls taking only values have a _async variant returning a std::future.
+
+#pragma once
+
+#include <cstdint>
+#include <cstring>
+#include <future>
+#include <memory>
+#include <optional>
+#include <stdexcept>
+#include <string>
+#include <utility>
+#include <vector>
+
+extern "C"
+{
+#include "sui_lib.h"
+}
+
+namespace sui
+{
+
+// An error reported by the SDK, `code` is the status of the result (ObjectStatus, the status of
+// ImportResult and KeyResult) or -1 when the result has none.
+class Error : public std::runtime_error
+{
+public:
+    Error(const std::string &message, int code) : std::runtime_error(message), code_(code) {}
+
+    int code() const noexcept { return code_; }
+
+private:
+    int code_;
+};
+
+// A string argument, converts from std::string, string literals and nullptr (absent).
+class Str
+{
+public:
+    Str(std::nullptr_t) {}
+    Str(const char *value)
+    {
+        if (value != nullptr)
+        {
+            value_ = value;
+        }
+    }
+    Str(std::string value) : value_(std::move(value)) {}
+
+    const char *c_str() const { return value_ ? value_->c_str() : nullptr; }
+
+private:
+    std::optional<std::string> value_;
+};
+
+// A struct returned by value, released with `Free` when it goes out of scope.
+template <typename T, void (*Free)(T)>
+class Owned
+{
+public:
+    explicit Owned(T value) : value_(value), owned_(true) {}
+    Owned(Owned &&other) noexcept : value_(other.value_), owned_(std::exchange(other.owned_, false)) {}
+    Owned &operator=(Owned &&other) noexcept
+    {
+        if (this != &other)
+        {
+            reset();
+            value_ = other.value_;
+            owned_ = std::exchange(other.owned_, false);
+        }
+        return *this;
+    }
+    Owned(const Owned &) = delete;
+    Owned &operator=(const Owned &) = delete;
+    ~Owned() { reset(); }
+
+    const T &get() const { return value_; }
+    const T *operator->() const { return &value_; }
+    const T &operator*() const { return value_; }
+
+private:
+    void reset()
+    {
+        if (owned_)
+        {
+            Free(value_);
+            owned_ = false;
+        }
+    }
+
+    T value_;
+    bool owned_;
+};
+
+template <typename T, void (*Free)(T *)>
+struct Deleter
+{
+    void operator()(T *value) const { Free(value); }
+};
+
+// A pointer returned by the library, released with `Free`. Call release() before passing it to
+// a function that consumes it (execute_transaction, object_filter_match_*).
+template <typename T, void (*Free)(T *)>
+using Handle = std::unique_ptr<T, Deleter<T, Free>>;
+
+// Iterate over an array of a result without copying it, valid while the result lives.
+template <typename T>
+struct Span
+{
+    const T *data;
+    size_t len;
+
+    const T *begin() const { return data; }
+    const T *end() const { return data + len; }
+    size_t size() const { return len; }
+    const T &operator[](size_t index) const { return data[index]; }
+};
+
+template <typename T>
+Span<T> span(const T *data, size_t len)
+{
+    return Span<T>{data, data == nullptr ? 0 : len};
+}
+
+namespace detail
+{
+
+// The error field of a result, released together with the result
+inline void check(const char *error, int code)
+{
+    if (error != nullptr)
+    {
+        throw Error(error, code);
+    }
+}
+
+// An error message owned by the caller, released before throwing
+inline void check_status(const char *error)
+{
+    if (error != nullptr)
+    {
+        std::string message(error);
+        sui_free_string(error);
+        throw Error(message, -1);
+    }
+}
+
+inline std::string take_string(const char *value)
+{
+    if (value == nullptr)
+    {
+        return std::string();
+    }
+    std::string result(value);
+    sui_free_string(value);
+    return result;
+}
+
+inline std::optional<std::string> borrowed(const char *value)
+{
+    if (value == nullptr)
+    {
+        return std::nullopt;
+    }
+    return std::string(value);
+}
+
+inline std::vector<std::string> take_strings(ResultCStringArray result)
+{
+    Owned<ResultCStringArray, sui_free_result_string_array> owned(result);
+    check(owned->error, -1);
+    std::vector<std::string> strings;
+    for (int i = 0; i < owned->strings.len; i++)
+    {
+        strings.emplace_back(owned->strings.data[i]);
+    }
+    return strings;
+}
+
+inline std::vector<uint8_t> take_bytes(CU8Array array)
+{
+    Owned<CU8Array, sui_free_u8_array> owned(array);
+    check(owned->error, -1);
+    if (owned->data == nullptr)
+    {
+        return std::vector<uint8_t>();
+    }
+    return std::vector<uint8_t>(owned->data, owned->data + owned->len);
+}
+
+// A borrowed CStringArray over strings owned by the caller.
+class StringArray
+{
+public:
+    explicit StringArray(const std::vector<std::string> &strings)
+    {
+        for (const std::string &string : strings)
+        {
+            pointers_.push_back(string.c_str());
+        }
+    }
+
+    CStringArray get() const { return CStringArray{pointers_.data(), static_cast<int>(pointers_.size())}; }
+
+private:
+    std::vector<const char *> pointers_;
+};
+
+inline CU8Array bytes(const std::vector<uint8_t> &bytes)
+{
+    return CU8Array{bytes.data(), static_cast<unsigned int>(bytes.size()), nullptr};
+}
+
+} // namespace detail
+
+"#;
+
+pub fn generate(source: &str) -> String {
+    let source = strip_comments(source);
+    let functions = parse_functions(&source);
+    let generator = Generator {
+        structs: parse_structs(&source),
+        frees: free_functions(&functions),
+    };
+    let mut out = PRELUDE.to_string();
+    let mut seen = BTreeSet::new();
+    for function in &functions {
+        if is_free_function(&function.name) || !seen.insert(function.name.clone()) {
+            continue;
+        }
+        generator.wrapper(function, &mut out);
+    }
+    out.push_str("} // namespace sui\n");
+    out
+}
+
+fn main() {
+    let source = fs::read_to_string(C_HEADER).expect("run from the crate root");
+    let generated = generate(&source);
+    if fs::read_to_string(CPP_HEADER).ok().as_deref() != Some(generated.as_str()) {
+        fs::write(CPP_HEADER, generated).expect("cannot write header/sui_lib.hpp");
+    }
+}